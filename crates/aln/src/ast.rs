@@ -0,0 +1,172 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::Dialect;
+
+/// 1-based source position
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    pub fn new(line: usize, column: usize) -> Self {
+        Span { line, column }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Key {
+    pub name: String,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    /// `raw` keeps the source spelling so `1.0` stays `1.0` when used as text
+    Number { value: f64, raw: String },
+    String(String),
+    List(Vec<Node>),
+    /// Entries in source order; duplicate keys are kept
+    Map(Vec<(Key, Node)>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+    pub value: Value,
+    pub span: Span,
+}
+
+impl Node {
+    pub(crate) fn new(value: Value, span: Span) -> Self {
+        Node { value, span }
+    }
+
+    /// First entry named `key` in a map
+    pub fn get(&self, key: &str) -> Option<&Node> {
+        self.as_map()?.iter().find(|(k, _)| k.name == key).map(|(_, v)| v)
+    }
+
+    /// Every entry named `key`, in source order
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a Node> + 'a {
+        self.as_map()
+            .unwrap_or(&[])
+            .iter()
+            .filter(move |(k, _)| k.name == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Dotted lookup through nested maps, e.g. `constraints.max_roh`
+    pub fn path(&self, path: &str) -> Option<&Node> {
+        path.split('.').try_fold(self, |node, key| node.get(key))
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.value {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Scalar as written: strings, number spellings and booleans
+    pub fn as_text(&self) -> Option<&str> {
+        match &self.value {
+            Value::String(s) => Some(s),
+            Value::Number { raw, .. } => Some(raw),
+            Value::Bool(true) => Some("true"),
+            Value::Bool(false) => Some("false"),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.value {
+            Value::Number { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.value {
+            Value::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[Node]> {
+        match &self.value {
+            Value::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_map(&self) -> Option<&[(Key, Node)]> {
+        match &self.value {
+            Value::Map(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self.value {
+            Value::Null => "null",
+            Value::Bool(_) => "bool",
+            Value::Number { .. } => "number",
+            Value::String(_) => "string",
+            Value::List(_) => "list",
+            Value::Map(_) => "map",
+        }
+    }
+}
+
+/// A `section` of a keyword or JSON document
+#[derive(Clone, Debug, PartialEq)]
+pub struct Section<'a> {
+    pub name: &'a str,
+    pub span: Span,
+    pub entries: Vec<&'a (Key, Node)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Document {
+    pub dialect: Dialect,
+    pub root: Node,
+}
+
+impl Document {
+    pub fn get(&self, key: &str) -> Option<&Node> {
+        self.root.get(key)
+    }
+
+    pub fn path(&self, path: &str) -> Option<&Node> {
+        self.root.path(path)
+    }
+
+    /// Sections in source order. Keyword documents nest entries under `section Name`;
+    /// JSON documents use repeated `"section"` markers followed by sibling keys.
+    pub fn sections(&self) -> Vec<Section<'_>> {
+        let mut sections: Vec<Section<'_>> = Vec::new();
+        for entry in self.root.as_map().unwrap_or(&[]) {
+            let (key, node) = entry;
+            if key.name == "section" {
+                match &node.value {
+                    Value::Map(children) => sections.push(Section {
+                        name: node.get("name").and_then(Node::as_str).unwrap_or(""),
+                        span: key.span,
+                        entries: children.iter().filter(|(k, _)| k.name != "name").collect(),
+                    }),
+                    Value::String(name) => sections.push(Section { name, span: key.span, entries: Vec::new() }),
+                    _ => {}
+                }
+            } else if self.dialect == Dialect::Json {
+                if let Some(current) = sections.last_mut() {
+                    current.entries.push(entry);
+                }
+            }
+        }
+        sections
+    }
+}
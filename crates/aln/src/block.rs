@@ -0,0 +1,213 @@
+//! Block dialect (`aln/*.aln`): indentation-nested `key: value` maps, `- item` lists,
+//! folded (`>`) and literal (`|`) strings, inline `[..]`/`{..}` values and `#` comments.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ast::{Key, Node, Span, Value};
+use crate::cursor::Cursor;
+use crate::ParseError;
+
+struct Line<'a> {
+    number: usize,
+    indent: usize,
+    text: &'a str, // without indentation or trailing comment
+}
+
+impl Line<'_> {
+    fn span(&self) -> Span {
+        Span::new(self.number, self.indent + 1)
+    }
+
+    fn is_item(&self) -> bool {
+        self.text == "-" || self.text.starts_with("- ")
+    }
+}
+
+fn strip_comment(text: &str) -> &str {
+    let mut in_quote = false;
+    let mut prev = ' ';
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => in_quote = !in_quote,
+            '#' if !in_quote && prev.is_whitespace() => return text[..i].trim_end(),
+            _ => {}
+        }
+        prev = c;
+    }
+    text.trim_end()
+}
+
+/// `key: rest` split; keys are bare words and the colon must end the line or precede a space
+fn split_key(text: &str) -> Option<(&str, &str)> {
+    if text.starts_with(['"', '[', '{']) {
+        return None;
+    }
+    let colon = text.find(':')?;
+    let key = &text[..colon];
+    let rest = &text[colon + 1..];
+    if key.is_empty() || key.contains(char::is_whitespace) || !(rest.is_empty() || rest.starts_with(' ')) {
+        return None;
+    }
+    Some((key, rest))
+}
+
+struct Parser<'a> {
+    lines: Vec<Line<'a>>,
+    pos: usize,
+}
+
+pub(crate) fn parse(text: &str) -> Result<Node, ParseError> {
+    let mut lines = Vec::new();
+    for (i, raw) in text.lines().enumerate() {
+        let content = raw.trim_start_matches(' ');
+        let indent = raw.len() - content.len();
+        if content.starts_with('\t') {
+            return Err(ParseError::new(Span::new(i + 1, indent + 1), "tabs are not allowed for indentation"));
+        }
+        let text = strip_comment(content);
+        if text.is_empty() || content.starts_with('#') {
+            continue;
+        }
+        lines.push(Line { number: i + 1, indent, text });
+    }
+
+    let mut parser = Parser { lines, pos: 0 };
+    if parser.lines.is_empty() {
+        return Ok(Node::new(Value::Map(Vec::new()), Span::new(1, 1)));
+    }
+    let first_indent = parser.lines[0].indent;
+    let root = parser.map(first_indent)?;
+    if let Some(line) = parser.lines.get(parser.pos) {
+        return Err(ParseError::new(line.span(), "unexpected indentation"));
+    }
+    Ok(root)
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Line<'a>> {
+        self.lines.get(self.pos)
+    }
+
+    fn map(&mut self, indent: usize) -> Result<Node, ParseError> {
+        let span = self.lines[self.pos].span();
+        let mut entries = Vec::new();
+        while let Some(line) = self.peek() {
+            if line.indent < indent {
+                break;
+            }
+            if line.indent > indent {
+                return Err(ParseError::new(line.span(), "unexpected indentation"));
+            }
+            if line.is_item() {
+                return Err(ParseError::new(line.span(), "list item where a `key: value` entry was expected"));
+            }
+            let (key, rest) =
+                split_key(line.text).ok_or_else(|| ParseError::new(line.span(), "expected `key: value`"))?;
+            let key = Key { name: String::from(key), span: line.span() };
+            let value_col = line.indent + line.text.len() - rest.trim_start().len() + 1;
+            let value_span = Span::new(line.number, value_col);
+            let rest = rest.trim();
+            self.pos += 1;
+
+            let value = match rest {
+                "" => self.nested(indent, value_span)?,
+                ">" | ">-" | "|" | "|-" => self.block_scalar(indent, rest.starts_with('|'), value_span),
+                _ => {
+                    let mut cur = Cursor::from_segments(&[(value_span, rest)]);
+                    let node = cur.inline_value(false)?;
+                    cur.skip_ws();
+                    if !cur.at_end() {
+                        return Err(ParseError::new(cur.span(), "unexpected text after value"));
+                    }
+                    node
+                }
+            };
+            entries.push((key, value));
+        }
+        Ok(Node::new(Value::Map(entries), span))
+    }
+
+    /// Value of `key:` with nothing after the colon
+    fn nested(&mut self, indent: usize, span: Span) -> Result<Node, ParseError> {
+        match self.peek() {
+            Some(next) if next.indent > indent => {
+                let child = next.indent;
+                if next.is_item() {
+                    self.list(child)
+                } else {
+                    self.map(child)
+                }
+            }
+            // YAML allows a key's list at the key's own indentation
+            Some(next) if next.indent == indent && next.is_item() => self.list(indent),
+            _ => Ok(Node::new(Value::Null, span)),
+        }
+    }
+
+    fn list(&mut self, indent: usize) -> Result<Node, ParseError> {
+        let span = self.lines[self.pos].span();
+        let mut items = Vec::new();
+        while let Some(line) = self.peek() {
+            if line.indent != indent || !line.is_item() {
+                break;
+            }
+            let body = line.text[1..].trim_start();
+            let body_col = line.indent + line.text.len() - body.len();
+            let body_span = Span::new(line.number, body_col + 1);
+
+            if body.is_empty() {
+                self.pos += 1;
+                items.push(self.nested(indent, body_span)?);
+            } else if split_key(body).is_some() {
+                // `- key: value` opens a map whose continuation lines align with `key`
+                let number = line.number;
+                self.lines[self.pos] = Line { number, indent: body_col, text: body };
+                items.push(self.map(body_col)?);
+            } else {
+                self.pos += 1;
+                let mut cur = Cursor::from_segments(&[(body_span, body)]);
+                items.push(cur.inline_value(false)?);
+            }
+        }
+        Ok(Node::new(Value::List(items), span))
+    }
+
+    fn block_scalar(&mut self, indent: usize, literal: bool, span: Span) -> Node {
+        let mut parts: Vec<&str> = Vec::new();
+        while let Some(line) = self.peek() {
+            if line.indent <= indent {
+                break;
+            }
+            parts.push(line.text);
+            self.pos += 1;
+        }
+        let joined = parts.join(if literal { "\n" } else { " " });
+        Node::new(Value::String(joined), span)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_blocks_and_lists() {
+        let text = "id: x\ndescription: >\n  folded\n  text\nfaces:\n  - a: [1.0, 0.0]\n    b: 0.6 # ceiling\nkeys:\n  - host_did\n  - \"upgrade_id\"\nnested:\n  inner:\n    flag: true\n";
+        let root = parse(text).unwrap();
+        assert_eq!(root.get("description").and_then(Node::as_str), Some("folded text"));
+        let face = &root.get("faces").unwrap().as_list().unwrap()[0];
+        assert_eq!(face.get("b").and_then(Node::as_f64), Some(0.6));
+        assert_eq!(face.get("a").unwrap().span, Span::new(6, 8));
+        assert_eq!(root.get("keys").unwrap().as_list().unwrap()[1].as_str(), Some("upgrade_id"));
+        assert_eq!(root.path("nested.inner.flag").and_then(Node::as_bool), Some(true));
+    }
+
+    #[test]
+    fn test_errors_carry_line_and_column() {
+        let err = parse("id: x\nfields:\n  a: 1\n    b: 2\n").unwrap_err();
+        assert_eq!(err.span, Span::new(4, 5));
+        let err = parse("id: x\nnot a pair\n").unwrap_err();
+        assert_eq!((err.span, err.message.as_str()), (Span::new(2, 1), "expected `key: value`"));
+    }
+}
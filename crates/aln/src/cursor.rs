@@ -0,0 +1,260 @@
+//! Character cursor with line/column tracking, plus the inline value grammar
+//! (`"quoted"`, `[a, b]`, `{k: v}`, bare scalars) shared by the block and keyword dialects.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ast::{Key, Node, Span, Value};
+use crate::ParseError;
+
+pub(crate) struct Cursor {
+    chars: Vec<(char, Span)>,
+    pos: usize,
+    end: Span,
+}
+
+impl Cursor {
+    /// Whole text, lines numbered from 1
+    pub(crate) fn from_text(text: &str) -> Self {
+        let mut chars = Vec::new();
+        let (mut line, mut column) = (1, 1);
+        for c in text.chars() {
+            chars.push((c, Span::new(line, column)));
+            if c == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        Cursor { chars, pos: 0, end: Span::new(line, column) }
+    }
+
+    /// Pieces of one or more lines, each starting at the given span; joined with newlines
+    pub(crate) fn from_segments(segments: &[(Span, &str)]) -> Self {
+        let mut chars = Vec::new();
+        let mut end = Span::new(1, 1);
+        for (start, text) in segments {
+            let mut column = start.column;
+            for c in text.chars() {
+                chars.push((c, Span::new(start.line, column)));
+                column += 1;
+            }
+            end = Span::new(start.line, column);
+            chars.push(('\n', end));
+        }
+        chars.pop();
+        Cursor { chars, pos: 0, end }
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).map(|(c, _)| *c)
+    }
+
+    pub(crate) fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    pub(crate) fn span(&self) -> Span {
+        self.chars.get(self.pos).map(|(_, s)| *s).unwrap_or(self.end)
+    }
+
+    pub(crate) fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    pub(crate) fn at_end(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+
+    pub(crate) fn expect(&mut self, want: char) -> Result<(), ParseError> {
+        match self.bump() {
+            Some(c) if c == want => Ok(()),
+            Some(c) => Err(ParseError::new(self.prev_span(), alloc::format!("expected `{}`, found `{}`", want, c))),
+            None => Err(ParseError::new(self.end, alloc::format!("expected `{}`, found end of input", want))),
+        }
+    }
+
+    fn prev_span(&self) -> Span {
+        self.chars.get(self.pos.saturating_sub(1)).map(|(_, s)| *s).unwrap_or(self.end)
+    }
+
+    /// `"..."` with JSON escapes
+    pub(crate) fn quoted(&mut self) -> Result<String, ParseError> {
+        let start = self.span();
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            match self.bump() {
+                None | Some('\n') => return Err(ParseError::new(start, "unterminated string")),
+                Some('"') => return Ok(out),
+                Some('\\') => {
+                    let esc_span = self.prev_span();
+                    match self.bump() {
+                        Some('"') => out.push('"'),
+                        Some('\\') => out.push('\\'),
+                        Some('/') => out.push('/'),
+                        Some('n') => out.push('\n'),
+                        Some('t') => out.push('\t'),
+                        Some('r') => out.push('\r'),
+                        Some('b') => out.push('\u{8}'),
+                        Some('f') => out.push('\u{c}'),
+                        Some('u') => out.push(self.unicode_escape(esc_span)?),
+                        _ => return Err(ParseError::new(esc_span, "invalid escape sequence")),
+                    }
+                }
+                Some(c) => out.push(c),
+            }
+        }
+    }
+
+    fn hex4(&mut self, span: Span) -> Result<u32, ParseError> {
+        let mut v = 0;
+        for _ in 0..4 {
+            let d = self
+                .bump()
+                .and_then(|c| c.to_digit(16))
+                .ok_or_else(|| ParseError::new(span, "invalid \\u escape"))?;
+            v = v * 16 + d;
+        }
+        Ok(v)
+    }
+
+    fn unicode_escape(&mut self, span: Span) -> Result<char, ParseError> {
+        let hi = self.hex4(span)?;
+        let code = if (0xD800..0xDC00).contains(&hi) {
+            // Surrogate pair
+            self.expect('\\')?;
+            self.expect('u')?;
+            let lo = self.hex4(span)?;
+            if !(0xDC00..0xE000).contains(&lo) {
+                return Err(ParseError::new(span, "invalid surrogate pair"));
+            }
+            0x10000 + ((hi - 0xD800) << 10) + (lo - 0xDC00)
+        } else {
+            hi
+        };
+        char::from_u32(code).ok_or_else(|| ParseError::new(span, "invalid unicode scalar"))
+    }
+
+    /// Inline value. Inside `[..]`/`{..}` bare scalars stop at `,` `]` `}`; at top level they run to the end.
+    pub(crate) fn inline_value(&mut self, nested: bool) -> Result<Node, ParseError> {
+        self.skip_ws();
+        let span = self.span();
+        match self.peek() {
+            None => Ok(Node::new(Value::Null, span)),
+            Some('"') => Ok(Node::new(Value::String(self.quoted()?), span)),
+            Some('[') => self.inline_list(),
+            Some('{') => self.inline_map(),
+            Some(_) => {
+                let mut raw = String::new();
+                while let Some(c) = self.peek() {
+                    if nested && matches!(c, ',' | ']' | '}') {
+                        break;
+                    }
+                    raw.push(c);
+                    self.pos += 1;
+                }
+                Ok(scalar(raw.trim(), span))
+            }
+        }
+    }
+
+    fn inline_list(&mut self) -> Result<Node, ParseError> {
+        let span = self.span();
+        self.expect('[')?;
+        let mut items = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.peek() == Some(']') {
+                self.pos += 1;
+                return Ok(Node::new(Value::List(items), span));
+            }
+            items.push(self.inline_value(true)?);
+            self.skip_ws();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {}
+                Some(c) => return Err(ParseError::new(self.span(), alloc::format!("expected `,` or `]`, found `{}`", c))),
+                None => return Err(ParseError::new(span, "unterminated list")),
+            }
+        }
+    }
+
+    fn inline_map(&mut self) -> Result<Node, ParseError> {
+        let span = self.span();
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.peek() == Some('}') {
+                self.pos += 1;
+                return Ok(Node::new(Value::Map(entries), span));
+            }
+            let key_span = self.span();
+            let mut name = String::new();
+            while let Some(c) = self.peek() {
+                if c == ':' || c.is_whitespace() {
+                    break;
+                }
+                name.push(c);
+                self.pos += 1;
+            }
+            self.skip_ws();
+            self.expect(':')?;
+            let value = self.inline_value(true)?;
+            entries.push((Key { name, span: key_span }, value));
+            self.skip_ws();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some('}') => {}
+                _ => return Err(ParseError::new(self.span(), "expected `,` or `}` in inline map")),
+            }
+        }
+    }
+}
+
+/// Classify a bare scalar: null, bool, number (keeping its spelling) or string
+pub(crate) fn scalar(raw: &str, span: Span) -> Node {
+    let value = match raw {
+        "" | "null" | "~" => Value::Null,
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => {
+            let numeric_start = raw.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+' || c == '.');
+            match raw.parse::<f64>() {
+                Ok(value) if numeric_start => Value::Number { value, raw: String::from(raw) },
+                _ => Value::String(String::from(raw)),
+            }
+        }
+    };
+    Node::new(value, span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_values_keep_spans() {
+        let mut cur = Cursor::from_segments(&[(Span::new(3, 5), "[1.0, \"a, b\", x]")]);
+        let node = cur.inline_value(false).unwrap();
+        let items = node.as_list().unwrap();
+        assert_eq!(items[0].as_text(), Some("1.0"));
+        assert_eq!(items[1].as_str(), Some("a, b"));
+        assert_eq!(items[2].span, Span::new(3, 19));
+        assert_eq!(scalar("1.0.0", Span::default()).as_str(), Some("1.0.0"));
+        assert_eq!(scalar("inf", Span::default()).as_str(), Some("inf"));
+    }
+
+    #[test]
+    fn test_unterminated_list_reports_its_start() {
+        let mut cur = Cursor::from_segments(&[(Span::new(7, 3), "[1, 2")]);
+        let err = cur.inline_value(false).unwrap_err();
+        assert_eq!(err.span, Span::new(7, 3));
+    }
+}
@@ -0,0 +1,164 @@
+//! Shard hexstamps: `0x` + the first 16 bytes of SHA-256 over the canonical form of the
+//! document with its top-level `hexstamp` entry removed. Canonical form ignores layout,
+//! comments and dialect, so re-indenting a shard does not change its stamp.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Write as _;
+
+use sha2::{Digest, Sha256};
+
+use crate::ast::{Document, Node, Value};
+
+/// Bytes of the SHA-256 digest kept in a stamp
+pub const STAMP_BYTES: usize = 16;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HexstampError {
+    Missing,
+    Mismatch { declared: String, computed: String },
+}
+
+impl fmt::Display for HexstampError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HexstampError::Missing => write!(f, "shard has no `hexstamp`"),
+            HexstampError::Mismatch { declared, computed } => {
+                write!(f, "declared hexstamp {} does not match content ({})", declared, computed)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HexstampError {}
+
+/// A shard as seen by the compiler; build scripts emit these so binaries can be checked
+/// against the shards deployed next to them
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompiledShard {
+    pub id: &'static str,
+    pub version: &'static str,
+    pub hexstamp: &'static str,
+}
+
+fn write_node(out: &mut String, node: &Node) {
+    match &node.value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number { raw, .. } => out.push_str(raw),
+        Value::String(s) => write_str(out, s),
+        Value::List(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_node(out, item);
+            }
+            out.push(']');
+        }
+        Value::Map(entries) => write_map(out, entries.iter().map(|(k, v)| (k.name.as_str(), v))),
+    }
+}
+
+fn write_map<'a>(out: &mut String, entries: impl Iterator<Item = (&'a str, &'a Node)>) {
+    out.push('{');
+    for (i, (key, value)) in entries.enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_str(out, key);
+        out.push(':');
+        write_node(out, value);
+    }
+    out.push('}');
+}
+
+fn write_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Compact JSON-like encoding of the document minus its top-level `hexstamp`; map order is kept
+pub fn canonical_bytes(doc: &Document) -> Vec<u8> {
+    let mut out = String::new();
+    match doc.root.as_map() {
+        Some(entries) => write_map(
+            &mut out,
+            entries.iter().filter(|(k, _)| k.name != "hexstamp").map(|(k, v)| (k.name.as_str(), v)),
+        ),
+        None => write_node(&mut out, &doc.root),
+    }
+    out.into_bytes()
+}
+
+/// The stamp this document should declare
+pub fn compute(doc: &Document) -> String {
+    let digest = Sha256::digest(canonical_bytes(doc));
+    let mut stamp = String::from("0x");
+    for byte in &digest[..STAMP_BYTES] {
+        let _ = write!(stamp, "{:02x}", byte);
+    }
+    stamp
+}
+
+/// Declared `hexstamp` equals the computed one (hex digits compared case-insensitively)
+pub fn verify(doc: &Document) -> Result<(), HexstampError> {
+    let declared = doc.get("hexstamp").and_then(Node::as_text).ok_or(HexstampError::Missing)?;
+    let computed = compute(doc);
+    if declared.eq_ignore_ascii_case(&computed) {
+        Ok(())
+    } else {
+        Err(HexstampError::Mismatch { declared: String::from(declared), computed })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, parse_as, Dialect};
+
+    #[test]
+    fn test_stamp_ignores_layout_but_not_content() {
+        let a = parse("id: x\nlimits:\n  max_roh: 0.3 # ceiling\nhexstamp: \"0x00\"\n").unwrap();
+        let b = parse("# reformatted\nid: x\nlimits: {max_roh: 0.3}\n").unwrap();
+        let c = parse("id: x\nlimits:\n  max_roh: 0.30\n").unwrap();
+        assert_eq!(compute(&a), compute(&b));
+        assert_ne!(compute(&a), compute(&c));
+        assert_eq!(compute(&a).len(), 2 + 2 * STAMP_BYTES);
+
+        let json = parse_as("{\"id\": \"x\", \"limits\": {\"max_roh\": 0.3}}", Dialect::Json).unwrap();
+        assert_eq!(compute(&a), compute(&json));
+    }
+
+    #[test]
+    fn test_repo_shards_carry_valid_stamps() {
+        for text in [
+            include_str!("../../../aln/decision.ledger.entry.v1.aln"),
+            include_str!("../../../aln/content.website.governance.v1.aln"),
+            include_str!("../../../aln/governance.totem.superposition.v1.aln"),
+            include_str!("../../../aln/phoenix-west-Pbee.aln"),
+            include_str!("../../../aln/bee-weighting.aln"),
+        ] {
+            let doc = parse(text).unwrap();
+            assert_eq!(verify(&doc), Ok(()), "{:?}", doc.get("id"));
+        }
+
+        let tampered = include_str!("../../../aln/decision.ledger.entry.v1.aln").replace("max_roh: 1.0", "max_roh: 2.0");
+        let doc = parse(&tampered).unwrap();
+        assert!(matches!(verify(&doc), Err(HexstampError::Mismatch { .. })));
+        assert_eq!(verify(&parse("id: x\n").unwrap()), Err(HexstampError::Missing));
+    }
+}
@@ -0,0 +1,123 @@
+//! JSON dialect (`errority-ratchet.v1.aln`). Strict JSON, except that repeated keys
+//! are kept in order since shards use repeated `"section"` markers.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ast::{Key, Node, Value};
+use crate::cursor::Cursor;
+use crate::ParseError;
+
+pub(crate) fn parse(text: &str) -> Result<Node, ParseError> {
+    let mut cur = Cursor::from_text(text);
+    let root = value(&mut cur)?;
+    cur.skip_ws();
+    if !cur.at_end() {
+        return Err(ParseError::new(cur.span(), "trailing content after JSON document"));
+    }
+    Ok(root)
+}
+
+fn value(cur: &mut Cursor) -> Result<Node, ParseError> {
+    cur.skip_ws();
+    let span = cur.span();
+    match cur.peek() {
+        Some('{') => {
+            cur.bump();
+            let mut entries = Vec::new();
+            cur.skip_ws();
+            if cur.peek() == Some('}') {
+                cur.bump();
+                return Ok(Node::new(Value::Map(entries), span));
+            }
+            loop {
+                cur.skip_ws();
+                let key_span = cur.span();
+                if cur.peek() != Some('"') {
+                    return Err(ParseError::new(key_span, "expected string key"));
+                }
+                let name = cur.quoted()?;
+                cur.skip_ws();
+                cur.expect(':')?;
+                let node = value(cur)?;
+                entries.push((Key { name, span: key_span }, node));
+                cur.skip_ws();
+                match cur.bump() {
+                    Some(',') => continue,
+                    Some('}') => return Ok(Node::new(Value::Map(entries), span)),
+                    _ => return Err(ParseError::new(cur.span(), "expected `,` or `}`")),
+                }
+            }
+        }
+        Some('[') => {
+            cur.bump();
+            let mut items = Vec::new();
+            cur.skip_ws();
+            if cur.peek() == Some(']') {
+                cur.bump();
+                return Ok(Node::new(Value::List(items), span));
+            }
+            loop {
+                items.push(value(cur)?);
+                cur.skip_ws();
+                match cur.bump() {
+                    Some(',') => continue,
+                    Some(']') => return Ok(Node::new(Value::List(items), span)),
+                    _ => return Err(ParseError::new(cur.span(), "expected `,` or `]`")),
+                }
+            }
+        }
+        Some('"') => Ok(Node::new(Value::String(cur.quoted()?), span)),
+        Some(c) if c == '-' || c.is_ascii_digit() => {
+            let mut raw = String::new();
+            while let Some(c) = cur.peek() {
+                if !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')) {
+                    break;
+                }
+                raw.push(c);
+                cur.bump();
+            }
+            let value = raw.parse::<f64>().map_err(|_| ParseError::new(span, "invalid number"))?;
+            Ok(Node::new(Value::Number { value, raw }, span))
+        }
+        Some(c) if c.is_ascii_alphabetic() => {
+            let mut word = String::new();
+            while let Some(c) = cur.peek().filter(char::is_ascii_alphabetic) {
+                word.push(c);
+                cur.bump();
+            }
+            let value = match word.as_str() {
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                "null" => Value::Null,
+                _ => return Err(ParseError::new(span, alloc::format!("unexpected literal `{}`", word))),
+            };
+            Ok(Node::new(value, span))
+        }
+        Some(c) => Err(ParseError::new(span, alloc::format!("unexpected character `{}`", c))),
+        None => Err(ParseError::new(span, "unexpected end of input")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::Span;
+
+    #[test]
+    fn test_duplicate_keys_and_escapes_survive() {
+        let root = parse("{\"section\": \"A\", \"x\": [1, -2.5e1], \"section\": \"B\", \"s\": \"\\u00e9\\n\"}").unwrap();
+        let sections: Vec<&str> = root.get_all("section").filter_map(Node::as_str).collect();
+        assert_eq!(sections, ["A", "B"]);
+        assert_eq!(root.get("x").unwrap().as_list().unwrap()[1].as_f64(), Some(-25.0));
+        assert_eq!(root.get("s").and_then(Node::as_str), Some("é\n"));
+    }
+
+    #[test]
+    fn test_errors_point_at_offending_token() {
+        let err = parse("{\n  \"a\": 1,\n  \"b\": tru\n}").unwrap_err();
+        assert_eq!(err.span, Span::new(3, 8));
+        let err = parse("{} {}").unwrap_err();
+        assert_eq!(err.span, Span::new(1, 4));
+    }
+}
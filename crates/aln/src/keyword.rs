@@ -0,0 +1,156 @@
+//! Keyword dialect (`cyconetics-grammar-v1.aln`): `key value` lines, entries grouped by
+//! `section Name`, and `[ ... ]` lists that may span several lines.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ast::{Key, Node, Span, Value};
+use crate::cursor::{scalar, Cursor};
+use crate::ParseError;
+
+/// Net `[`/`]` depth of a line, ignoring brackets inside quotes
+fn bracket_delta(text: &str) -> isize {
+    let (mut depth, mut in_quote, mut escaped) = (0, false, false);
+    for c in text.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quote => escaped = true,
+            '"' => in_quote = !in_quote,
+            '[' if !in_quote => depth += 1,
+            ']' if !in_quote => depth -= 1,
+            _ => {}
+        }
+    }
+    depth
+}
+
+/// Bare multi-word values nest: `aln version 1.0` reads as `aln: { version: 1.0 }`
+fn bare_value(rest: &str, span: Span) -> Node {
+    match rest.split_once(' ') {
+        Some((key, tail)) => {
+            let tail_trimmed = tail.trim_start();
+            let tail_col = span.column + rest.chars().count() - tail_trimmed.chars().count();
+            let key = Key { name: String::from(key), span };
+            let inner = bare_value(tail_trimmed, Span::new(span.line, tail_col));
+            Node::new(Value::Map(alloc::vec![(key, inner)]), span)
+        }
+        None => scalar(rest, span),
+    }
+}
+
+pub(crate) fn parse(text: &str) -> Result<Node, ParseError> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut root: Vec<(Key, Node)> = Vec::new();
+    let mut section: Option<(Key, Vec<(Key, Node)>)> = None;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let raw = lines[i];
+        let number = i + 1;
+        i += 1;
+        let content = raw.trim_start();
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+        let indent = raw.chars().count() - content.chars().count();
+        if raw.starts_with('\t') {
+            return Err(ParseError::new(Span::new(number, 1), "tabs are not allowed for indentation"));
+        }
+        let content = content.trim_end();
+        let key_span = Span::new(number, indent + 1);
+        let (name, rest) = content.split_once(char::is_whitespace).unwrap_or((content, ""));
+        let rest = rest.trim_start();
+        let value_span = Span::new(number, indent + 1 + content.chars().count() - rest.chars().count());
+
+        if indent == 0 && name == "section" {
+            if let Some((key, entries)) = section.take() {
+                root.push(close_section(key, entries));
+            }
+            if rest.is_empty() || rest.contains(char::is_whitespace) {
+                return Err(ParseError::new(value_span, "expected `section Name`"));
+            }
+            let entries = alloc::vec![(Key { name: String::from("name"), span: value_span }, scalar(rest, value_span))];
+            section = Some((Key { name: String::from("section"), span: key_span }, entries));
+            continue;
+        }
+        if indent == 0 {
+            if let Some((key, entries)) = section.take() {
+                root.push(close_section(key, entries));
+            }
+        } else if section.is_none() {
+            return Err(ParseError::new(key_span, "indented entry outside a section"));
+        }
+
+        let value = if rest.starts_with('[') {
+            let mut segments = alloc::vec![(value_span, rest)];
+            let mut depth = bracket_delta(rest);
+            while depth > 0 && i < lines.len() {
+                let next = lines[i];
+                let trimmed = next.trim();
+                let col = next.chars().count() - next.trim_start().chars().count() + 1;
+                segments.push((Span::new(i + 1, col), trimmed));
+                depth += bracket_delta(trimmed);
+                i += 1;
+            }
+            let mut cur = Cursor::from_segments(&segments);
+            let node = cur.inline_value(false)?;
+            cur.skip_ws();
+            if !cur.at_end() {
+                return Err(ParseError::new(cur.span(), "unexpected text after list"));
+            }
+            node
+        } else if rest.starts_with('"') {
+            let mut cur = Cursor::from_segments(&[(value_span, rest)]);
+            let node = Node::new(Value::String(cur.quoted()?), value_span);
+            cur.skip_ws();
+            if !cur.at_end() {
+                return Err(ParseError::new(cur.span(), "unexpected text after string"));
+            }
+            node
+        } else {
+            bare_value(rest, value_span)
+        };
+
+        let entry = (Key { name: String::from(name), span: key_span }, value);
+        match section.as_mut() {
+            Some((_, entries)) if indent > 0 => entries.push(entry),
+            _ => root.push(entry),
+        }
+    }
+    if let Some((key, entries)) = section.take() {
+        root.push(close_section(key, entries));
+    }
+    Ok(Node::new(Value::Map(root), Span::new(1, 1)))
+}
+
+fn close_section(key: Key, entries: Vec<(Key, Node)>) -> (Key, Node) {
+    let span = key.span;
+    (key, Node::new(Value::Map(entries), span))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections_and_multiline_lists() {
+        let text = "aln version 1.0\nid demo\n\nsection Guards\n  label \"Observers [v1]\"\n  refs [\n    \"a.rs\",\n    \"b.rs\"\n  ]\n  canonical true\n";
+        let root = parse(text).unwrap();
+        assert_eq!(root.path("aln.version").and_then(Node::as_text), Some("1.0"));
+        let section = root.get("section").unwrap();
+        assert_eq!(section.get("name").and_then(Node::as_str), Some("Guards"));
+        assert_eq!(section.get("label").and_then(Node::as_str), Some("Observers [v1]"));
+        let refs = section.get("refs").and_then(Node::as_list).unwrap();
+        assert_eq!(refs[1].as_str(), Some("b.rs"));
+        assert_eq!(refs[1].span, Span::new(8, 5));
+        assert_eq!(section.get("canonical").and_then(Node::as_bool), Some(true));
+    }
+
+    #[test]
+    fn test_rejects_orphan_indentation_and_open_lists() {
+        let err = parse("id demo\n  label x\n").unwrap_err();
+        assert_eq!(err.span, Span::new(2, 3));
+        let err = parse("section S\n  refs [\n    \"a\",\n").unwrap_err();
+        assert_eq!((err.span, err.message.as_str()), (Span::new(2, 8), "unterminated list"));
+    }
+}
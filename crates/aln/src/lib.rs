@@ -0,0 +1,126 @@
+//! Unified parser for the three ALN shard dialects: block (`aln/*.aln`), keyword/section
+//! (`cyconetics-grammar-v1.aln`) and JSON (`errority-ratchet.v1.aln`), all into one spanned AST.
+
+#![no_std]
+#![forbid(unsafe_code)]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod ast;
+mod block;
+mod cursor;
+pub mod hexstamp;
+mod json;
+mod keyword;
+mod registry;
+
+use alloc::string::String;
+use core::fmt;
+
+pub use ast::{Document, Key, Node, Section, Span, Value};
+pub use hexstamp::{CompiledShard, HexstampError};
+pub use registry::{AlnRegistry, RegistryError, ShardHeader};
+
+/// Source dialect of a parsed document
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dialect {
+    /// Indented `key: value` blocks, as in `aln/*.aln`
+    Block,
+    /// `key value` lines grouped under `section Name`
+    Keyword,
+    /// JSON object, duplicate keys preserved in order
+    Json,
+}
+
+impl Dialect {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Dialect::Block => "block",
+            Dialect::Keyword => "keyword",
+            Dialect::Json => "json",
+        }
+    }
+}
+
+/// Parse failure with the 1-based line/column where it was detected
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub span: Span,
+    pub message: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(span: Span, message: impl Into<String>) -> Self {
+        ParseError { span, message: message.into() }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.span.line, self.span.column, self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Guess the dialect from the first meaningful line
+pub fn detect(text: &str) -> Dialect {
+    let first = text
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty() && !l.starts_with('#'))
+        .unwrap_or("");
+    if first.starts_with('{') {
+        return Dialect::Json;
+    }
+    let head = first.split_whitespace().next().unwrap_or("");
+    if head.ends_with(':') || first.contains(": ") {
+        Dialect::Block
+    } else {
+        Dialect::Keyword
+    }
+}
+
+pub fn parse(text: &str) -> Result<Document, ParseError> {
+    parse_as(text, detect(text))
+}
+
+pub fn parse_as(text: &str, dialect: Dialect) -> Result<Document, ParseError> {
+    let root = match dialect {
+        Dialect::Block => block::parse(text)?,
+        Dialect::Keyword => keyword::parse(text)?,
+        Dialect::Json => json::parse(text)?,
+    };
+    Ok(Document { dialect, root })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_repo_dialects() {
+        assert_eq!(detect(include_str!("../../../aln/decision.ledger.entry.v1.aln")), Dialect::Block);
+        assert_eq!(detect(include_str!("../../../cyconetics-grammar-v1.aln")), Dialect::Keyword);
+        assert_eq!(detect(include_str!("../../../errority-ratchet.v1.aln")), Dialect::Json);
+    }
+
+    #[test]
+    fn test_repo_shards_share_one_ast() {
+        let ledger = parse(include_str!("../../../aln/decision.ledger.entry.v1.aln")).unwrap();
+        let grammar = parse(include_str!("../../../cyconetics-grammar-v1.aln")).unwrap();
+        let errority = parse(include_str!("../../../errority-ratchet.v1.aln")).unwrap();
+
+        assert_eq!(ledger.path("fields.roh_delta").and_then(Node::as_str), Some("float"));
+        assert_eq!(ledger.path("constraints.max_roh").and_then(Node::as_f64), Some(1.0));
+        assert_eq!(grammar.path("aln.version").and_then(Node::as_text), Some("1.0"));
+        assert_eq!(errority.get("id").and_then(Node::as_str), Some("errority-ratchet.v1.aln"));
+
+        let names: alloc::vec::Vec<&str> = errority.sections().iter().map(|s| s.name).collect();
+        assert_eq!(names.first(), Some(&"Errority_Event_Definition"));
+        assert!(grammar.sections().iter().any(|s| s.name == "Errority_Ratchet"));
+    }
+}
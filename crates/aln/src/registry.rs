@@ -0,0 +1,235 @@
+//! Shards keyed by `id`, with the common header (id/version/kind/hexstamp) pulled out of
+//! whichever dialect the shard was written in.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use crate::ast::{Document, Node};
+use crate::hexstamp::{self, CompiledShard, HexstampError};
+use crate::{Dialect, ParseError};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShardHeader {
+    pub id: String,
+    pub version: Option<String>,
+    pub kind: Option<String>,
+    pub hexstamp: Option<String>,
+    pub dialect: Dialect,
+    /// File path or other label the shard was loaded from
+    pub source: Option<String>,
+}
+
+impl ShardHeader {
+    /// `None` when the document has no `id`
+    pub fn from_document(doc: &Document, source: Option<&str>) -> Option<Self> {
+        let text = |path: &str| doc.path(path).and_then(Node::as_text).map(String::from);
+        Some(ShardHeader {
+            id: text("id")?,
+            version: text("version").or_else(|| text("aln_version")).or_else(|| text("aln.version")),
+            kind: text("kind"),
+            hexstamp: text("hexstamp"),
+            dialect: doc.dialect,
+            source: source.map(String::from),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegistryError {
+    Parse { source: String, error: ParseError },
+    MissingId { source: String },
+    DuplicateId { id: String, first: String, second: String },
+    Hexstamp { id: String, error: HexstampError },
+    NotLoaded { id: String },
+    /// Compiled against `compiled` (`version@hexstamp`) but `loaded` is deployed
+    CompiledMismatch { id: String, compiled: String, loaded: String },
+    #[cfg(feature = "std")]
+    Io { path: String, message: String },
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::Parse { source, error } => write!(f, "{}:{}", source, error),
+            RegistryError::MissingId { source } => write!(f, "{}: shard has no `id`", source),
+            RegistryError::DuplicateId { id, first, second } => {
+                write!(f, "shard id {} defined by both {} and {}", id, first, second)
+            }
+            RegistryError::Hexstamp { id, error } => write!(f, "{}: {}", id, error),
+            RegistryError::NotLoaded { id } => write!(f, "shard {} is not loaded", id),
+            RegistryError::CompiledMismatch { id, compiled, loaded } => {
+                write!(f, "shard {} compiled as {} but loaded as {}", id, compiled, loaded)
+            }
+            #[cfg(feature = "std")]
+            RegistryError::Io { path, message } => write!(f, "{}: {}", path, message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegistryError {}
+
+#[derive(Debug, Clone, Default)]
+pub struct AlnRegistry {
+    shards: BTreeMap<String, (ShardHeader, Document)>,
+}
+
+impl AlnRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a parsed document under its `id`
+    pub fn insert(&mut self, doc: Document, source: &str) -> Result<&ShardHeader, RegistryError> {
+        let header = ShardHeader::from_document(&doc, Some(source))
+            .ok_or_else(|| RegistryError::MissingId { source: source.to_string() })?;
+        if let Some((existing, _)) = self.shards.get(&header.id) {
+            return Err(RegistryError::DuplicateId {
+                id: header.id.clone(),
+                first: existing.source.clone().unwrap_or_default(),
+                second: source.to_string(),
+            });
+        }
+        let id = header.id.clone();
+        self.shards.insert(id.clone(), (header, doc));
+        Ok(&self.shards[&id].0)
+    }
+
+    pub fn parse_and_insert(&mut self, text: &str, source: &str) -> Result<&ShardHeader, RegistryError> {
+        let doc = crate::parse(text)
+            .map_err(|error| RegistryError::Parse { source: source.to_string(), error })?;
+        self.insert(doc, source)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Document> {
+        self.shards.get(id).map(|(_, doc)| doc)
+    }
+
+    pub fn header(&self, id: &str) -> Option<&ShardHeader> {
+        self.shards.get(id).map(|(header, _)| header)
+    }
+
+    /// Headers in id order
+    pub fn headers(&self) -> impl Iterator<Item = &ShardHeader> {
+        self.shards.values().map(|(header, _)| header)
+    }
+
+    /// Every shard's declared hexstamp matches its content
+    pub fn verify_hexstamps(&self) -> Result<(), RegistryError> {
+        for (id, (_, doc)) in &self.shards {
+            hexstamp::verify(doc).map_err(|error| RegistryError::Hexstamp { id: id.clone(), error })?;
+        }
+        Ok(())
+    }
+
+    /// The loaded shards are the ones a binary was compiled against: same version and a
+    /// hexstamp that both matches the compiled one and the shard content
+    pub fn check_compiled(&self, compiled: &[CompiledShard]) -> Result<(), RegistryError> {
+        for shard in compiled {
+            let (header, doc) =
+                self.shards.get(shard.id).ok_or_else(|| RegistryError::NotLoaded { id: shard.id.to_string() })?;
+            hexstamp::verify(doc).map_err(|error| RegistryError::Hexstamp { id: shard.id.to_string(), error })?;
+            let version = header.version.as_deref().unwrap_or_default();
+            let stamp = header.hexstamp.as_deref().unwrap_or_default();
+            if version != shard.version || !stamp.eq_ignore_ascii_case(shard.hexstamp) {
+                return Err(RegistryError::CompiledMismatch {
+                    id: shard.id.to_string(),
+                    compiled: alloc::format!("{}@{}", shard.version, shard.hexstamp),
+                    loaded: alloc::format!("{}@{}", version, stamp),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.shards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shards.is_empty()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn load_file(&mut self, path: &std::path::Path) -> Result<&ShardHeader, RegistryError> {
+        let source = path.display().to_string();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| RegistryError::Io { path: source.clone(), message: e.to_string() })?;
+        self.parse_and_insert(&text, &source)
+    }
+
+    /// Every `*.aln` file directly under `dir`, in file-name order
+    #[cfg(feature = "std")]
+    pub fn load_dir(&mut self, dir: &std::path::Path) -> Result<usize, RegistryError> {
+        let io = |e: std::io::Error| RegistryError::Io { path: dir.display().to_string(), message: e.to_string() };
+        let mut paths = std::vec::Vec::new();
+        for entry in std::fs::read_dir(dir).map_err(io)? {
+            let path = entry.map_err(io)?.path();
+            if path.extension().is_some_and(|ext| ext == "aln") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        for path in &paths {
+            self.load_file(path)?;
+        }
+        Ok(paths.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headers_from_every_dialect() {
+        let mut reg = AlnRegistry::new();
+        reg.parse_and_insert(include_str!("../../../aln/phoenix-west-Pbee.aln"), "Pbee").unwrap();
+        reg.parse_and_insert(include_str!("../../../cyconetics-grammar-v1.aln"), "grammar").unwrap();
+        reg.parse_and_insert(include_str!("../../../errority-ratchet.v1.aln"), "errority").unwrap();
+
+        let bee = reg.header("phoenix-west-Pbee").unwrap();
+        assert_eq!(bee.kind.as_deref(), Some("corridor_polytope"));
+        assert!(bee.hexstamp.as_deref().is_some_and(|h| h.starts_with("0x")));
+        assert_eq!(reg.header("cyconetics-grammar-v1.aln").unwrap().version.as_deref(), Some("1.0"));
+        assert_eq!(reg.header("errority-ratchet.v1.aln").unwrap().dialect, Dialect::Json);
+        assert_eq!(reg.len(), 3);
+    }
+
+    #[test]
+    fn test_rejects_duplicate_and_missing_ids() {
+        let mut reg = AlnRegistry::new();
+        reg.parse_and_insert("id: a\n", "one.aln").unwrap();
+        assert!(matches!(reg.parse_and_insert("id: a\n", "two.aln"), Err(RegistryError::DuplicateId { .. })));
+        assert!(matches!(reg.parse_and_insert("kind: x\n", "three.aln"), Err(RegistryError::MissingId { .. })));
+        let err = reg.parse_and_insert("id: b\n  x: 1\n", "four.aln").unwrap_err();
+        assert_eq!(err.to_string(), "four.aln:2:3: unexpected indentation");
+    }
+
+    #[test]
+    fn test_check_compiled_catches_stale_binaries() {
+        let mut reg = AlnRegistry::new();
+        reg.parse_and_insert(include_str!("../../../aln/decision.ledger.entry.v1.aln"), "ledger").unwrap();
+        // CompiledShard holds the 'static strings a build script would emit
+        let stamp: &'static str = reg.header("decision.ledger.entry.v1").unwrap().hexstamp.clone().unwrap().leak();
+
+        let built = CompiledShard { id: "decision.ledger.entry.v1", version: "1.0.0", hexstamp: stamp };
+        assert_eq!(reg.check_compiled(&[built]), Ok(()));
+        let stale = CompiledShard { hexstamp: "0x00", ..built };
+        assert!(matches!(reg.check_compiled(&[stale]), Err(RegistryError::CompiledMismatch { .. })));
+        let missing = CompiledShard { id: "bee-weighting", ..built };
+        assert!(matches!(reg.check_compiled(&[missing]), Err(RegistryError::NotLoaded { .. })));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_load_dir_covers_repo_shards() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../aln");
+        let mut reg = AlnRegistry::new();
+        let n = reg.load_dir(&dir).unwrap();
+        assert_eq!(n, reg.len());
+        assert!(reg.get("decision.ledger.entry.v1").is_some());
+        assert_eq!(reg.verify_hexstamps(), Ok(()));
+    }
+}
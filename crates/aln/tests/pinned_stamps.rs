@@ -0,0 +1,84 @@
+//! Hexstamps pinned by hand in files no build script reads, checked against the shards
+//! under `aln/`.
+
+use std::path::Path;
+
+use aln::AlnRegistry;
+
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+fn registry() -> AlnRegistry {
+    let mut registry = AlnRegistry::new();
+    registry.load_dir(&Path::new(ROOT).join("aln")).unwrap();
+    registry.verify_hexstamps().unwrap();
+    registry
+}
+
+fn stamp_of(registry: &AlnRegistry, id: &str) -> String {
+    let header = registry.header(id).unwrap_or_else(|| panic!("no shard {}", id));
+    header.hexstamp.clone().unwrap()
+}
+
+fn read(path: &str) -> String {
+    std::fs::read_to_string(Path::new(ROOT).join(path)).unwrap()
+}
+
+/// The quoted value after each `prefix`, e.g. `name: "` → the text up to the closing quote.
+fn quoted_after<'a>(text: &'a str, prefix: &str) -> Vec<&'a str> {
+    text.match_indices(prefix)
+        .map(|(at, _)| {
+            let rest = &text[at + prefix.len()..];
+            &rest[..rest.find('"').unwrap()]
+        })
+        .collect()
+}
+
+/// Every `0x`-prefixed hex literal of at least six digits in `text`.
+fn hex_literals(text: &str) -> Vec<&str> {
+    text.match_indices("0x")
+        .map(|(at, _)| {
+            let digits = text[at + 2..].find(|c: char| !c.is_ascii_hexdigit()).unwrap_or(text.len() - at - 2);
+            &text[at..at + 2 + digits]
+        })
+        .filter(|literal| literal.len() >= 8)
+        .collect()
+}
+
+#[test]
+fn test_cookbook_pages_pin_their_page_shards() {
+    let registry = registry();
+    let asset = aln::parse(&read("cookbook/website.asset.yaml")).unwrap();
+    let pages = asset.get("pages").and_then(aln::Node::as_list).unwrap();
+    assert_eq!(pages.len(), 2);
+    for page in pages {
+        let shard = page.get("shard").and_then(aln::Node::as_text).unwrap();
+        let pinned = page.get("hexstamp").and_then(aln::Node::as_text).unwrap();
+        assert_eq!(pinned, stamp_of(&registry, shard), "page {:?}", page.get("id"));
+    }
+
+    assert_eq!(hex_literals(&read("cookbook/website.asset.yaml")).len(), pages.len());
+
+    let policy = asset.path("anchors.content_policy").and_then(aln::Node::as_text).unwrap();
+    assert!(registry.header(policy).is_some());
+}
+
+#[test]
+fn test_website_route_pins_the_governance_page() {
+    let registry = registry();
+    let route = read("crates/cyberretrieval-router/src/website.rs");
+    let pinned = quoted_after(&route, "GOVERNANCE_PAGE_HEXSTAMP: &str = \"");
+    assert_eq!(pinned, [stamp_of(&registry, "website.page.governance.v1")]);
+    assert_eq!(hex_literals(&route), pinned);
+}
+
+#[test]
+fn test_cookbook_readme_pins_its_envelope() {
+    let registry = registry();
+    let readme = read("crates/cyber-retrieval-router/docs/examples/README.md");
+    let shard = quoted_after(&readme, "envelope_shard: \"");
+    assert_eq!(shard, ["cybernetic.cookbook.envelope.v1"]);
+
+    // Front matter and footer both carry it, and nothing else looks like a stamp.
+    let stamp = stamp_of(&registry, shard[0]);
+    assert_eq!(hex_literals(&readme), [stamp.as_str(), stamp.as_str()]);
+}
@@ -0,0 +1,503 @@
+// File: biosafety-guards/src/corridor_shards.rs
+// Role: Parse corridor polytope shards (<corridor>-Peco/Pbee/Ptree/Pservice.aln) and bee-weighting.aln
+// Compliance: cyconetics-grammar-v1.aln, section EcoAdmissible_Polytopes
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use aln::{hexstamp, Dialect, HexstampError, Key, Node};
+
+use crate::polytope::{Polytope, PolytopeError};
+use crate::{CorridorState, EcoBeeTreeGuard};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShardError {
+    Syntax { shard: String, line: usize, column: usize, message: String },
+    MissingField { shard: String, field: &'static str },
+    WrongKind { shard: String, expected: &'static str, got: String },
+    UnknownPolytope { shard: String, name: String },
+    UnknownDimension { shard: String, name: String },
+    DimensionMismatch { shard: String, expected: usize, got: usize },
+    CorridorMismatch { shard: String, expected: String, got: String },
+    DuplicateKind { shard: String, kind: &'static str },
+    Polytope { shard: String, error: PolytopeError },
+    Hexstamp { shard: String, error: HexstampError },
+    #[cfg(feature = "std")]
+    Io { path: String, message: String },
+}
+
+impl fmt::Display for ShardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShardError::Syntax { shard, line, column, message } => {
+                write!(f, "{}:{}:{}: {}", shard, line, column, message)
+            }
+            ShardError::MissingField { shard, field } => write!(f, "{}: missing `{}`", shard, field),
+            ShardError::WrongKind { shard, expected, got } => {
+                write!(f, "{}: kind `{}`, expected `{}`", shard, got, expected)
+            }
+            ShardError::UnknownPolytope { shard, name } => write!(f, "{}: unknown polytope `{}`", shard, name),
+            ShardError::UnknownDimension { shard, name } => write!(f, "{}: unknown dimension `{}`", shard, name),
+            ShardError::DimensionMismatch { shard, expected, got } => {
+                write!(f, "{}: {} dimensions, expected {}", shard, got, expected)
+            }
+            ShardError::CorridorMismatch { shard, expected, got } => {
+                write!(f, "{}: corridor `{}`, expected `{}`", shard, got, expected)
+            }
+            ShardError::DuplicateKind { shard, kind } => write!(f, "{}: second `{}` shard", shard, kind),
+            ShardError::Polytope { shard, error } => write!(f, "{}: {}", shard, error),
+            ShardError::Hexstamp { shard, error } => write!(f, "{}: {}", shard, error),
+            #[cfg(feature = "std")]
+            ShardError::Io { path, message } => write!(f, "{}: {}", path, message),
+        }
+    }
+}
+
+/// The four corridor polytopes named by the grammar
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolytopeKind {
+    Peco,
+    Pbee,
+    Ptree,
+    Pservice,
+}
+
+impl PolytopeKind {
+    pub const ALL: [PolytopeKind; 4] = [PolytopeKind::Peco, PolytopeKind::Pbee, PolytopeKind::Ptree, PolytopeKind::Pservice];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PolytopeKind::Peco => "Peco",
+            PolytopeKind::Pbee => "Pbee",
+            PolytopeKind::Ptree => "Ptree",
+            PolytopeKind::Pservice => "Pservice",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|k| k.as_str() == name)
+    }
+}
+
+/// One `kind: corridor_polytope` shard
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolytopeShard {
+    pub id: String,
+    pub corridor: String,
+    pub kind: PolytopeKind,
+    pub dimensions: Vec<String>,
+    pub polytope: Polytope,
+    pub hexstamp: String,
+}
+
+/// One `kind: hazard_weights` shard (λᵢ per named dimension)
+#[derive(Clone, Debug, PartialEq)]
+pub struct HazardWeights {
+    pub id: String,
+    pub corridor: String,
+    pub applies_to: PolytopeKind,
+    pub weights: Vec<(String, f32)>,
+    pub hexstamp: String,
+}
+
+/// A parsed shard plus the name used in error messages
+struct Doc {
+    shard: String,
+    root: Node,
+}
+
+impl Doc {
+    fn parse(shard: &str, text: &str) -> Result<Self, ShardError> {
+        let doc = aln::parse_as(text, Dialect::Block).map_err(|e| ShardError::Syntax {
+            shard: shard.to_string(),
+            line: e.span.line,
+            column: e.span.column,
+            message: e.message,
+        })?;
+        // A shard edited without restamping is rejected, not silently loaded
+        hexstamp::verify(&doc).map_err(|error| ShardError::Hexstamp { shard: shard.to_string(), error })?;
+        Ok(Doc { shard: shard.to_string(), root: doc.root })
+    }
+
+    fn node(&self, key: &'static str) -> Result<&Node, ShardError> {
+        self.root.get(key).ok_or(ShardError::MissingField { shard: self.shard.clone(), field: key })
+    }
+
+    fn scalar(&self, key: &'static str) -> Result<&str, ShardError> {
+        let node = self.node(key)?;
+        node.as_text().ok_or_else(|| self.syntax(node, "expected a scalar"))
+    }
+
+    fn list(&self, key: &'static str) -> Result<&[Node], ShardError> {
+        let node = self.node(key)?;
+        node.as_list().ok_or_else(|| self.syntax(node, "expected a list"))
+    }
+
+    fn map(&self, key: &'static str) -> Result<&[(Key, Node)], ShardError> {
+        let node = self.node(key)?;
+        node.as_map().ok_or_else(|| self.syntax(node, "expected `name: value` entries"))
+    }
+
+    fn expect_kind(&self, expected: &'static str) -> Result<(), ShardError> {
+        let got = self.scalar("kind")?;
+        if got != expected {
+            return Err(ShardError::WrongKind { shard: self.shard.clone(), expected, got: got.to_string() });
+        }
+        Ok(())
+    }
+
+    fn syntax(&self, node: &Node, message: &str) -> ShardError {
+        ShardError::Syntax {
+            shard: self.shard.clone(),
+            line: node.span.line,
+            column: node.span.column,
+            message: message.to_string(),
+        }
+    }
+
+    fn number(&self, node: &Node) -> Result<f32, ShardError> {
+        node.as_f64().map(|v| v as f32).ok_or_else(|| self.syntax(node, "expected a number"))
+    }
+
+    /// `faces:` entries as (a, b); an empty polytope when the shard has none
+    fn faces(&self) -> Result<Vec<(Vec<f32>, f32)>, ShardError> {
+        let Some(faces) = self.root.get("faces") else {
+            return Ok(Vec::new());
+        };
+        let faces = faces.as_list().ok_or_else(|| self.syntax(faces, "expected a list of faces"))?;
+        faces
+            .iter()
+            .map(|face| {
+                let a = face.get("a").ok_or_else(|| self.syntax(face, "face is missing `a`"))?;
+                let b = face.get("b").ok_or_else(|| self.syntax(face, "face is missing `b`"))?;
+                let row = a
+                    .as_list()
+                    .ok_or_else(|| self.syntax(a, "expected [a1, a2, ...]"))?
+                    .iter()
+                    .map(|c| self.number(c))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok((row, self.number(b)?))
+            })
+            .collect()
+    }
+}
+
+fn hexstamp(doc: &Doc) -> String {
+    doc.scalar("hexstamp").map(|h| h.to_string()).unwrap_or_default()
+}
+
+impl PolytopeShard {
+    pub fn parse(shard: &str, text: &str) -> Result<Self, ShardError> {
+        let doc = Doc::parse(shard, text)?;
+        doc.expect_kind("corridor_polytope")?;
+        let name = doc.scalar("polytope")?;
+        let kind = PolytopeKind::from_name(name)
+            .ok_or_else(|| ShardError::UnknownPolytope { shard: doc.shard.clone(), name: name.to_string() })?;
+        let dimensions = doc
+            .list("dimensions")?
+            .iter()
+            .map(|d| d.as_text().map(String::from).ok_or_else(|| doc.syntax(d, "expected a dimension name")))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut polytope = Polytope::unconstrained(dimensions.len());
+        for (a, b) in doc.faces()? {
+            polytope
+                .add_face(&a, b)
+                .map_err(|error| ShardError::Polytope { shard: doc.shard.clone(), error })?;
+        }
+
+        Ok(PolytopeShard {
+            id: doc.scalar("id")?.to_string(),
+            corridor: doc.scalar("corridor")?.to_string(),
+            kind,
+            dimensions,
+            polytope,
+            hexstamp: hexstamp(&doc),
+        })
+    }
+}
+
+impl HazardWeights {
+    pub fn parse(shard: &str, text: &str) -> Result<Self, ShardError> {
+        let doc = Doc::parse(shard, text)?;
+        doc.expect_kind("hazard_weights")?;
+        let target = doc.scalar("applies_to")?;
+        let applies_to = PolytopeKind::from_name(target)
+            .ok_or_else(|| ShardError::UnknownPolytope { shard: doc.shard.clone(), name: target.to_string() })?;
+        let weights = doc
+            .map("weights")?
+            .iter()
+            .map(|(name, v)| Ok((name.name.clone(), doc.number(v)?)))
+            .collect::<Result<Vec<_>, ShardError>>()?;
+
+        Ok(HazardWeights {
+            id: doc.scalar("id")?.to_string(),
+            corridor: doc.scalar("corridor")?.to_string(),
+            applies_to,
+            weights,
+            hexstamp: hexstamp(&doc),
+        })
+    }
+}
+
+/// A corridor's four polytopes over one shared coordinate system, plus hazard weights
+#[derive(Clone, Debug, PartialEq)]
+pub struct CorridorPolytopes {
+    pub corridor: String,
+    pub dimensions: Vec<String>,
+    shards: Vec<PolytopeShard>, // ordered as PolytopeKind::ALL
+    weights: Option<HazardWeights>,
+}
+
+impl CorridorPolytopes {
+    /// Assemble from exactly one shard per PolytopeKind; all shards, and the weights,
+    /// must agree on corridor and dimensions
+    pub fn new(shards: Vec<PolytopeShard>, weights: Option<HazardWeights>) -> Result<Self, ShardError> {
+        let mut ordered = Vec::new();
+        for kind in PolytopeKind::ALL {
+            let mut of_kind = shards.iter().filter(|s| s.kind == kind);
+            let shard = of_kind
+                .next()
+                .ok_or(ShardError::MissingField { shard: String::from("corridor"), field: kind.as_str() })?;
+            if let Some(dup) = of_kind.next() {
+                return Err(ShardError::DuplicateKind { shard: dup.id.clone(), kind: kind.as_str() });
+            }
+            ordered.push(shard.clone());
+        }
+
+        let first = &ordered[0];
+        for shard in &ordered[1..] {
+            if shard.corridor != first.corridor {
+                return Err(ShardError::CorridorMismatch {
+                    shard: shard.id.clone(),
+                    expected: first.corridor.clone(),
+                    got: shard.corridor.clone(),
+                });
+            }
+            if shard.dimensions != first.dimensions {
+                return Err(ShardError::DimensionMismatch {
+                    shard: shard.id.clone(),
+                    expected: first.dimensions.len(),
+                    got: shard.dimensions.len(),
+                });
+            }
+        }
+        if let Some(w) = &weights {
+            if w.corridor != first.corridor {
+                return Err(ShardError::CorridorMismatch {
+                    shard: w.id.clone(),
+                    expected: first.corridor.clone(),
+                    got: w.corridor.clone(),
+                });
+            }
+            if let Some((name, _)) = w.weights.iter().find(|(n, _)| !first.dimensions.contains(n)) {
+                return Err(ShardError::UnknownDimension { shard: w.id.clone(), name: name.clone() });
+            }
+        }
+
+        Ok(CorridorPolytopes {
+            corridor: first.corridor.clone(),
+            dimensions: first.dimensions.clone(),
+            shards: ordered,
+            weights,
+        })
+    }
+
+    pub fn polytope(&self, kind: PolytopeKind) -> &Polytope {
+        &self.shards[kind as usize].polytope
+    }
+
+    /// λ for a dimension (1.0 when unweighted)
+    pub fn hazard_weight(&self, dimension: &str) -> f32 {
+        self.weights
+            .as_ref()
+            .and_then(|w| w.weights.iter().find(|(n, _)| n == dimension))
+            .map(|(_, l)| *l)
+            .unwrap_or(1.0)
+    }
+
+    /// Polytope with λᵢ applied to positive (stressor) coefficients of its target shard
+    pub fn weighted(&self, kind: PolytopeKind) -> Polytope {
+        let base = self.polytope(kind);
+        let applies = self.weights.as_ref().map(|w| w.applies_to == kind).unwrap_or(false);
+        if !applies {
+            return base.clone();
+        }
+        let mut weighted = Polytope::unconstrained(base.dim());
+        for i in 0..base.face_count() {
+            if let Some((a, b)) = base.face(i) {
+                let row: Vec<f32> = a
+                    .iter()
+                    .zip(&self.dimensions)
+                    .map(|(c, dim)| if *c > 0.0 { c * self.hazard_weight(dim) } else { *c })
+                    .collect();
+                // Rows already validated on load, so this cannot fail
+                let _ = weighted.add_face(&row, b);
+            }
+        }
+        weighted
+    }
+
+    /// x_proj must use this corridor's coordinate system
+    pub fn validate_state(&self, corridor: &CorridorState) -> Result<(), ShardError> {
+        if corridor.x_proj.len() != self.dimensions.len() {
+            return Err(ShardError::DimensionMismatch {
+                shard: corridor.corridor_id.clone(),
+                expected: self.dimensions.len(),
+                got: corridor.x_proj.len(),
+            });
+        }
+        Ok(())
+    }
+
+    /// (shard id, declared hexstamp) for every loaded shard
+    pub fn hexstamps(&self) -> Vec<(String, String)> {
+        self.shards
+            .iter()
+            .map(|s| (s.id.clone(), s.hexstamp.clone()))
+            .chain(self.weights.iter().map(|w| (w.id.clone(), w.hexstamp.clone())))
+            .collect()
+    }
+
+    pub fn guard(&self, warn_margin: f32) -> EcoBeeTreeGuard {
+        EcoBeeTreeGuard {
+            peco: self.weighted(PolytopeKind::Peco),
+            pbee: self.weighted(PolytopeKind::Pbee),
+            ptree: self.weighted(PolytopeKind::Ptree),
+            pservice: self.weighted(PolytopeKind::Pservice),
+            warn_margin,
+        }
+    }
+}
+
+/// Read `<corridor>-P*.aln` and `bee-weighting.aln` (if present) from a shard directory
+#[cfg(feature = "std")]
+pub fn load_corridor(dir: &std::path::Path, corridor: &str) -> Result<CorridorPolytopes, ShardError> {
+    let read = |name: &str| {
+        let path = dir.join(name);
+        std::fs::read_to_string(&path).map_err(|e| ShardError::Io {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })
+    };
+
+    let mut shards = Vec::new();
+    for kind in PolytopeKind::ALL {
+        let name = alloc::format!("{}-{}.aln", corridor, kind.as_str());
+        shards.push(PolytopeShard::parse(&name, &read(&name)?)?);
+    }
+    let weights = if dir.join("bee-weighting.aln").exists() {
+        Some(HazardWeights::parse("bee-weighting.aln", &read("bee-weighting.aln")?)?)
+    } else {
+        None
+    };
+    CorridorPolytopes::new(shards, weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::vec;
+
+    fn stamped(text: &str) -> String {
+        let stamp = hexstamp::compute(&aln::parse(text).unwrap());
+        format!("{}hexstamp: \"{}\"\n", text, stamp)
+    }
+
+    fn phoenix_west_shards() -> (Vec<PolytopeShard>, HazardWeights) {
+        let shards = vec![
+            PolytopeShard::parse("Peco", include_str!("../../../aln/phoenix-west-Peco.aln")).unwrap(),
+            PolytopeShard::parse("Pbee", include_str!("../../../aln/phoenix-west-Pbee.aln")).unwrap(),
+            PolytopeShard::parse("Ptree", include_str!("../../../aln/phoenix-west-Ptree.aln")).unwrap(),
+            PolytopeShard::parse("Pservice", include_str!("../../../aln/phoenix-west-Pservice.aln")).unwrap(),
+        ];
+        let weights = HazardWeights::parse("bee-weighting", include_str!("../../../aln/bee-weighting.aln")).unwrap();
+        (shards, weights)
+    }
+
+    fn phoenix_west() -> CorridorPolytopes {
+        let (shards, weights) = phoenix_west_shards();
+        CorridorPolytopes::new(shards, Some(weights)).unwrap()
+    }
+
+    #[test]
+    fn test_phoenix_west_shards_load() {
+        let corridor = phoenix_west();
+        assert_eq!(corridor.corridor, "phoenix-west");
+        assert_eq!(corridor.dimensions.len(), 4);
+        assert_eq!(corridor.polytope(PolytopeKind::Pbee).face_count(), 4);
+        assert_eq!(corridor.hazard_weight("rf_dbm"), 1.5);
+        assert_eq!(corridor.hexstamps().len(), 5);
+        assert!(corridor.hexstamps().iter().all(|(_, h)| h.starts_with("0x")));
+
+        // λ_rf = 1.5 turns rf ≤ 15 dBm into rf ≤ 10 dBm for bees
+        let x = [0.1, 12.0, 0.05, 40.0];
+        assert!(corridor.polytope(PolytopeKind::Pbee).contains(&x));
+        assert!(!corridor.weighted(PolytopeKind::Pbee).contains(&x));
+    }
+
+    #[test]
+    fn test_dimensionality_is_validated() {
+        let corridor = phoenix_west();
+        let mut state = CorridorState {
+            corridor_id: String::from("phoenix-west"),
+            x_proj: vec![0.1, 8.0, 0.05],
+            in_peco: true,
+            in_pbee: true,
+            in_ptree: true,
+            in_pservice: true,
+            bee_hb_rating: 9.7,
+        };
+        assert!(matches!(corridor.validate_state(&state), Err(ShardError::DimensionMismatch { .. })));
+        state.x_proj.push(40.0);
+        assert!(corridor.validate_state(&state).is_ok());
+
+        let bad = "id: x\nkind: corridor_polytope\ncorridor: c\npolytope: Pbee\ndimensions:\n  - a\n  - b\nfaces:\n  - a: [1.0]\n    b: 1.0\n";
+        assert!(matches!(PolytopeShard::parse("x", &stamped(bad)), Err(ShardError::Polytope { .. })));
+
+        let typo = stamped(&bad.replace("b: 1.0", "b: one"));
+        let err = PolytopeShard::parse("x", &typo).unwrap_err();
+        assert_eq!(err.to_string(), "x:10:8: expected a number");
+    }
+
+    #[test]
+    fn test_shards_from_another_corridor_are_rejected() {
+        let (mut shards, weights) = phoenix_west_shards();
+        shards[2].corridor = String::from("tucson-east");
+        let err = CorridorPolytopes::new(shards, Some(weights)).unwrap_err();
+        assert!(matches!(err, ShardError::CorridorMismatch { ref got, .. } if got == "tucson-east"));
+
+        // Weights tuned for another corridor would silently reweight this one
+        let (shards, mut weights) = phoenix_west_shards();
+        weights.corridor = String::from("tucson-east");
+        let err = CorridorPolytopes::new(shards, Some(weights)).unwrap_err();
+        assert!(matches!(err, ShardError::CorridorMismatch { ref shard, .. } if shard == "bee-weighting"));
+    }
+
+    #[test]
+    fn test_duplicate_kind_is_rejected() {
+        let (mut shards, weights) = phoenix_west_shards();
+        let mut second = shards[1].clone();
+        second.id = String::from("phoenix-west-Pbee-loose");
+        shards.push(second);
+        let err = CorridorPolytopes::new(shards, Some(weights)).unwrap_err();
+        assert_eq!(err, ShardError::DuplicateKind { shard: String::from("phoenix-west-Pbee-loose"), kind: "Pbee" });
+    }
+
+    #[test]
+    fn test_restamp_required_after_edit() {
+        let edited = include_str!("../../../aln/phoenix-west-Pbee.aln").replace("b: 15.0", "b: 25.0");
+        let err = PolytopeShard::parse("Pbee", &edited).unwrap_err();
+        assert!(matches!(err, ShardError::Hexstamp { error: HexstampError::Mismatch { .. }, .. }));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_load_corridor_from_disk() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../aln");
+        assert_eq!(load_corridor(&dir, "phoenix-west").unwrap(), phoenix_west());
+        assert!(matches!(load_corridor(&dir, "tucson-east"), Err(ShardError::Io { .. })));
+    }
+}
@@ -6,10 +6,18 @@
 
 #![no_std]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
+pub mod corridor_shards;
+pub mod polytope;
+
+pub use corridor_shards::{CorridorPolytopes, HazardWeights, PolytopeKind, PolytopeShard, ShardError};
+pub use polytope::{project, Polytope, PolytopeError};
+
 /// BioState: outer-domain biophysical telemetry only, no neural content
 #[derive(Clone, Copy, Debug)]
 pub struct BioState {
@@ -62,6 +70,16 @@ pub trait SafetyGuard {
     ) -> ActionVerdict;
 
     fn name(&self) -> &'static str;
+
+    /// Corridor-aware evaluation; guards that ignore the corridor keep the default
+    fn evaluate_in_corridor(
+        &self,
+        state: &BioState,
+        _corridor: &CorridorState,
+        proposal: &ActionProposal,
+    ) -> ActionVerdict {
+        self.evaluate(state, proposal)
+    }
 }
 
 /// BciCeilingGuard: enforces BCI ≤ 0.30 hard ceiling
@@ -217,8 +235,33 @@ impl SafetyGuard for BiomechPolicyGuard {
     }
 }
 
-/// EcoBeeTreeGuard: enforces corridor polytope membership
-pub struct EcoBeeTreeGuard;
+/// EcoBeeTreeGuard: enforces corridor polytope membership on the predicted state
+pub struct EcoBeeTreeGuard {
+    pub peco: Polytope,
+    pub pbee: Polytope,
+    pub ptree: Polytope,
+    pub pservice: Polytope,
+    pub warn_margin: f32,     // slack below which the action is degraded, e.g. 0.05
+}
+
+impl EcoBeeTreeGuard {
+    /// P_corr := P_eco ∩ P_bee ∩ P_tree ∩ P_service
+    pub fn p_corr(&self) -> Result<Polytope, PolytopeError> {
+        self.peco
+            .intersect(&self.pbee)?
+            .intersect(&self.ptree)?
+            .intersect(&self.pservice)
+    }
+
+    /// Recompute the membership flags from x_proj instead of trusting the caller
+    pub fn refresh(&self, corridor: &mut CorridorState) {
+        let x = &corridor.x_proj;
+        corridor.in_peco = self.peco.contains(x);
+        corridor.in_pbee = self.pbee.contains(x);
+        corridor.in_ptree = self.ptree.contains(x);
+        corridor.in_pservice = self.pservice.contains(x);
+    }
+}
 
 impl SafetyGuard for EcoBeeTreeGuard {
     fn evaluate(
@@ -226,7 +269,35 @@ impl SafetyGuard for EcoBeeTreeGuard {
         _state: &BioState,
         _proposal: &ActionProposal,
     ) -> ActionVerdict {
-        // In full implementation, check if projected state stays within polytopes
+        // Without a corridor there is no predicted state to check
+        ActionVerdict::PauseAndRest
+    }
+
+    fn evaluate_in_corridor(
+        &self,
+        _state: &BioState,
+        corridor: &CorridorState,
+        proposal: &ActionProposal,
+    ) -> ActionVerdict {
+        let predicted = match project(&corridor.x_proj, &proposal.env_impact) {
+            Ok(x) => x,
+            Err(_) => return ActionVerdict::PauseAndRest,
+        };
+
+        let margin = match self.p_corr().and_then(|p| p.margin(&predicted)) {
+            Ok(m) => m,
+            Err(_) => return ActionVerdict::PauseAndRest,
+        };
+
+        if margin < -polytope::FACE_EPSILON {
+            // Predicted state leaves the corridor
+            return ActionVerdict::PauseAndRest;
+        }
+
+        if margin < self.warn_margin {
+            return ActionVerdict::DegradePrecision;
+        }
+
         ActionVerdict::AllowFullAction
     }
 
@@ -242,11 +313,11 @@ pub fn action_allowed(
     proposal: &ActionProposal,
     guards: &[&dyn SafetyGuard],
 ) -> (ActionVerdict, String) {
-    // Require all corridor checks pass
+    // Caller-supplied flags can only tighten; EcoBeeTreeGuard re-derives membership from x_proj
     if !corridor.in_peco || !corridor.in_pbee || !corridor.in_ptree || !corridor.in_pservice {
         return (
             ActionVerdict::PauseAndRest,
-            alloc::format!("Corridor polytopes violated"),
+            "Corridor polytopes violated".to_string(),
         );
     }
 
@@ -255,7 +326,7 @@ pub fn action_allowed(
     let mut reason = alloc::string::String::from("AllowFullAction");
 
     for guard in guards {
-        let verdict = guard.evaluate(bio, proposal);
+        let verdict = guard.evaluate_in_corridor(bio, corridor, proposal);
         if verdict == ActionVerdict::PauseAndRest {
             worst_verdict = ActionVerdict::PauseAndRest;
             reason = alloc::format!("{} -> PauseAndRest", guard.name());
@@ -285,9 +356,38 @@ pub struct ErrorityRecord {
     pub severity: String,    // "Minor", "Moderate", "Severe", "Critical"
 }
 
+impl Default for BioState {
+    fn default() -> Self {
+        BioState {
+            bci_star: 0.1,
+            roh: 0.1,
+            hrv_sdnn: 50.0,
+            pain_score: 0.0,
+            fatigue_index: 0.1,
+            nanoswarm_density: 0.05,
+            eco_stress: 0.0,
+        }
+    }
+}
+
+impl Default for ActionProposal {
+    fn default() -> Self {
+        ActionProposal {
+            action_id: "default".to_string(),
+            module_id: "default".to_string(),
+            action_kind: "default".to_string(),
+            bci_delta: 0.0,
+            roh_delta: 0.0,
+            env_impact: alloc::vec![],
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
 
     #[test]
     fn test_bci_ceiling_guard() {
@@ -319,7 +419,7 @@ mod tests {
 
         let proposal_warn = ActionProposal {
             bci_delta: 0.08, // Would reach 0.28 (warning)
-            ..proposal_safe
+            ..proposal_safe.clone()
         };
 
         assert_eq!(
@@ -372,31 +472,52 @@ mod tests {
             ActionVerdict::AllowFullAction
         );
     }
-}
 
-impl Default for BioState {
-    fn default() -> Self {
-        BioState {
-            bci_star: 0.1,
-            roh: 0.1,
-            hrv_sdnn: 50.0,
-            pain_score: 0.0,
-            fatigue_index: 0.1,
-            nanoswarm_density: 0.05,
-            eco_stress: 0.0,
-        }
-    }
-}
+    #[test]
+    fn test_eco_bee_tree_guard_uses_predicted_state() {
+        // x = [rf_dbm, toxin_index]
+        let rf_cap = Polytope::from_faces(2, &[(vec![1.0, 0.0], 15.0)]).unwrap();
+        let toxin_cap = Polytope::from_faces(2, &[(vec![0.0, 1.0], 0.4)]).unwrap();
+        let guard = EcoBeeTreeGuard {
+            peco: toxin_cap,
+            pbee: rf_cap,
+            ptree: Polytope::unconstrained(2),
+            pservice: Polytope::unconstrained(2),
+            warn_margin: 0.05,
+        };
 
-impl Default for ActionProposal {
-    fn default() -> Self {
-        ActionProposal {
-            action_id: "default".to_string(),
-            module_id: "default".to_string(),
-            action_kind: "default".to_string(),
-            bci_delta: 0.0,
-            roh_delta: 0.0,
-            env_impact: alloc::vec![],
-        }
+        let mut corridor = CorridorState {
+            corridor_id: "phoenix-west".to_string(),
+            x_proj: vec![10.0, 0.1],
+            in_peco: false,
+            in_pbee: false,
+            in_ptree: false,
+            in_pservice: false,
+            bee_hb_rating: 9.7,
+        };
+        guard.refresh(&mut corridor);
+        assert!(corridor.in_peco && corridor.in_pbee);
+
+        let state = BioState::default();
+        let quiet = ActionProposal { env_impact: vec![1.0, 0.0], ..Default::default() };
+        assert_eq!(guard.evaluate_in_corridor(&state, &corridor, &quiet), ActionVerdict::AllowFullAction);
+
+        let near_edge = ActionProposal { env_impact: vec![0.0, 0.28], ..Default::default() };
+        assert_eq!(guard.evaluate_in_corridor(&state, &corridor, &near_edge), ActionVerdict::DegradePrecision);
+
+        // Caller flags say "inside", but the projected RF leaves P_bee
+        let loud = ActionProposal { env_impact: vec![8.0, 0.0], ..Default::default() };
+        assert_eq!(guard.evaluate_in_corridor(&state, &corridor, &loud), ActionVerdict::PauseAndRest);
+        let (verdict, reason) = action_allowed(&state, &corridor, &loud, &[&guard]);
+        assert_eq!(verdict, ActionVerdict::PauseAndRest);
+        assert_eq!(reason, "EcoBeeTreeGuard -> PauseAndRest");
+
+        // NaN compares false against every face, so it must fail closed rather than slip through
+        let nan = ActionProposal { env_impact: vec![f32::NAN, 0.0], ..Default::default() };
+        assert_eq!(guard.evaluate_in_corridor(&state, &corridor, &nan), ActionVerdict::PauseAndRest);
+        corridor.x_proj = vec![f32::NAN, 0.1];
+        guard.refresh(&mut corridor);
+        assert!(!corridor.in_pbee && !corridor.in_ptree);
+        assert_eq!(guard.evaluate_in_corridor(&state, &corridor, &quiet), ActionVerdict::PauseAndRest);
     }
 }
@@ -0,0 +1,218 @@
+// File: biosafety-guards/src/polytope.rs
+// Role: H-representation corridor polytopes (A·x ≤ b) for EcoBeeTreeGuard
+// Compliance: cyconetics-grammar-v1.aln (P_corr := P_eco ∩ P_bee ∩ P_tree ∩ P_service)
+
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Tolerance for membership tests so boundary points are not rejected by rounding
+pub const FACE_EPSILON: f32 = 1e-6;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PolytopeError {
+    DimensionMismatch { expected: usize, got: usize },
+    FaceOutOfRange { face: usize, faces: usize },
+    WidenFace { face: usize, current: f32, proposed: f32 }, // faces may only shrink
+    NonFinite, // NaN or infinite coefficient, bound or coordinate
+}
+
+impl fmt::Display for PolytopeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PolytopeError::DimensionMismatch { expected, got } => {
+                write!(f, "DimensionMismatch: expected {}, got {}", expected, got)
+            }
+            PolytopeError::FaceOutOfRange { face, faces } => {
+                write!(f, "FaceOutOfRange: face {} of {}", face, faces)
+            }
+            PolytopeError::WidenFace { face, current, proposed } => {
+                write!(f, "WidenFace: face {} b {} -> {}", face, current, proposed)
+            }
+            PolytopeError::NonFinite => write!(f, "NonFinite value"),
+        }
+    }
+}
+
+/// Convex polytope {x : A·x ≤ b}; A is stored row-major, one row per face
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polytope {
+    dim: usize,
+    a: Vec<f32>,
+    b: Vec<f32>,
+}
+
+impl Polytope {
+    /// The whole space R^dim (no faces)
+    pub fn unconstrained(dim: usize) -> Self {
+        Polytope { dim, a: Vec::new(), b: Vec::new() }
+    }
+
+    pub fn from_faces(dim: usize, faces: &[(Vec<f32>, f32)]) -> Result<Self, PolytopeError> {
+        let mut p = Self::unconstrained(dim);
+        for (a, b) in faces {
+            p.add_face(a, *b)?;
+        }
+        Ok(p)
+    }
+
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    pub fn face_count(&self) -> usize {
+        self.b.len()
+    }
+
+    /// Row `i` of A and its bound b_i
+    pub fn face(&self, i: usize) -> Option<(&[f32], f32)> {
+        let b = *self.b.get(i)?;
+        Some((&self.a[i * self.dim..(i + 1) * self.dim], b))
+    }
+
+    /// Intersect with the half-space a·x ≤ b
+    pub fn add_face(&mut self, a: &[f32], b: f32) -> Result<(), PolytopeError> {
+        if a.len() != self.dim {
+            return Err(PolytopeError::DimensionMismatch { expected: self.dim, got: a.len() });
+        }
+        if !b.is_finite() || a.iter().any(|c| !c.is_finite()) {
+            return Err(PolytopeError::NonFinite);
+        }
+        self.a.extend_from_slice(a);
+        self.b.push(b);
+        Ok(())
+    }
+
+    /// Lower b_i; raising it would widen the corridor and is rejected
+    pub fn shrink_face(&mut self, face: usize, b: f32) -> Result<(), PolytopeError> {
+        let faces = self.face_count();
+        let current = self
+            .b
+            .get_mut(face)
+            .ok_or(PolytopeError::FaceOutOfRange { face, faces })?;
+        if !b.is_finite() {
+            return Err(PolytopeError::NonFinite);
+        }
+        if b > *current {
+            return Err(PolytopeError::WidenFace { face, current: *current, proposed: b });
+        }
+        *current = b;
+        Ok(())
+    }
+
+    /// P ∩ Q: the union of both face sets
+    pub fn intersect(&self, other: &Polytope) -> Result<Polytope, PolytopeError> {
+        if other.dim != self.dim {
+            return Err(PolytopeError::DimensionMismatch { expected: self.dim, got: other.dim });
+        }
+        let mut p = self.clone();
+        p.a.extend_from_slice(&other.a);
+        p.b.extend_from_slice(&other.b);
+        Ok(p)
+    }
+
+    /// Per-face slack b_i − a_i·x; negative entries are violated faces.
+    /// A NaN would compare false against every face, so non-finite input is an error.
+    pub fn slacks(&self, x: &[f32]) -> Result<Vec<f32>, PolytopeError> {
+        if x.len() != self.dim {
+            return Err(PolytopeError::DimensionMismatch { expected: self.dim, got: x.len() });
+        }
+        if x.iter().any(|c| !c.is_finite()) {
+            return Err(PolytopeError::NonFinite);
+        }
+        (0..self.face_count())
+            .map(|i| {
+                let row = &self.a[i * self.dim..(i + 1) * self.dim];
+                let ax: f32 = row.iter().zip(x).map(|(a, x)| a * x).sum();
+                let slack = self.b[i] - ax;
+                if slack.is_nan() {
+                    return Err(PolytopeError::NonFinite); // overflowing terms of opposite sign
+                }
+                Ok(slack)
+            })
+            .collect()
+    }
+
+    /// Smallest slack over all faces (f32::INFINITY when unconstrained)
+    pub fn margin(&self, x: &[f32]) -> Result<f32, PolytopeError> {
+        Ok(self.slacks(x)?.into_iter().fold(f32::INFINITY, f32::min))
+    }
+
+    pub fn contains(&self, x: &[f32]) -> bool {
+        self.margin(x).map(|m| m >= -FACE_EPSILON).unwrap_or(false)
+    }
+}
+
+/// x_proj + Δx: predicted corridor state after an action
+pub fn project(x_proj: &[f32], env_impact: &[f32]) -> Result<Vec<f32>, PolytopeError> {
+    let projected: Vec<f32> = if env_impact.is_empty() {
+        x_proj.to_vec() // no declared environmental impact
+    } else if env_impact.len() != x_proj.len() {
+        return Err(PolytopeError::DimensionMismatch { expected: x_proj.len(), got: env_impact.len() });
+    } else {
+        x_proj.iter().zip(env_impact).map(|(x, d)| x + d).collect()
+    };
+    if projected.iter().any(|c| !c.is_finite()) {
+        return Err(PolytopeError::NonFinite);
+    }
+    Ok(projected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn unit_box() -> Polytope {
+        Polytope::from_faces(
+            2,
+            &[
+                (vec![1.0, 0.0], 1.0),
+                (vec![-1.0, 0.0], 0.0),
+                (vec![0.0, 1.0], 1.0),
+                (vec![0.0, -1.0], 0.0),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_membership_and_intersection() {
+        let p = unit_box();
+        assert!(p.contains(&[0.5, 0.5]));
+        assert!(p.contains(&[1.0, 1.0]));
+        assert!(!p.contains(&[1.2, 0.5]));
+        assert!(!p.contains(&[0.5])); // wrong dimension is never admissible
+
+        let half = Polytope::from_faces(2, &[(vec![1.0, 1.0], 1.0)]).unwrap();
+        let both = p.intersect(&half).unwrap();
+        assert_eq!(both.face_count(), 5);
+        assert!(both.contains(&[0.4, 0.4]));
+        assert!(!both.contains(&[0.8, 0.8]));
+        assert!(p.intersect(&Polytope::unconstrained(3)).is_err());
+    }
+
+    #[test]
+    fn test_faces_only_shrink() {
+        let mut p = unit_box();
+        p.shrink_face(0, 0.5).unwrap();
+        assert!(!p.contains(&[0.8, 0.5]));
+        assert_eq!(
+            p.shrink_face(0, 0.9),
+            Err(PolytopeError::WidenFace { face: 0, current: 0.5, proposed: 0.9 })
+        );
+        assert!(matches!(p.shrink_face(9, 0.1), Err(PolytopeError::FaceOutOfRange { .. })));
+    }
+
+    #[test]
+    fn test_non_finite_coordinates_are_rejected() {
+        let p = unit_box();
+        assert_eq!(p.margin(&[f32::NAN, 0.5]), Err(PolytopeError::NonFinite));
+        assert_eq!(p.slacks(&[0.5, f32::INFINITY]), Err(PolytopeError::NonFinite));
+        assert!(!p.contains(&[f32::NAN, f32::NAN]));
+        assert!(!Polytope::unconstrained(2).contains(&[f32::NAN, 0.0]));
+
+        assert_eq!(project(&[0.5, 0.5], &[f32::NAN, 0.0]), Err(PolytopeError::NonFinite));
+        assert_eq!(project(&[f32::MAX, 0.0], &[f32::MAX, 0.0]), Err(PolytopeError::NonFinite));
+        assert_eq!(project(&[f32::NAN, 0.0], &[]), Err(PolytopeError::NonFinite));
+    }
+}
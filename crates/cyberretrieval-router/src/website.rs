@@ -1,16 +1,20 @@
-use neurorights_core::{NeurorightsBound, NeurorightsEnvelope};
-use neurorights_firewall::PromptEnvelope;
+use neurorights_firewall::{NeurorightsBound, NeurorightsEnvelope, PromptEnvelope};
 
 use cyberretrieval_website_governance::{
     handlers::{handle_website_governance, WebsiteGovArgs, WebsiteGovEnvelope},
     risk::RiskEnvelope,
 };
 
+/// Hexstamp of `website.page.governance.v1`, the governance page record this route
+/// serves; the cookbook YAML pins the same stamp for `/governance`. Checked against the
+/// shard by `crates/aln/tests/pinned_stamps.rs`.
+pub const GOVERNANCE_PAGE_HEXSTAMP: &str = "0x4ac66155df280e83a97d2dffa3cff53e";
+
 pub fn website_page_route(
     env: NeurorightsBound<PromptEnvelope<WebsiteGovArgs>, NeurorightsEnvelope>,
 ) -> Result<(), WebsiteRouteError> {
     // Construct default RiskEnvelope for this operation
-    let risk = RiskEnvelope::default("0x4F91C7AB39D62E11");
+    let risk = RiskEnvelope::default(GOVERNANCE_PAGE_HEXSTAMP);
 
     // ALN-derived governance constraints and RoH ceiling are enforced
     let decision = handle_website_governance(env, risk)?;
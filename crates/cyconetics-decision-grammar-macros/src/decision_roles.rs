@@ -0,0 +1,276 @@
+use std::collections::BTreeSet;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, Ident, Token};
+
+mod kw {
+    syn::custom_keyword!(class);
+}
+
+const UPGRADE_CLASSES: [&str; 4] = ["BCI", "EXO", "XR", "GOV"];
+const DECISION_VERBS: [&str; 5] = ["Approve", "Authorize", "Defer", "Reject", "Escalate"];
+const HOST_ROLE: &str = "HostSelf";
+
+struct RoleTable(Vec<ClassDecl>);
+
+struct ClassDecl {
+    name: Ident,
+    roles: Vec<RoleDecl>,
+}
+
+struct RoleDecl {
+    name: Ident,
+    verbs: Vec<Ident>,
+}
+
+impl Parse for RoleTable {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut classes = Vec::new();
+        while !input.is_empty() {
+            classes.push(input.parse()?);
+        }
+        Ok(RoleTable(classes))
+    }
+}
+
+impl Parse for ClassDecl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::class>()?;
+        let name: Ident = input.parse()?;
+        let content;
+        braced!(content in input);
+        let roles = Punctuated::<RoleDecl, Token![,]>::parse_terminated(&content)?;
+        Ok(ClassDecl { name, roles: roles.into_iter().collect() })
+    }
+}
+
+impl Parse for RoleDecl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let content;
+        braced!(content in input);
+        let verbs = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+        Ok(RoleDecl { name, verbs: verbs.into_iter().collect() })
+    }
+}
+
+impl RoleTable {
+    fn validate(&self) -> syn::Result<()> {
+        let mut classes = BTreeSet::new();
+        for class in &self.0 {
+            if !UPGRADE_CLASSES.iter().any(|c| class.name == c) {
+                return Err(syn::Error::new(
+                    class.name.span(),
+                    format!(
+                        "unknown upgrade class `{}`; expected one of: {}",
+                        class.name,
+                        UPGRADE_CLASSES.join(", ")
+                    ),
+                ));
+            }
+            if !classes.insert(class.name.to_string()) {
+                return Err(syn::Error::new(class.name.span(), format!("duplicate class `{}`", class.name)));
+            }
+
+            let mut roles = BTreeSet::new();
+            for role in &class.roles {
+                if !roles.insert(role.name.to_string()) {
+                    return Err(syn::Error::new(
+                        role.name.span(),
+                        format!("duplicate role `{}` in class {}", role.name, class.name),
+                    ));
+                }
+                let mut verbs = BTreeSet::new();
+                for verb in &role.verbs {
+                    if !DECISION_VERBS.iter().any(|v| verb == v) {
+                        return Err(syn::Error::new(
+                            verb.span(),
+                            format!(
+                                "unknown decision verb `{}`; expected one of: {}",
+                                verb,
+                                DECISION_VERBS.join(", ")
+                            ),
+                        ));
+                    }
+                    if !verbs.insert(verb.to_string()) {
+                        return Err(syn::Error::new(verb.span(), format!("duplicate verb `{}`", verb)));
+                    }
+                }
+            }
+
+            // The host veto is structural: no class may drop it
+            match class.roles.iter().find(|r| r.name == HOST_ROLE) {
+                None => {
+                    return Err(syn::Error::new(
+                        class.name.span(),
+                        format!("class {} must declare a HostSelf role with Reject (host veto)", class.name),
+                    ))
+                },
+                Some(host) if !host.verbs.iter().any(|v| v == "Reject") => {
+                    return Err(syn::Error::new(
+                        host.name.span(),
+                        format!("HostSelf in class {} must keep the Reject veto", class.name),
+                    ))
+                },
+                Some(_) => {},
+            }
+        }
+        Ok(())
+    }
+
+    fn generate(&self) -> syn::Result<TokenStream> {
+        self.validate()?;
+
+        let mut role_names: Vec<&Ident> = Vec::new();
+        for role in self.0.iter().flat_map(|c| &c.roles) {
+            if !role_names.iter().any(|r| **r == role.name) {
+                role_names.push(&role.name);
+            }
+        }
+        let role_strs: Vec<String> = role_names.iter().map(|r| r.to_string()).collect();
+
+        let rows = self.0.iter().flat_map(|class| {
+            let class_ident = &class.name;
+            class.roles.iter().map(move |role| {
+                let role_str = role.name.to_string();
+                let verbs: Vec<String> = role.verbs.iter().map(|v| v.to_string()).collect();
+                quote! {
+                    ::cyconetics_decision_grammar::roles::RolePermission {
+                        class: ::cyconetics_decision_grammar::roh_guard::UpgradeClass::#class_ident,
+                        role: #role_str,
+                        verbs: &[#(#verbs),*],
+                    }
+                }
+            })
+        });
+
+        let class_modules = self.0.iter().map(|class| {
+            let class_ident = &class.name;
+            let module = format_ident!("{}", class.name.to_string().to_lowercase(), span = class.name.span());
+            let doc = format!("Role markers for upgrade class {}", class.name);
+            let markers = class.roles.iter().map(|role| {
+                let role_ident = &role.name;
+                let role_str = role.name.to_string();
+                let verbs = &role.verbs;
+                let veto = if role.name == HOST_ROLE {
+                    quote! {
+                        impl ::cyconetics_decision_grammar::roles::__private::Sealed<
+                            ::cyconetics_decision_grammar::roles::__private::Veto
+                        > for #role_ident {}
+                        impl ::cyconetics_decision_grammar::roles::HostVeto for #role_ident {}
+                    }
+                } else {
+                    quote! {}
+                };
+                quote! {
+                    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+                    pub struct #role_ident;
+
+                    impl ::cyconetics_decision_grammar::roles::__private::Sealed for #role_ident {}
+
+                    impl ::cyconetics_decision_grammar::roles::RoleMarker for #role_ident {
+                        const ROLE: &'static str = #role_str;
+                        const CLASS: ::cyconetics_decision_grammar::roh_guard::UpgradeClass =
+                            ::cyconetics_decision_grammar::roh_guard::UpgradeClass::#class_ident;
+                    }
+
+                    #(
+                        impl ::cyconetics_decision_grammar::roles::__private::Sealed<
+                            ::cyconetics_decision_grammar::roles::verbs::#verbs
+                        > for #role_ident {}
+                        impl ::cyconetics_decision_grammar::roles::Decides<
+                            ::cyconetics_decision_grammar::roles::verbs::#verbs
+                        > for #role_ident {}
+                    )*
+
+                    #veto
+                }
+            });
+            quote! {
+                #[doc = #doc]
+                pub mod #module {
+                    #(#markers)*
+                }
+            }
+        });
+
+        Ok(quote! {
+            /// Roles declared in the `decision_roles!` table
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum Role {
+                #(#role_names),*
+            }
+
+            impl Role {
+                pub fn name(&self) -> &'static str {
+                    match self {
+                        #(Role::#role_names => #role_strs),*
+                    }
+                }
+
+                pub fn can_decide(
+                    &self,
+                    verb: &::cyconetics_decision_grammar::types::DecisionKind,
+                    class: ::cyconetics_decision_grammar::roh_guard::UpgradeClass,
+                ) -> bool {
+                    can_role_decide(*self, verb, class)
+                }
+            }
+
+            /// The declared table; `can_role_decide` and `role_table` both read from it
+            pub const ROLE_PERMISSIONS: &[::cyconetics_decision_grammar::roles::RolePermission] = &[
+                #(#rows),*
+            ];
+
+            pub fn can_role_decide(
+                role: Role,
+                verb: &::cyconetics_decision_grammar::types::DecisionKind,
+                class: ::cyconetics_decision_grammar::roh_guard::UpgradeClass,
+            ) -> bool {
+                let verb = verb.verb();
+                ROLE_PERMISSIONS
+                    .iter()
+                    .any(|p| p.class == class && p.role == role.name() && p.verbs.contains(&verb))
+            }
+
+            /// Table in the shape stored in `DecisionGrammarPolicyShard.role_permissions`
+            pub fn role_table() -> ::cyconetics_decision_grammar::roles::RolePermissionTable {
+                ::cyconetics_decision_grammar::roles::RolePermissionTable::from_static(ROLE_PERMISSIONS)
+            }
+
+            #(#class_modules)*
+        })
+    }
+}
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let table: RoleTable = syn::parse2(input)?;
+    table.generate()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_of(src: &str) -> String {
+        expand(src.parse().unwrap()).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_host_veto_cannot_be_omitted() {
+        assert!(error_of("class BCI { SafetyDaemon => { Reject, Escalate } }")
+            .contains("must declare a HostSelf role"));
+        assert!(error_of("class XR { HostSelf => { Authorize, Escalate } }")
+            .contains("must keep the Reject veto"));
+    }
+
+    #[test]
+    fn test_unknown_class_and_verb() {
+        assert!(error_of("class NEURO { HostSelf => { Reject } }").contains("unknown upgrade class"));
+        assert!(error_of("class BCI { HostSelf => { Reject, Rejected } }").contains("unknown decision verb"));
+    }
+}
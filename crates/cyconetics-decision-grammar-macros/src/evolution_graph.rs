@@ -0,0 +1,525 @@
+use std::collections::HashMap;
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{braced, Ident, Token};
+
+mod kw {
+    syn::custom_keyword!(evolution);
+    syn::custom_keyword!(node);
+    syn::custom_keyword!(edge);
+}
+
+/// Paths must stay strictly below this unless they pass a guard edge
+const ROH_CEILING: f64 = 0.30;
+const EPSILON: f64 = 1e-9;
+const DECISION_KINDS: [&str; 5] = ["Approve", "Authorize", "Defer", "Reject", "Escalate"];
+const GUARD_KINDS: [&str; 2] = ["Reject", "Escalate"];
+/// Bound on DFS steps so pathological graphs fail fast instead of hanging rustc
+const MAX_STEPS: usize = 1 << 16;
+
+struct GraphSet(Vec<Graph>);
+
+struct Graph {
+    name: Ident,
+    nodes: Vec<NodeDecl>,
+    edges: Vec<EdgeDecl>,
+}
+
+struct NodeDecl {
+    name: Ident,
+    roh_delta: f64,
+}
+
+struct EdgeDecl {
+    span: Span,
+    from: Ident,
+    to: Ident,
+    kind: Ident,
+    roh_delta: f64,
+}
+
+impl EdgeDecl {
+    fn is_guard(&self) -> bool {
+        GUARD_KINDS.iter().any(|k| self.kind == k)
+    }
+}
+
+impl Parse for GraphSet {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut graphs = Vec::new();
+        while !input.is_empty() {
+            graphs.push(input.parse()?);
+        }
+        Ok(GraphSet(graphs))
+    }
+}
+
+impl Parse for Graph {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::evolution>()?;
+        let name: Ident = input.parse()?;
+        let content;
+        braced!(content in input);
+
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        while !content.is_empty() {
+            if content.peek(kw::node) {
+                content.parse::<kw::node>()?;
+                let name: Ident = content.parse()?;
+                let mut roh_delta = 0.0;
+                if content.peek(Token![=>]) {
+                    content.parse::<Token![=>]>()?;
+                }
+                if content.peek(syn::token::Brace) {
+                    let fields = parse_fields(&content, &["roh_delta"])?;
+                    roh_delta = fields.roh_delta.unwrap_or(0.0);
+                }
+                nodes.push(NodeDecl { name, roh_delta });
+            } else if content.peek(kw::edge) {
+                let keyword = content.parse::<kw::edge>()?;
+                let from: Ident = content.parse()?;
+                content.parse::<Token![->]>()?;
+                let to: Ident = content.parse()?;
+                let fields = parse_fields(&content, &["kind", "roh_delta"])?;
+                let kind = fields
+                    .kind
+                    .ok_or_else(|| syn::Error::new(keyword.span, "edge is missing `kind`"))?;
+                edges.push(EdgeDecl {
+                    span: keyword.span,
+                    from,
+                    to,
+                    kind,
+                    roh_delta: fields.roh_delta.unwrap_or(0.0),
+                });
+            } else {
+                return Err(content.error("expected `node` or `edge`"));
+            }
+            if content.peek(Token![;]) {
+                content.parse::<Token![;]>()?;
+            }
+        }
+
+        Ok(Graph { name, nodes, edges })
+    }
+}
+
+#[derive(Default)]
+struct Fields {
+    kind: Option<Ident>,
+    roh_delta: Option<f64>,
+}
+
+/// `{ kind: Authorize, roh_delta: 0.08 }`, restricted to `allowed` keys
+fn parse_fields(input: ParseStream, allowed: &[&str]) -> syn::Result<Fields> {
+    let body;
+    braced!(body in input);
+    let mut fields = Fields::default();
+    while !body.is_empty() {
+        let key: Ident = body.parse()?;
+        body.parse::<Token![:]>()?;
+        if !allowed.iter().any(|a| key == a) {
+            return Err(syn::Error::new(
+                key.span(),
+                format!("unexpected field `{}`; expected one of: {}", key, allowed.join(", ")),
+            ));
+        }
+        if key == "kind" {
+            if fields.kind.is_some() {
+                return Err(syn::Error::new(key.span(), "duplicate field `kind`"));
+            }
+            fields.kind = Some(body.parse()?);
+        } else {
+            if fields.roh_delta.is_some() {
+                return Err(syn::Error::new(key.span(), "duplicate field `roh_delta`"));
+            }
+            fields.roh_delta = Some(parse_delta(&body)?);
+        }
+        if body.peek(Token![,]) {
+            body.parse::<Token![,]>()?;
+        }
+    }
+    Ok(fields)
+}
+
+fn parse_delta(input: ParseStream) -> syn::Result<f64> {
+    let negative = if input.peek(Token![-]) {
+        input.parse::<Token![-]>()?;
+        true
+    } else {
+        false
+    };
+    let value = match input.parse::<syn::Lit>()? {
+        syn::Lit::Float(f) => f.base10_parse::<f64>()?,
+        syn::Lit::Int(i) => i.base10_parse::<f64>()?,
+        other => return Err(syn::Error::new(other.span(), "expected a numeric roh_delta")),
+    };
+    Ok(if negative { -value } else { value })
+}
+
+/// Result of walking every unguarded path
+struct Analysis {
+    max_unguarded: f64,
+}
+
+struct Walker<'a> {
+    graph: &'a Graph,
+    index: HashMap<String, usize>,
+    outgoing: Vec<Vec<usize>>,
+    stack: Vec<(usize, f64)>,
+    visited: Vec<bool>,
+    max_unguarded: f64,
+    steps: usize,
+}
+
+impl<'a> Walker<'a> {
+    fn node_delta(&self, node: usize) -> f64 {
+        self.graph.nodes[node].roh_delta
+    }
+
+    fn path_to(&self, next: usize) -> String {
+        self.stack
+            .iter()
+            .map(|(n, _)| n)
+            .chain(std::iter::once(&next))
+            .map(|&n| self.graph.nodes[n].name.to_string())
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+
+    fn start(&mut self, node: usize) -> syn::Result<()> {
+        let roh = self.node_delta(node);
+        if roh >= ROH_CEILING - EPSILON {
+            return Err(syn::Error::new(
+                self.graph.nodes[node].name.span(),
+                format!("node `{}` alone reaches RoH {:.2} (ceiling 0.30)", self.graph.nodes[node].name, roh),
+            ));
+        }
+        self.walk(node, roh)
+    }
+
+    fn walk(&mut self, node: usize, roh: f64) -> syn::Result<()> {
+        self.steps += 1;
+        if self.steps > MAX_STEPS {
+            return Err(syn::Error::new(
+                self.graph.name.span(),
+                "evolution graph has too many paths to analyse",
+            ));
+        }
+        self.visited[node] = true;
+        self.max_unguarded = self.max_unguarded.max(roh);
+        self.stack.push((node, roh));
+
+        for e in self.outgoing[node].clone() {
+            let edge = &self.graph.edges[e];
+            if edge.is_guard() {
+                continue;
+            }
+            let to = self.index[&edge.to.to_string()];
+            let next = roh + edge.roh_delta + self.node_delta(to);
+
+            if next >= ROH_CEILING - EPSILON {
+                return Err(syn::Error::new(
+                    edge.span,
+                    format!(
+                        "evolution `{}`: path {} reaches RoH {:.2} without a Reject or Escalate edge (ceiling 0.30)",
+                        self.graph.name,
+                        self.path_to(to),
+                        next
+                    ),
+                ));
+            }
+
+            if let Some(&(_, before)) = self.stack.iter().find(|(n, _)| *n == to) {
+                if next - before > EPSILON {
+                    return Err(syn::Error::new(
+                        edge.span,
+                        format!(
+                            "evolution `{}`: cycle {} gains RoH {:.2} per lap without a Reject or Escalate edge",
+                            self.graph.name,
+                            self.path_to(to),
+                            next - before
+                        ),
+                    ));
+                }
+                continue;
+            }
+
+            self.walk(to, next)?;
+        }
+
+        self.stack.pop();
+        Ok(())
+    }
+}
+
+impl Graph {
+    fn validate(&self) -> syn::Result<(HashMap<String, usize>, Analysis)> {
+        if self.nodes.is_empty() {
+            return Err(syn::Error::new(self.name.span(), "evolution graph declares no nodes"));
+        }
+
+        let mut index = HashMap::new();
+        for (i, node) in self.nodes.iter().enumerate() {
+            if node.name == "Node" {
+                return Err(syn::Error::new(node.name.span(), "`Node` is reserved for the generated state enum"));
+            }
+            if index.insert(node.name.to_string(), i).is_some() {
+                return Err(syn::Error::new(node.name.span(), format!("duplicate node `{}`", node.name)));
+            }
+        }
+
+        let mut outgoing = vec![Vec::new(); self.nodes.len()];
+        let mut seen = HashMap::new();
+        for (e, edge) in self.edges.iter().enumerate() {
+            for end in [&edge.from, &edge.to] {
+                if !index.contains_key(&end.to_string()) {
+                    return Err(syn::Error::new(end.span(), format!("undeclared node `{}`", end)));
+                }
+            }
+            if !DECISION_KINDS.iter().any(|k| edge.kind == k) {
+                return Err(syn::Error::new(
+                    edge.kind.span(),
+                    format!(
+                        "unknown decision kind `{}`; expected one of: {}",
+                        edge.kind,
+                        DECISION_KINDS.join(", ")
+                    ),
+                ));
+            }
+            let key = (edge.from.to_string(), edge.to.to_string(), edge.kind.to_string());
+            if seen.insert(key, e).is_some() {
+                return Err(syn::Error::new(
+                    edge.span,
+                    format!("duplicate edge {} -> {} with kind {}", edge.from, edge.to, edge.kind),
+                ));
+            }
+            outgoing[index[&edge.from.to_string()]].push(e);
+        }
+
+        let mut walker = Walker {
+            graph: self,
+            index: index.clone(),
+            outgoing,
+            stack: Vec::new(),
+            visited: vec![false; self.nodes.len()],
+            max_unguarded: 0.0,
+            steps: 0,
+        };
+        // Initial node first, then any component it cannot reach
+        for node in 0..self.nodes.len() {
+            if !walker.visited[node] {
+                walker.start(node)?;
+            }
+        }
+
+        Ok((index, Analysis { max_unguarded: walker.max_unguarded }))
+    }
+
+    fn generate(&self) -> syn::Result<TokenStream> {
+        let (index, analysis) = self.validate()?;
+
+        let name = &self.name;
+        let name_str = name.to_string();
+        let module = format_ident!("{}", snake_case(&name_str), span = name.span());
+        let nodes: Vec<&Ident> = self.nodes.iter().map(|n| &n.name).collect();
+        let node_strs: Vec<String> = nodes.iter().map(|n| n.to_string()).collect();
+        let initial = nodes[0];
+        let initial_roh = Literal::f32_suffixed(self.nodes[0].roh_delta as f32);
+        let max_unguarded = Literal::f32_suffixed(analysis.max_unguarded as f32);
+
+        let edge_rows = self.edges.iter().map(|edge| {
+            let (from, to) = (&edge.from, &edge.to);
+            let kind = edge.kind.to_string();
+            let delta = edge_delta(self, &index, edge);
+            quote! {
+                EvolutionEdge { from: Node::#from, to: Node::#to, kind: #kind, roh_delta: #delta }
+            }
+        });
+
+        let states = self.nodes.iter().map(|node| {
+            let state = &node.name;
+            let transitions = self.edges.iter().filter(|e| e.from == *state).map(|edge| {
+                let to = &edge.to;
+                let method = format_ident!(
+                    "{}_to_{}",
+                    snake_case(&edge.kind.to_string()),
+                    snake_case(&to.to_string()),
+                    span = edge.span
+                );
+                let delta = edge_delta(self, &index, edge);
+                let doc = format!("Take the declared `{}` edge {} -> {}", edge.kind, state, to);
+                quote! {
+                    #[doc = #doc]
+                    pub fn #method(self) -> #to {
+                        #to { roh: (self.roh + #delta).max(0.0) }
+                    }
+                }
+            });
+            quote! {
+                /// Typestate for one evolution node; only reachable via declared edges
+                #[derive(Debug)]
+                pub struct #state {
+                    roh: f32,
+                }
+
+                impl #state {
+                    pub const NODE: Node = Node::#state;
+
+                    pub fn roh(&self) -> f32 {
+                        self.roh
+                    }
+
+                    #(#transitions)*
+                }
+            }
+        });
+
+        let doc = format!("State machine generated by `evolutiongraph!` for `{}`", name_str);
+        Ok(quote! {
+            #[doc = #doc]
+            #[allow(dead_code)]
+            pub mod #module {
+                use ::cyconetics_decision_grammar::evolution::{EvolutionEdge, EvolutionMachine};
+
+                pub const NAME: &str = #name_str;
+
+                /// Highest cumulative RoH reachable without a Reject or Escalate edge
+                pub const MAX_UNGUARDED_ROH: f32 = #max_unguarded;
+
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                pub enum Node {
+                    #(#nodes),*
+                }
+
+                impl Node {
+                    pub const INITIAL: Node = Node::#initial;
+
+                    pub fn name(&self) -> &'static str {
+                        match self {
+                            #(Node::#nodes => #node_strs),*
+                        }
+                    }
+                }
+
+                pub const EDGES: &[EvolutionEdge<Node>] = &[
+                    #(#edge_rows),*
+                ];
+
+                /// Runtime machine positioned at the initial node
+                pub fn machine() -> EvolutionMachine<Node> {
+                    EvolutionMachine::resume(EDGES, Node::INITIAL, #initial_roh)
+                }
+
+                #(#states)*
+
+                impl #initial {
+                    pub fn new() -> Self {
+                        #initial { roh: #initial_roh }
+                    }
+                }
+
+                impl Default for #initial {
+                    fn default() -> Self {
+                        Self::new()
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Cost of taking `edge`, including the delta of the node it enters
+fn edge_delta(graph: &Graph, index: &HashMap<String, usize>, edge: &EdgeDecl) -> Literal {
+    let to = index[&edge.to.to_string()];
+    Literal::f32_suffixed((edge.roh_delta + graph.nodes[to].roh_delta) as f32)
+}
+
+/// `PhoenixBCIPath` -> `phoenix_bci_path`
+fn snake_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).map(|n| n.is_lowercase()).unwrap_or(false);
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let GraphSet(graphs) = syn::parse2(input)?;
+    let mut out = TokenStream::new();
+    for graph in &graphs {
+        out.extend(graph.generate()?);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_of(src: &str) -> String {
+        expand(src.parse().unwrap()).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_guarded_graph_expands() {
+        let out = expand(
+            "evolution PhoenixBCIPath {
+                node Init; node Mild; node Advanced;
+                edge Init -> Mild { kind: Authorize, roh_delta: 0.08 }
+                edge Mild -> Advanced { kind: Authorize, roh_delta: 0.15 }
+                edge Mild -> Advanced { kind: Escalate, roh_delta: 0.25 }
+                edge Advanced -> Init { kind: Reject, roh_delta: -0.20 }
+            }"
+            .parse()
+            .unwrap(),
+        )
+        .unwrap()
+        .to_string();
+        assert!(out.contains("pub mod phoenix_bci_path"));
+        assert!(out.contains("escalate_to_advanced"));
+    }
+
+    #[test]
+    fn test_unguarded_path_over_ceiling_is_rejected() {
+        let err = error_of(
+            "evolution P {
+                node Init; node Mild => { roh_delta: 0.10 } node Advanced;
+                edge Init -> Mild { kind: Authorize, roh_delta: 0.08 }
+                edge Mild -> Advanced { kind: Approve, roh_delta: 0.12 }
+            }",
+        );
+        assert!(err.contains("Init -> Mild -> Advanced reaches RoH 0.30"), "{}", err);
+    }
+
+    #[test]
+    fn test_positive_unguarded_cycle_is_rejected() {
+        let err = error_of(
+            "evolution P {
+                node A; node B;
+                edge A -> B { kind: Authorize, roh_delta: 0.01 }
+                edge B -> A { kind: Defer, roh_delta: 0.0 }
+            }",
+        );
+        assert!(err.contains("cycle A -> B -> A gains RoH"), "{}", err);
+    }
+
+    #[test]
+    fn test_unknown_kind_and_node() {
+        assert!(error_of("evolution P { node A; edge A -> A { kind: Authorized } }")
+            .contains("unknown decision kind `Authorized`"));
+        assert!(error_of("evolution P { node A; edge A -> B { kind: Reject } }")
+            .contains("undeclared node `B`"));
+        assert_eq!(snake_case("PhoenixSanJollaBCIPath"), "phoenix_san_jolla_bci_path");
+    }
+}
@@ -0,0 +1,34 @@
+#![forbid(unsafe_code)]
+
+mod decision_roles;
+mod evolution_graph;
+mod roh_policy;
+
+use proc_macro::TokenStream;
+
+/// Parse an `evolution` node/edge graph, reject any path that reaches RoH ≥ 0.30
+/// without a Reject or Escalate edge, and emit a typed state machine.
+#[proc_macro]
+pub fn evolutiongraph(input: TokenStream) -> TokenStream {
+    evolution_graph::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Parse a `class BCI { HostSelf => { Approve, ... } }` role table into per-class
+/// role markers, `can_role_decide`, and a shard-compatible `role_table()`.
+#[proc_macro]
+pub fn decision_roles(input: TokenStream) -> TokenStream {
+    decision_roles::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Compile `policy Name { window_hours, incident_count_threshold, ceiling_delta }`
+/// into an Errority ratchet type that can only lower its RoH ceiling.
+#[proc_macro]
+pub fn roh_policy(input: TokenStream) -> TokenStream {
+    roh_policy::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
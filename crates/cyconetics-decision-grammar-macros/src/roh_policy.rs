@@ -0,0 +1,335 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, bracketed, Ident, LitFloat, LitInt, LitStr, Token};
+
+mod kw {
+    syn::custom_keyword!(policy);
+}
+
+const UPGRADE_CLASSES: [&str; 4] = ["BCI", "EXO", "XR", "GOV"];
+const INCIDENT_TYPES: [&str; 5] = [
+    "RoHTrendingHigh",
+    "HemodynamicDeviation",
+    "MetabolicStrain",
+    "NeuroconsentViolation",
+    "BloodTokenOverdraw",
+];
+const CEILING_FLOOR: f64 = 0.15;
+const CEILING_MAX: f64 = 0.30;
+
+struct Policies(Vec<PolicyDecl>);
+
+struct PolicyDecl {
+    name: Ident,
+    window_hours: u32,
+    incident_count_threshold: u32,
+    ceiling_delta: f64,
+    initial_ceiling: f64,
+    classes: Vec<Ident>,
+    incidents: Option<Vec<Ident>>,  // None: IncidentType::ROH
+    zone: Option<LitStr>,
+}
+
+enum Value {
+    Int(LitInt),
+    Float(LitFloat),
+    Str(LitStr),
+    List(Vec<Ident>),
+}
+
+struct Field {
+    key: Ident,
+    value: Value,
+}
+
+impl Parse for Policies {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut policies = Vec::new();
+        while !input.is_empty() {
+            policies.push(input.parse()?);
+        }
+        Ok(Policies(policies))
+    }
+}
+
+impl Parse for Field {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let value = if input.peek(syn::token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            let items = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            Value::List(items.into_iter().collect())
+        } else if input.peek(LitStr) {
+            Value::Str(input.parse()?)
+        } else if input.peek(LitFloat) {
+            Value::Float(input.parse()?)
+        } else {
+            Value::Int(input.parse()?)
+        };
+        Ok(Field { key, value })
+    }
+}
+
+impl Parse for PolicyDecl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::policy>()?;
+        let name: Ident = input.parse()?;
+        let content;
+        braced!(content in input);
+        let fields = Punctuated::<Field, Token![,]>::parse_terminated(&content)?;
+
+        let mut window_hours = None;
+        let mut incident_count_threshold = None;
+        let mut ceiling_delta = None;
+        let mut initial_ceiling = None;
+        let mut classes = None;
+        let mut incidents = None;
+        let mut zone = None;
+        for field in fields {
+            let key = field.key.to_string();
+            let duplicate = match key.as_str() {
+                "window_hours" => window_hours.replace(int(&field)?).is_some(),
+                "incident_count_threshold" => incident_count_threshold.replace(int(&field)?).is_some(),
+                "ceiling_delta" => ceiling_delta.replace(float(&field)?).is_some(),
+                "initial_ceiling" => initial_ceiling.replace(float(&field)?).is_some(),
+                "classes" => match field.value {
+                    Value::List(list) => classes.replace(list).is_some(),
+                    _ => return Err(syn::Error::new(field.key.span(), "`classes` expects a list like [BCI, XR]")),
+                },
+                "incidents" => match field.value {
+                    Value::List(list) => incidents.replace(list).is_some(),
+                    _ => {
+                        return Err(syn::Error::new(
+                            field.key.span(),
+                            "`incidents` expects a list like [RoHTrendingHigh, MetabolicStrain]",
+                        ))
+                    },
+                },
+                "zone" => match field.value {
+                    Value::Str(lit) => zone.replace(lit).is_some(),
+                    _ => return Err(syn::Error::new(field.key.span(), "`zone` expects a string like \"phoenix-west\"")),
+                },
+                _ => {
+                    return Err(syn::Error::new(
+                        field.key.span(),
+                        format!(
+                            "unknown roh_policy field `{}`; expected window_hours, incident_count_threshold, \
+                             ceiling_delta, initial_ceiling, classes, incidents or zone",
+                            key
+                        ),
+                    ))
+                },
+            };
+            if duplicate {
+                return Err(syn::Error::new(field.key.span(), format!("duplicate field `{}`", key)));
+            }
+        }
+
+        let missing = |what: &str| syn::Error::new(name.span(), format!("policy {} is missing `{}`", name, what));
+        let decl = PolicyDecl {
+            window_hours: window_hours.ok_or_else(|| missing("window_hours"))?,
+            incident_count_threshold: incident_count_threshold.ok_or_else(|| missing("incident_count_threshold"))?,
+            ceiling_delta: ceiling_delta.ok_or_else(|| missing("ceiling_delta"))?,
+            initial_ceiling: initial_ceiling.unwrap_or(CEILING_MAX),
+            classes: classes.unwrap_or_else(|| {
+                UPGRADE_CLASSES.iter().map(|c| Ident::new(c, Span::call_site())).collect()
+            }),
+            incidents,
+            zone,
+            name,
+        };
+        decl.validate()?;
+        Ok(decl)
+    }
+}
+
+fn int(field: &Field) -> syn::Result<u32> {
+    match &field.value {
+        Value::Int(lit) => lit.base10_parse(),
+        _ => Err(syn::Error::new(field.key.span(), format!("`{}` expects an integer", field.key))),
+    }
+}
+
+fn float(field: &Field) -> syn::Result<f64> {
+    match &field.value {
+        Value::Float(lit) => lit.base10_parse(),
+        Value::Int(lit) => lit.base10_parse::<u32>().map(f64::from),
+        _ => Err(syn::Error::new(field.key.span(), format!("`{}` expects a number", field.key))),
+    }
+}
+
+impl PolicyDecl {
+    fn validate(&self) -> syn::Result<()> {
+        let err = |msg: String| Err(syn::Error::new(self.name.span(), msg));
+        if self.window_hours == 0 {
+            return err(format!("policy {}: window_hours must be at least 1", self.name));
+        }
+        if self.incident_count_threshold == 0 {
+            return err(format!("policy {}: incident_count_threshold must be at least 1", self.name));
+        }
+        // A zero or negative delta would make the ratchet a no-op or a relaxation
+        if !(self.ceiling_delta > 0.0 && self.ceiling_delta <= CEILING_MAX - CEILING_FLOOR) {
+            return err(format!(
+                "policy {}: ceiling_delta {} must be in (0, {:.2}]",
+                self.name,
+                self.ceiling_delta,
+                CEILING_MAX - CEILING_FLOOR
+            ));
+        }
+        if !(CEILING_FLOOR..=CEILING_MAX).contains(&self.initial_ceiling) {
+            return err(format!(
+                "policy {}: initial_ceiling {} must be within [{:.2}, {:.2}]",
+                self.name, self.initial_ceiling, CEILING_FLOOR, CEILING_MAX
+            ));
+        }
+        for class in &self.classes {
+            if !UPGRADE_CLASSES.iter().any(|c| class == c) {
+                return Err(syn::Error::new(
+                    class.span(),
+                    format!("unknown upgrade class `{}`; expected one of: {}", class, UPGRADE_CLASSES.join(", ")),
+                ));
+            }
+        }
+        for incident in self.incidents.iter().flatten() {
+            if !INCIDENT_TYPES.iter().any(|t| incident == t) {
+                return Err(syn::Error::new(
+                    incident.span(),
+                    format!("unknown incident type `{}`; expected one of: {}", incident, INCIDENT_TYPES.join(", ")),
+                ));
+            }
+        }
+        if self.incidents.as_ref().is_some_and(|list| list.is_empty()) {
+            return err(format!("policy {}: `incidents` must name at least one incident type", self.name));
+        }
+        if self.zone.as_ref().is_some_and(|zone| zone.value().is_empty()) {
+            return err(format!("policy {}: `zone` must not be empty", self.name));
+        }
+        Ok(())
+    }
+
+    fn generate(&self) -> TokenStream {
+        let name = &self.name;
+        let name_str = name.to_string();
+        let window_hours = self.window_hours;
+        let threshold = self.incident_count_threshold;
+        let delta = self.ceiling_delta as f32;
+        let initial = self.initial_ceiling as f32;
+        let classes = &self.classes;
+        let incident_types = match &self.incidents {
+            Some(list) => quote! { &[#(::cyconetics_decision_grammar::IncidentType::#list),*] },
+            None => quote! { &::cyconetics_decision_grammar::IncidentType::ROH },
+        };
+        let zone = match &self.zone {
+            Some(lit) => quote! { ::core::option::Option::Some(#lit) },
+            None => quote! { ::core::option::Option::None },
+        };
+        let doc = format!(
+            "Errority RoH ratchet: {} incidents within {}h lower the ceiling by {} (floor {:.2})",
+            threshold, window_hours, delta, CEILING_FLOOR
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug)]
+            pub struct #name {
+                ratchet: ::cyconetics_decision_grammar::roh_policy::RohRatchet,
+            }
+
+            impl #name {
+                pub const WINDOW_HOURS: u32 = #window_hours;
+                pub const INCIDENT_COUNT_THRESHOLD: u32 = #threshold;
+                pub const CEILING_DELTA: f32 = #delta;
+                pub const INITIAL_CEILING: f32 = #initial;
+
+                pub const SPEC: ::cyconetics_decision_grammar::roh_policy::RohPolicySpec =
+                    ::cyconetics_decision_grammar::roh_policy::RohPolicySpec {
+                        name: #name_str,
+                        window_hours: #window_hours,
+                        incident_count_threshold: #threshold,
+                        ceiling_delta: #delta,
+                        initial_ceiling: #initial,
+                        affected_classes: &[
+                            #(::cyconetics_decision_grammar::roh_guard::UpgradeClass::#classes),*
+                        ],
+                        incident_types: #incident_types,
+                        zone: #zone,
+                    };
+
+                pub fn new() -> Self {
+                    let ratchet = ::cyconetics_decision_grammar::roh_policy::RohRatchet::new(Self::SPEC)
+                        .expect("roh_policy! validated SPEC at compile time");
+                    Self { ratchet }
+                }
+
+                /// Rebuild from an incident log instead of trusting a stored ceiling
+                pub fn replay<'a>(
+                    logs: impl IntoIterator<Item = &'a ::cyconetics_decision_grammar::SafetyEpochLog>,
+                ) -> Self {
+                    let ratchet = ::cyconetics_decision_grammar::roh_policy::RohRatchet::replay(Self::SPEC, logs)
+                        .expect("roh_policy! validated SPEC at compile time");
+                    Self { ratchet }
+                }
+
+                pub fn observe(
+                    &mut self,
+                    log: &::cyconetics_decision_grammar::SafetyEpochLog,
+                ) -> Option<::cyconetics_decision_grammar::PolicyTightening> {
+                    self.ratchet.observe(log)
+                }
+
+                pub fn ceiling(&self) -> f32 {
+                    self.ratchet.ceiling()
+                }
+
+                pub fn admits(&self, predicted_roh: f32) -> bool {
+                    self.ratchet.admits(predicted_roh)
+                }
+
+                pub fn history(&self) -> &[::cyconetics_decision_grammar::PolicyTightening] {
+                    self.ratchet.history()
+                }
+            }
+
+            impl Default for #name {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        }
+    }
+}
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let Policies(policies) = syn::parse2(input)?;
+    Ok(policies.iter().map(PolicyDecl::generate).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_of(src: &str) -> String {
+        expand(src.parse().unwrap()).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_rejects_relaxing_or_empty_deltas() {
+        let src = "policy P { window_hours: 24, incident_count_threshold: 3, ceiling_delta: 0.0 }";
+        assert!(error_of(src).contains("ceiling_delta"));
+        let src = "policy P { window_hours: 24, incident_count_threshold: 3, ceiling_delta: 0.05, initial_ceiling: 0.4 }";
+        assert!(error_of(src).contains("initial_ceiling"));
+    }
+
+    #[test]
+    fn test_requires_all_ratchet_parameters() {
+        assert!(error_of("policy P { window_hours: 24, ceiling_delta: 0.05 }").contains("incident_count_threshold"));
+        let src = "policy P { window_hours: 24, incident_count_threshold: 3, ceiling_delta: 0.05, raise_by: 0.1 }";
+        assert!(error_of(src).contains("unknown roh_policy field"));
+        let src = "policy P { window_hours: 24, incident_count_threshold: 3, ceiling_delta: 0.05, incidents: [Typo] }";
+        assert!(error_of(src).contains("unknown incident type"));
+    }
+}
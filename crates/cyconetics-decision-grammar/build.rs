@@ -1,45 +1,110 @@
-use std::{env, fs, path::PathBuf};
+use std::{env, fmt::Write as _, fs, path::PathBuf};
+
+use aln::{AlnRegistry, Node};
 
 fn main() {
     // Path to ALN shards; configurable so Phoenix/San Jolla can point to their own copies.
-    let aln_dir = env::var("ALN_DIR").unwrap_or_else(|_| "aln".to_string());
+    let aln_dir = env::var("ALN_DIR").map(PathBuf::from).unwrap_or_else(|_| {
+        PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR must be set")).join("../../aln")
+    });
+    println!("cargo:rerun-if-env-changed=ALN_DIR");
 
-    let ledger_path = PathBuf::from(&aln_dir).join("decision.ledger.entry.v1.aln");
+    let ledger_path = aln_dir.join("decision.ledger.entry.v1.aln");
     println!("cargo:rerun-if-changed={}", ledger_path.display());
 
     let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR must be set"));
     let dest_path = out_dir.join("aln_generated.rs");
 
-    let ledger_yaml =
-        fs::read_to_string(&ledger_path).expect("failed to read decision.ledger.entry.v1.aln");
+    let mut registry = AlnRegistry::new();
+    if let Err(e) = registry.load_file(&ledger_path) {
+        panic!("failed to load ALN ledger shard: {e}");
+    }
+    // Refuse to compile against a shard whose content no longer matches its declared stamp
+    if let Err(e) = registry.verify_hexstamps() {
+        panic!("{}: {e}; recompute the hexstamp after editing the shard", ledger_path.display());
+    }
 
-    let generated =
-        generate_ledger_code(&ledger_yaml).expect("failed to generate ALN-derived Rust code");
+    let generated = match generate_ledger_code(&registry) {
+        Ok(code) => code,
+        Err(e) => panic!("failed to generate ALN-derived Rust code: {e}"),
+    };
 
     fs::write(&dest_path, generated).expect("failed to write aln_generated.rs");
 }
 
-fn generate_ledger_code(yaml: &str) -> Result<String, String> {
-    use serde::Deserialize;
+const LEDGER_ID: &str = "decision.ledger.entry.v1";
+
+/// Shard field type -> Rust type. CamelCase names refer to grammar types in `crate::types`.
+fn rust_type(source: &str, name: &str, node: &Node) -> Result<String, String> {
+    let ty = node.as_str().ok_or_else(|| at(source, node, &format!("field `{name}` needs a type name")))?;
+    Ok(match ty {
+        "string" => "String".into(),
+        "float" => "f64".into(),
+        "integer" => "i64".into(),
+        "bool" | "boolean" => "bool".into(),
+        _ if ty.starts_with(|c: char| c.is_ascii_uppercase()) && ty.chars().all(|c| c.is_ascii_alphanumeric()) => {
+            format!("crate::types::{ty}")
+        }
+        _ => return Err(at(source, node, &format!("unknown type `{ty}` for field `{name}`"))),
+    })
+}
+
+fn at(source: &str, node: &Node, message: &str) -> String {
+    format!("{source}:{}:{}: {message}", node.span.line, node.span.column)
+}
+
+fn constraint_f64(source: &str, doc: &aln::Document, name: &str, default: f64) -> Result<f64, String> {
+    match doc.path(&format!("constraints.{name}")) {
+        Some(n) => n.as_f64().ok_or_else(|| at(source, n, &format!("`{name}` must be a number"))),
+        None => Ok(default),
+    }
+}
 
-    #[derive(Deserialize)]
-    struct LedgerRoot {
-        id: String,
-        version: String,
-        hexstamp: String,
-        fields: LedgerFields,
+fn flag(source: &str, doc: &aln::Document, path: &str) -> Result<bool, String> {
+    match doc.path(path) {
+        Some(n) => n.as_bool().ok_or_else(|| at(source, n, &format!("`{path}` must be true or false"))),
+        None => Ok(false),
     }
+}
 
-    #[derive(Deserialize)]
-    struct LedgerFields {
-        roh_at_decision: f64,
-        roh_delta: f64,
+fn generate_ledger_code(registry: &AlnRegistry) -> Result<String, String> {
+    let header = registry.header(LEDGER_ID).ok_or_else(|| format!("shard {LEDGER_ID} not loaded"))?;
+    let doc = registry.get(LEDGER_ID).expect("header implies document");
+    let source = header.source.as_deref().unwrap_or(LEDGER_ID);
+
+    let version = header.version.as_deref().ok_or_else(|| format!("{source}: missing `version`"))?;
+    let fields_node = doc.get("fields").ok_or_else(|| format!("{source}: missing `fields`"))?;
+    let fields = fields_node.as_map().ok_or_else(|| at(source, fields_node, "`fields` must be a map"))?;
+    let keys_node = doc.get("keys").ok_or_else(|| format!("{source}: missing `keys`"))?;
+    let keys = keys_node
+        .as_list()
+        .ok_or_else(|| at(source, keys_node, "`keys` must be a list"))?
+        .iter()
+        .map(|k| k.as_str().ok_or_else(|| at(source, k, "key names must be strings")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let min_roh = constraint_f64(source, doc, "min_roh", 0.0)?;
+    let max_roh = constraint_f64(source, doc, "max_roh", 1.0)?;
+    if min_roh > max_roh {
+        return Err(at(source, doc.path("constraints").unwrap_or(&doc.root), "min_roh exceeds max_roh"));
     }
+    let append_only = flag(source, doc, "constraints.append_only")?;
 
-    let root: LedgerRoot =
-        serde_yaml::from_str(yaml).map_err(|e| format!("ledger yaml parse error: {e}"))?;
+    // Struct: key columns first, then the declared fields in shard order
+    let mut columns: Vec<(String, String)> = keys.iter().map(|k| (k.to_string(), "String".to_string())).collect();
+    for (key, node) in fields {
+        if columns.iter().any(|(name, _)| *name == key.name) {
+            return Err(format!("{source}:{}:{}: duplicate field `{}`", key.span.line, key.span.column, key.name));
+        }
+        columns.push((key.name.clone(), rust_type(source, &key.name, node)?));
+    }
+    let has = |name: &str, ty: &str| columns.iter().any(|(n, t)| n == name && t == ty);
 
-    let code = format!(
+    let mut code = String::new();
+    let w = &mut code;
+    writeln!(w, "// @generated by cyconetics-decision-grammar build.rs from {LEDGER_ID}; do not edit.").unwrap();
+    writeln!(
+        w,
         r#"
 /// ALN id for DecisionLedgerEntry shard.
 pub const DECISION_LEDGER_ENTRY_ID: &str = "{id}";
@@ -47,21 +112,102 @@ pub const DECISION_LEDGER_ENTRY_ID: &str = "{id}";
 pub const DECISION_LEDGER_ENTRY_VERSION: &str = "{version}";
 /// Hex-stamp snapshot for DecisionLedgerEntry schema.
 pub const DECISION_LEDGER_ENTRY_HEXSTAMP: &str = "{hexstamp}";
+/// The ledger shard as compiled in; check it against deployed shards with `AlnRegistry::check_compiled`.
+pub const DECISION_LEDGER_ENTRY_SHARD: aln::CompiledShard = aln::CompiledShard {{
+    id: DECISION_LEDGER_ENTRY_ID,
+    version: DECISION_LEDGER_ENTRY_VERSION,
+    hexstamp: DECISION_LEDGER_ENTRY_HEXSTAMP,
+}};
 
 /// Maximum allowed risk-of-harm at decision time for any upgrade path.
 /// This should align with the global RoH ceiling (0.3) enforced in the safety spine.
 pub const LEDGER_ROH_MAX: f64 = 0.30;
 
-/// Default numeric hints from the ALN shard (for tests and CI sanity checks).
-pub const LEDGER_DEFAULT_ROH_AT_DECISION: f64 = {roh_at};
-pub const LEDGER_DEFAULT_ROH_DELTA: f64 = {roh_delta};
+/// `constraints` of the shard.
+pub const DECISION_LEDGER_MIN_ROH: f64 = {min_roh:?};
+pub const DECISION_LEDGER_MAX_ROH: f64 = {max_roh:?};
+pub const DECISION_LEDGER_APPEND_ONLY: bool = {append_only};
+
+/// `logging` requirements of the shard.
+pub const DECISION_LEDGER_REQUIRE_AUTHORSHIP_TRIPLET: bool = {triplet};
+pub const DECISION_LEDGER_REQUIRE_NEURORIGHTS_PROFILE: bool = {profile};
 "#,
-        id = root.id,
-        version = root.version,
-        hexstamp = root.hexstamp,
-        roh_at = root.fields.roh_at_decision,
-        roh_delta = root.fields.roh_delta,
-    );
+        id = header.id,
+        hexstamp = header.hexstamp.as_deref().expect("verified above"),
+        triplet = flag(source, doc, "logging.require_authorship_triplet")?,
+        profile = flag(source, doc, "logging.require_neurorights_profile")?,
+    )
+    .unwrap();
+
+    writeln!(w, "/// One `{LEDGER_ID}` entry: `keys` followed by `fields`, in shard order.").unwrap();
+    writeln!(w, "#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]").unwrap();
+    writeln!(w, "pub struct DecisionLedgerEntryV1 {{").unwrap();
+    for (name, ty) in &columns {
+        writeln!(w, "    pub {name}: {ty},").unwrap();
+    }
+    writeln!(w, "}}\n").unwrap();
+
+    writeln!(w, "impl DecisionLedgerEntryV1 {{").unwrap();
+    writeln!(w, "    pub const KEYS: &'static [&'static str] = &{keys:?};").unwrap();
+    let field_list: Vec<(&str, &str)> =
+        fields.iter().map(|(k, n)| (k.name.as_str(), n.as_str().unwrap_or_default())).collect();
+    writeln!(w, "    pub const FIELDS: &'static [(&'static str, &'static str)] = &{field_list:?};\n").unwrap();
+
+    writeln!(w, "    pub fn key(&self) -> crate::types::DecisionLedgerKey {{").unwrap();
+    writeln!(w, "        crate::types::DecisionLedgerKey {{").unwrap();
+    for k in &keys {
+        writeln!(w, "            {k}: self.{k}.clone(),").unwrap();
+    }
+    writeln!(w, "        }}\n    }}\n").unwrap();
+
+    writeln!(w, "    /// Checks generated from the shard's `keys` and `constraints`").unwrap();
+    writeln!(w, "    pub fn validate(&self) -> Result<(), LedgerConstraintViolation> {{").unwrap();
+    for k in &keys {
+        writeln!(
+            w,
+            "        if self.{k}.is_empty() {{\n            return Err(LedgerConstraintViolation::EmptyKey {{ field: \"{k}\" }});\n        }}"
+        )
+        .unwrap();
+    }
+    for (name, ty) in &columns {
+        if ty != "f64" || !name.starts_with("roh_") {
+            continue;
+        }
+        // Levels must sit inside [min_roh, max_roh]; deltas can move at most the width of that band
+        let (lo, hi, value) = if name.ends_with("_delta") {
+            (format!("{:?}", min_roh - max_roh), format!("{:?}", max_roh - min_roh), format!("self.{name}"))
+        } else {
+            ("DECISION_LEDGER_MIN_ROH".into(), "DECISION_LEDGER_MAX_ROH".into(), format!("self.{name}"))
+        };
+        writeln!(
+            w,
+            "        if !({value}.is_finite() && {value} >= {lo} && {value} <= {hi}) {{\n            return Err(LedgerConstraintViolation::RohOutOfRange {{ field: \"{name}\", value: {value}, min: {lo}, max: {hi} }});\n        }}"
+        )
+        .unwrap();
+    }
+    writeln!(w, "        Ok(())\n    }}\n").unwrap();
+
+    writeln!(w, "    /// `validate` plus the `append_only` rules against the entries already in the ledger").unwrap();
+    writeln!(w, "    pub fn validate_append(&self, ledger: &[Self]) -> Result<(), LedgerConstraintViolation> {{").unwrap();
+    writeln!(w, "        self.validate()?;").unwrap();
+    if append_only && has("created_at_ms", "i64") {
+        writeln!(
+            w,
+            "        if let Some(last) = ledger.last() {{\n            if self.created_at_ms < last.created_at_ms {{\n                return Err(LedgerConstraintViolation::OutOfOrder {{ previous_ms: last.created_at_ms, created_at_ms: self.created_at_ms }});\n            }}\n        }}"
+        )
+        .unwrap();
+    }
+    if append_only && has("decision_record_hash", "String") {
+        writeln!(
+            w,
+            "        if ledger.iter().any(|e| e.decision_record_hash == self.decision_record_hash) {{\n            return Err(LedgerConstraintViolation::DuplicateRecord {{ hash: self.decision_record_hash.clone() }});\n        }}"
+        )
+        .unwrap();
+    }
+    if !append_only {
+        writeln!(w, "        let _ = ledger;").unwrap();
+    }
+    writeln!(w, "        Ok(())\n    }}\n}}").unwrap();
 
     Ok(code)
 }
@@ -6,9 +6,15 @@
 //! 3. Role-based decision making
 //! 4. Evidence bundle creation
 //! 5. ALN shard binding
-//! 6. CI sidecar checks
+//! 6. Validator checks
 
 use cyconetics_decision_grammar::*;
+use cyconetics_decision_grammar::aln_shards::{DecisionLedgerShardEntry, NeuroConsentEntry};
+use cyconetics_decision_grammar::roh_guard::{
+    evaluate_upgrade, try_roh_bound_30, BioKarmaRiskVector, UpgradeClass, UpgradeDescriptor,
+};
+use cyconetics_decision_grammar::types::{BciHostSnapshot, HostBudget};
+use cyconetics_decision_grammar::validators::validate_bfc_broadcast;
 use chrono::Utc;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -16,7 +22,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 1. Create host state (augmented citizen in Phoenix zone)
     let host_did = "bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7";
-    let mut host_state = RoHGuardedHostState {
+    let host_state = RoHGuardedHostState {
         host_budget: HostBudget {
             auet_budget: 1000.0,
             blood_tokens_reserved: 50.0,
@@ -89,10 +95,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  Core Temp: {:.1}°C", evidence.biomarkers.core_temperature);
     println!();
 
-    // 5. Run CI sidecar checks
-    let sidecar = CISidecarm::new();
-    let evidence_check = sidecar.check_evidence_bundle(&evidence);
-    println!("CI Sidecar Evidence Check: {}", evidence_check);
+    // 5. Check the evidence for critical biomarkers
+    println!("Critical Biomarker Present: {}", evidence.has_critical_biomarker());
     println!();
 
     // 6. Compute RoH from biomarma vector
@@ -152,15 +156,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
              decision_record.ksr_band.risk);
     println!();
 
-    // 8. Check decision record via CI sidecar
-    let record_check = sidecar.check_decision_record(&decision_record);
-    println!("CI Sidecar Record Check: {}", record_check);
+    // 8. Validate the decision record
+    let record_check = validate_decision_record(&decision_record);
+    println!("Decision Record Check: {:?}", record_check);
     println!();
 
     // 9. Create ledger entry and commit to ALN shard
     let mut ledger_entry = DecisionLedgerEntry::new(
         decision_record.ledger_key.clone(),
-        decision_record.decision,
+        decision_record.decision.clone(),
         decision_record.ksr_band,
         evidence,
     );
@@ -172,6 +176,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         homeostasis_protected: true,
         evidence_hash: "evidence_hash_placeholder".to_string(),
     });
+    ledger_entry.seal();
 
     println!("Decision Ledger Entry Created:");
     println!("  Ledger Key: {}", ledger_entry.key);
@@ -184,8 +189,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 10. Commit to ALN shard
     let mut decision_shard = DecisionLedgerShard::new();
-    let shard_entry = ledger_entry.to_aln_shard();
-    decision_shard.append(shard_entry);
+    let aln_entry = ledger_entry.to_aln_shard();
+    decision_shard.append(DecisionLedgerShardEntry {
+        key: aln_entry.ledger_key,
+        decision_verb: aln_entry.decision_verb,
+        roh_band_hex: aln_entry.roh_hex,
+        evidence_hash: aln_entry.evidence_hash,
+        entry_hash: aln_entry.entry_hash,
+        timestamp: aln_entry.timestamp,
+        zone_id: ledger_entry.evidence_bundle.zone_id.clone(),
+    });
 
     println!("ALN Shard Committed:");
     println!("  Shard Name: {}", decision_shard.shard_name);
@@ -213,8 +226,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  Target Entities: {} entities", bfc_proposal.target_entities.len());
     println!("  Zero Observation: {}", bfc_proposal.zero_observation);
 
-    let neuro_check = sidecar.check_bfc_broadcast(&bfc_proposal);
-    println!("  CI Neuro-Consent Check: {}", neuro_check);
+    let neuro_check = validate_bfc_broadcast(&bfc_proposal);
+    println!("  Neuro-Consent Check: {:?}", neuro_check);
     println!();
 
     // 12. Create neuro-consent registry entry
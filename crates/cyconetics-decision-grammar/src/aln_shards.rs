@@ -2,15 +2,70 @@
 //! Decisions are committed to immutable, blockchain-anchored shards for audit and traceability.
 
 use serde::{Serialize, Deserialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::canonical::{canonical_bytes, canonical_hash, canonical_hash_excluding};
+use crate::errority::PolytopeFace;
+use crate::merkle::{InclusionProof, MerkleAccumulator};
+use crate::roles::{RolePermissionTable, RoleTableError};
 
 /// ALN shard schema: cyberswarm.decision.ledger.v1
-/// Immutable append-only ledger of all governance decisions
+/// Immutable append-only ledger of all governance decisions.
+/// `last_committed_hash` is the Merkle root over `entries`; the accumulator is
+/// not serialized and is rebuilt from the entries on load, which fails if the
+/// rebuilt root differs from the stored one.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "DecisionLedgerShardRepr")]
 pub struct DecisionLedgerShard {
     pub shard_name: String,  // "cyberswarm.decision.ledger.v1"
     pub entries: Vec<DecisionLedgerShardEntry>,
     pub last_committed_hash: String,
+    #[serde(skip)]
+    merkle: MerkleAccumulator,
+}
+
+#[derive(Deserialize)]
+struct DecisionLedgerShardRepr {
+    shard_name: String,
+    entries: Vec<DecisionLedgerShardEntry>,
+    last_committed_hash: String,
+}
+
+/// Stored `last_committed_hash` does not match the root rebuilt from the entries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShardRootMismatch {
+    pub stored: String,
+    pub computed: String,
+}
+
+impl fmt::Display for ShardRootMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "shard root mismatch: stored {}, rebuilt {}", self.stored, self.computed)
+    }
+}
+
+impl std::error::Error for ShardRootMismatch {}
+
+impl TryFrom<DecisionLedgerShardRepr> for DecisionLedgerShard {
+    type Error = ShardRootMismatch;
+
+    fn try_from(repr: DecisionLedgerShardRepr) -> Result<Self, Self::Error> {
+        let mut merkle = MerkleAccumulator::new();
+        for entry in &repr.entries {
+            merkle.push(&entry.leaf_bytes());
+        }
+        let computed = merkle.root_hex();
+        if computed != repr.last_committed_hash {
+            return Err(ShardRootMismatch { stored: repr.last_committed_hash, computed });
+        }
+        Ok(DecisionLedgerShard {
+            shard_name: repr.shard_name,
+            entries: repr.entries,
+            last_committed_hash: computed,
+            merkle,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,28 +79,54 @@ pub struct DecisionLedgerShardEntry {
     pub zone_id: String,
 }
 
+impl DecisionLedgerShardEntry {
+    /// Bytes committed as this entry's Merkle leaf
+    pub fn leaf_bytes(&self) -> Vec<u8> {
+        canonical_bytes(self).expect("ledger entries hold only strings and integers")
+    }
+}
+
+impl Default for DecisionLedgerShard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DecisionLedgerShard {
     pub fn new() -> Self {
         DecisionLedgerShard {
             shard_name: "cyberswarm.decision.ledger.v1".to_string(),
             entries: Vec::new(),
-            last_committed_hash: String::new(),
+            last_committed_hash: MerkleAccumulator::new().root_hex(),
+            merkle: MerkleAccumulator::new(),
         }
     }
 
     /// Append an entry (immutable)
     pub fn append(&mut self, entry: DecisionLedgerShardEntry) {
+        self.merkle.push(&entry.leaf_bytes());
         self.entries.push(entry);
         self.update_hash();
     }
 
     /// Update the committed hash after append
     fn update_hash(&mut self) {
-        use sha2::{Sha256, Digest};
-        let json = serde_json::to_string(&self.entries).unwrap_or_default();
-        let mut hasher = Sha256::new();
-        hasher.update(json.as_bytes());
-        self.last_committed_hash = hex::encode(hasher.finalize());
+        self.last_committed_hash = self.merkle.root_hex();
+    }
+
+    /// Current Merkle root over all entries (hex)
+    pub fn root(&self) -> String {
+        self.merkle.root_hex()
+    }
+
+    /// Proof that entry `index` is part of the current root
+    pub fn inclusion_proof(&self, index: usize) -> Option<InclusionProof> {
+        self.merkle.inclusion_proof(index)
+    }
+
+    /// Verify a single disclosed entry against a published root, without the rest of the ledger
+    pub fn verify_inclusion(entry: &DecisionLedgerShardEntry, proof: &InclusionProof, root: &str) -> bool {
+        MerkleAccumulator::verify_inclusion(&entry.leaf_bytes(), proof, root)
     }
 
     /// Serialize to JSONL for blockchain commit
@@ -78,6 +159,12 @@ pub struct NeuroConsentEntry {
     pub audit_hash: String,
 }
 
+impl Default for NeurorightsConsentShard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl NeurorightsConsentShard {
     pub fn new() -> Self {
         NeurorightsConsentShard {
@@ -94,11 +181,7 @@ impl NeurorightsConsentShard {
     }
 
     fn update_hash(&mut self) {
-        use sha2::{Sha256, Digest};
-        let json = serde_json::to_string(&self.consents).unwrap_or_default();
-        let mut hasher = Sha256::new();
-        hasher.update(json.as_bytes());
-        self.last_committed_hash = hex::encode(hasher.finalize());
+        self.last_committed_hash = canonical_hash(&self.consents);
     }
 }
 
@@ -124,6 +207,12 @@ pub struct BroadcastLedgerEntry {
     pub entry_hash: String,
 }
 
+impl Default for NeurorightsBoradcastLedgerShard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl NeurorightsBoradcastLedgerShard {
     pub fn new() -> Self {
         NeurorightsBoradcastLedgerShard {
@@ -140,11 +229,7 @@ impl NeurorightsBoradcastLedgerShard {
     }
 
     fn update_hash(&mut self) {
-        use sha2::{Sha256, Digest};
-        let json = serde_json::to_string(&self.broadcasts).unwrap_or_default();
-        let mut hasher = Sha256::new();
-        hasher.update(json.as_bytes());
-        self.last_committed_hash = hex::encode(hasher.finalize());
+        self.last_committed_hash = canonical_hash(&self.broadcasts);
     }
 
     /// Serialize to JSONL for blockchain commit
@@ -181,11 +266,30 @@ pub struct ZonePolicy {
     pub allowed_upgrade_classes: Vec<String>,
     pub jurisdiction: String,
     pub neuroights_requirements: String,
+    #[serde(default)]
+    pub corridor_faces: Vec<PolytopeFace>,  // P_corr as A·x <= b; empty = unconstrained
+    #[serde(default)]
+    pub class_ceilings: BTreeMap<String, f32>,  // per-class RoH ceilings below roh_ceiling
+}
+
+impl ZonePolicy {
+    /// RoH ceiling that applies to `upgrade_class` in this zone
+    pub fn ceiling_for(&self, upgrade_class: &str) -> f32 {
+        self.class_ceilings
+            .get(upgrade_class)
+            .map_or(self.roh_ceiling, |c| c.min(self.roh_ceiling))
+    }
+}
+
+impl Default for DecisionGrammarPolicyShard {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DecisionGrammarPolicyShard {
     pub fn new() -> Self {
-        DecisionGrammarPolicyShard {
+        let mut shard = DecisionGrammarPolicyShard {
             shard_name: "policy.governance.decision-grammar.v1".to_string(),
             roh_ceiling: 0.30,
             allowed_decision_verbs: vec![
@@ -218,7 +322,20 @@ impl DecisionGrammarPolicyShard {
             },
             zone_policies: HashMap::new(),
             last_committed_hash: String::new(),
-        }
+        };
+        shard.update_hash();
+        shard
+    }
+
+    /// Replace `role_permissions` with a `decision_roles!` table
+    pub fn apply_role_table(&mut self, table: &RolePermissionTable) {
+        self.role_permissions = table.to_shard_permissions();
+        self.update_hash();
+    }
+
+    /// Read `role_permissions` back as a typed table
+    pub fn role_table(&self) -> Result<RolePermissionTable, RoleTableError> {
+        RolePermissionTable::from_shard_permissions(&self.role_permissions)
     }
 
     /// Add a zone policy
@@ -228,11 +345,17 @@ impl DecisionGrammarPolicyShard {
     }
 
     fn update_hash(&mut self) {
-        use sha2::{Sha256, Digest};
-        let json = serde_json::to_string(self).unwrap_or_default();
-        let mut hasher = Sha256::new();
-        hasher.update(json.as_bytes());
-        self.last_committed_hash = hex::encode(hasher.finalize());
+        self.last_committed_hash = self.compute_hash();
+    }
+
+    /// Hash over every field but `last_committed_hash` itself
+    pub fn compute_hash(&self) -> String {
+        canonical_hash_excluding(self, "last_committed_hash")
+    }
+
+    /// True when `last_committed_hash` seals the current contents
+    pub fn verify_hash(&self) -> bool {
+        !self.last_committed_hash.is_empty() && self.last_committed_hash == self.compute_hash()
     }
 }
 
@@ -257,6 +380,55 @@ mod tests {
         assert!(!shard.last_committed_hash.is_empty());
     }
 
+    #[test]
+    fn test_decision_ledger_inclusion_proof() {
+        let mut shard = DecisionLedgerShard::new();
+        for i in 0..5 {
+            shard.append(DecisionLedgerShardEntry {
+                key: format!("host:upgrade:evo-{}", i),
+                decision_verb: "Approve".to_string(),
+                roh_band_hex: "E078E2A".to_string(),
+                evidence_hash: "abcd1234".to_string(),
+                entry_hash: "efgh5678".to_string(),
+                timestamp: 1708357200 + i,
+                zone_id: "phoenix-west".to_string(),
+            });
+        }
+        let root = shard.root();
+        assert_eq!(root, shard.last_committed_hash);
+
+        let proof = shard.inclusion_proof(3).unwrap();
+        assert!(DecisionLedgerShard::verify_inclusion(&shard.entries[3], &proof, &root));
+        assert!(!DecisionLedgerShard::verify_inclusion(&shard.entries[2], &proof, &root));
+
+        // Accumulator is rebuilt on load
+        let reloaded: DecisionLedgerShard =
+            serde_json::from_str(&serde_json::to_string(&shard).unwrap()).unwrap();
+        assert_eq!(reloaded.root(), root);
+    }
+
+    #[test]
+    fn test_decision_ledger_rejects_tampered_root() {
+        let empty = serde_json::to_string(&DecisionLedgerShard::new()).unwrap();
+        assert!(serde_json::from_str::<DecisionLedgerShard>(&empty).is_ok());
+
+        let mut shard = DecisionLedgerShard::new();
+        shard.append(DecisionLedgerShardEntry {
+            key: "host:upgrade:evo".to_string(),
+            decision_verb: "Reject".to_string(),
+            roh_band_hex: "E078E2A".to_string(),
+            evidence_hash: "abcd1234".to_string(),
+            entry_hash: "efgh5678".to_string(),
+            timestamp: 1708357200,
+            zone_id: "phoenix-west".to_string(),
+        });
+        let mut json = serde_json::to_value(&shard).unwrap();
+        json["entries"][0]["decision_verb"] = "Approve".into();
+
+        let err = serde_json::from_value::<DecisionLedgerShard>(json).unwrap_err();
+        assert!(err.to_string().contains("shard root mismatch"));
+    }
+
     #[test]
     fn test_neuro_consent_shard() {
         let mut shard = NeurorightsConsentShard::new();
@@ -272,4 +444,33 @@ mod tests {
         shard.register(entry);
         assert_eq!(shard.consents.len(), 1);
     }
+
+    #[test]
+    fn test_policy_shard_seal_round_trip() {
+        let mut shard = DecisionGrammarPolicyShard::new();
+        assert!(shard.verify_hash());
+
+        shard.add_zone_policy(ZonePolicy {
+            zone_id: "phoenix-west".to_string(),
+            roh_ceiling: 0.25,
+            allowed_upgrade_classes: vec!["BCI".to_string()],
+            jurisdiction: "US-AZ".to_string(),
+            neuroights_requirements: "baseline".to_string(),
+            corridor_faces: Vec::new(),
+            class_ceilings: BTreeMap::new(),
+        });
+        let json = serde_json::to_string(&shard).unwrap();
+        let restored: DecisionGrammarPolicyShard = serde_json::from_str(&json).unwrap();
+        assert!(restored.verify_hash());
+        assert_eq!(restored.compute_hash(), shard.last_committed_hash);
+
+        // Sealing does not depend on the previous seal
+        let mut resealed = restored.clone();
+        resealed.last_committed_hash = "stale".to_string();
+        assert_eq!(resealed.compute_hash(), shard.last_committed_hash);
+
+        let mut tampered = restored;
+        tampered.roh_ceiling = 0.50;
+        assert!(!tampered.verify_hash());
+    }
 }
@@ -0,0 +1,236 @@
+//! Canonical JSON encoding (RFC 8785 / JCS) for every hashed governance artifact.
+//! Output is independent of struct field order, `HashMap` iteration order and float formatting.
+
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Sha256, Digest};
+
+/// Serialize `value` to RFC 8785 canonical JSON
+pub fn to_canonical_json<T: Serialize + ?Sized>(value: &T) -> Result<String, serde_json::Error> {
+    let value = serde_json::to_value(value)?;
+    let mut out = String::new();
+    write_value(&value, &mut out);
+    Ok(out)
+}
+
+/// Canonical JSON as bytes
+pub fn canonical_bytes<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    to_canonical_json(value).map(String::into_bytes)
+}
+
+/// SHA256 (hex) over the canonical JSON encoding of `value`.
+///
+/// # Panics
+/// If `value` has no JSON form (e.g. a map with non-string keys). Every hashed
+/// artifact in this crate serializes; hashing a placeholder instead would give
+/// all such values the same digest.
+pub fn canonical_hash<T: Serialize + ?Sized>(value: &T) -> String {
+    let bytes = canonical_bytes(value).expect("hashed value must serialize to JSON");
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// SHA256 (hex) over the canonical encoding of `value` with the top-level
+/// `field` removed; used for records that carry their own seal hash.
+///
+/// # Panics
+/// Same as [`canonical_hash`].
+pub fn canonical_hash_excluding<T: Serialize + ?Sized>(value: &T, field: &str) -> String {
+    let mut value = serde_json::to_value(value).expect("hashed value must serialize to JSON");
+    if let Value::Object(map) = &mut value {
+        map.remove(field);
+    }
+    canonical_hash(&value)
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                out.push_str(&i.to_string());
+            } else if let Some(u) = n.as_u64() {
+                out.push_str(&u.to_string());
+            } else {
+                out.push_str(&format_f64(n.as_f64().unwrap_or(0.0)));
+            }
+        },
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(item, out);
+            }
+            out.push(']');
+        },
+        Value::Object(map) => {
+            // RFC 8785 §3.2.3: members sorted by UTF-16 code units of the key
+            let mut members: Vec<(&String, &Value)> = map.iter().collect();
+            members.sort_by(|a, b| a.0.encode_utf16().cmp(b.0.encode_utf16()));
+            out.push('{');
+            for (i, (key, item)) in members.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(key, out);
+                out.push(':');
+                write_value(item, out);
+            }
+            out.push('}');
+        },
+    }
+}
+
+/// serde_json's escaping already matches RFC 8785 §3.2.2.2 (short escapes,
+/// lowercase `\u00xx` for other controls, everything else literal UTF-8)
+fn write_string(s: &str, out: &mut String) {
+    out.push_str(&serde_json::to_string(s).unwrap_or_default());
+}
+
+/// ECMAScript `Number.prototype.toString` for finite doubles (RFC 8785 §3.2.2.3).
+/// `f32` fields are widened to `f64` first, so `0.15f32` encodes as `0.15000000596046448`.
+fn format_f64(value: f64) -> String {
+    if value == 0.0 || !value.is_finite() {
+        // -0 serializes as 0; non-finite values never reach here via serde_json
+        return "0".to_string();
+    }
+
+    // Rust's `{:e}` yields the shortest round-trip digits, e.g. "-1.5e-7"
+    let sci = format!("{:e}", value.abs());
+    let (mantissa, exp) = sci.split_once('e').unwrap_or((&sci, "0"));
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    let n = exp.parse::<i32>().unwrap_or(0) + 1; // value = 0.digits × 10^n
+
+    let mut out = String::new();
+    if value < 0.0 {
+        out.push('-');
+    }
+    if k <= n && n <= 21 {
+        out.push_str(&digits);
+        out.extend(std::iter::repeat_n('0', (n - k) as usize));
+    } else if 0 < n && n <= 21 {
+        out.push_str(&digits[..n as usize]);
+        out.push('.');
+        out.push_str(&digits[n as usize..]);
+    } else if -6 < n && n <= 0 {
+        out.push_str("0.");
+        out.extend(std::iter::repeat_n('0', (-n) as usize));
+        out.push_str(&digits);
+    } else {
+        out.push_str(&digits[..1]);
+        if k > 1 {
+            out.push('.');
+            out.push_str(&digits[1..]);
+        }
+        out.push('e');
+        out.push(if n - 1 < 0 { '-' } else { '+' });
+        out.push_str(&(n - 1).abs().to_string());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use serde_json::json;
+
+    #[test]
+    fn test_rfc8785_number_vectors() {
+        // Vectors from RFC 8785 Appendix B
+        let cases: [(f64, &str); 10] = [
+            (0.0, "0"),
+            (-0.0, "0"),
+            (5e-324, "5e-324"),
+            (1.7976931348623157e308, "1.7976931348623157e+308"),
+            (9007199254740992.0, "9007199254740992"),
+            (295147905179352830000.0, "295147905179352830000"),
+            (1e21, "1e+21"),
+            (1e-7, "1e-7"),
+            (0.000001, "0.000001"),
+            (333333333.3333333, "333333333.3333333"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(format_f64(value), expected, "formatting {:e}", value);
+        }
+    }
+
+    #[test]
+    fn test_member_order_and_escaping() {
+        let value = json!({
+            "\u{20ac}": "Euro Sign",
+            "\r": "Carriage Return",
+            "\u{fb33}": "Hebrew Letter Dalet With Dagesh",
+            "1": "One",
+            "\u{1f600}": "Emoji: Grinning Face",
+            "\u{0080}": "Control\u{7f}",
+            "\u{00f6}": "Latin Small Letter O With Diaeresis",
+        });
+        assert_eq!(
+            to_canonical_json(&value).unwrap(),
+            "{\"\\r\":\"Carriage Return\",\"1\":\"One\",\"\u{0080}\":\"Control\u{7f}\",\
+             \"\u{00f6}\":\"Latin Small Letter O With Diaeresis\",\"\u{20ac}\":\"Euro Sign\",\
+             \"\u{1f600}\":\"Emoji: Grinning Face\",\"\u{fb33}\":\"Hebrew Letter Dalet With Dagesh\"}"
+        );
+    }
+
+    #[test]
+    fn test_serialization_errors_propagate() {
+        let mut map = HashMap::new();
+        map.insert((1, 2), "tuple keys have no JSON form");
+        assert!(canonical_bytes(&map).is_err());
+        assert!(std::panic::catch_unwind(|| canonical_hash(&map)).is_err());
+    }
+
+    #[test]
+    fn test_hashmap_order_independent() {
+        let mut a = HashMap::new();
+        let mut b = HashMap::new();
+        for role in ["HostSelf", "NeurorightsBoard", "SafetyDaemon"] {
+            a.insert(role.to_string(), vec!["Reject".to_string()]);
+        }
+        for role in ["SafetyDaemon", "HostSelf", "NeurorightsBoard"] {
+            b.insert(role.to_string(), vec!["Reject".to_string()]);
+        }
+        assert_eq!(canonical_hash(&a), canonical_hash(&b));
+        assert_eq!(
+            to_canonical_json(&a).unwrap(),
+            r#"{"HostSelf":["Reject"],"NeurorightsBoard":["Reject"],"SafetyDaemon":["Reject"]}"#
+        );
+    }
+
+    fn golden_bundle() -> crate::ledger::EvidenceBundle {
+        let mut bundle = crate::ledger::EvidenceBundle::default();
+        bundle.biomarkers.heart_rate = 72.0;
+        bundle.biomarkers.il6_level = 3.5;
+        bundle.biomarkers.glucose_blood = 95.0;
+        bundle.hrv.rmssd = 0.15;
+        bundle.eeg_corridors.eeg_corridor_state = "nominal".to_string();
+        bundle.timestamp = 1708357200;
+        bundle.zone_id = "phoenix-west".to_string();
+        bundle
+    }
+
+    #[test]
+    fn test_golden_evidence_bundle() {
+        let bundle = golden_bundle();
+        let expected = concat!(
+            r#"{"biomarkers":{"core_temperature":0,"cortisol_level":0,"crp_level":0,"diastolic_bp":0,"#,
+            r#""glucose_blood":95,"heart_rate":72,"il6_level":3.5,"lactate_level":0,"#,
+            r#""oxygen_saturation":0,"systolic_bp":0},"#,
+            r#""eeg_corridors":{"alpha_power":0,"beta_power":0,"eeg_corridor_state":"nominal","#,
+            r#""gamma_power":0,"theta_power":0},"#,
+            r#""hrv":{"hrv_index":0,"rmssd":0.15000000596046448,"sdnn":0},"#,
+            r#""timestamp":1708357200,"zone_id":"phoenix-west"}"#,
+        );
+        assert_eq!(to_canonical_json(&bundle).unwrap(), expected);
+        assert_eq!(
+            bundle.hash(),
+            "7f6c2ca3f55d44d3fa08020a07a2532a9d7ab308f95edba1d085f2c969b3146f"
+        );
+    }
+}
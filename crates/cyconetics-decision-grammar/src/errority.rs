@@ -0,0 +1,600 @@
+//! Errority ratchet: applies the allowed tightening operations from errority-ratchet.v1.aln
+//! to a hash-linked chain of envelope states. Nothing here can relax a constraint.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::canonical::canonical_hash_excluding;
+use crate::registry::GENESIS_HASH;
+use crate::PolicyTightening;
+
+/// Dimension names that would turn a hazard weight into an inner-state scoring channel
+const NEURAL_MARKERS: [&str; 5] = ["eeg", "neural", "affect", "thought", "inner_state"];
+
+/// Operations listed under `Explicitly_Forbidden_Errority_Actions`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ForbiddenAction {
+    RelaxSafetyCeiling,
+    WidenPolytopeFaces,
+    IntroduceNeuralScoringChannels,
+    AddNewGovernanceScores,
+    RemoveOrTruncateAuditTrails,
+    DisableTighteningMacros,
+}
+
+impl ForbiddenAction {
+    /// Action name as spelled in errority-ratchet.v1.aln
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ForbiddenAction::RelaxSafetyCeiling => "Relax_Safety_Ceiling",
+            ForbiddenAction::WidenPolytopeFaces => "Widen_Polytope_Faces",
+            ForbiddenAction::IntroduceNeuralScoringChannels => "Introduce_Neural_Scoring_Channels",
+            ForbiddenAction::AddNewGovernanceScores => "Add_New_Governance_Scores",
+            ForbiddenAction::RemoveOrTruncateAuditTrails => "Remove_or_Truncate_Audit_Trails",
+            ForbiddenAction::DisableTighteningMacros => "Disable_Tightening_Macros",
+        }
+    }
+}
+
+/// One half-space `a · x <= b` of a corridor polytope
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolytopeFace {
+    pub a: Vec<f32>,
+    pub b: f32,
+}
+
+/// Allowed tightening operations (`Allowed_Tightening_Actions`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ErrorityOp {
+    /// Shrink_Nanopolytope: lower the right-hand side of an existing face
+    ShrinkPolytopeFace { polytope: String, face: usize, b: f32 },
+    /// Shrink_Nanopolytope: intersect with an extra half-space
+    AddPolytopeFace { polytope: String, face: PolytopeFace },
+    LowerBciCeiling(f32),
+    LowerRohCeiling(f32),
+    IncreaseHazardWeight { dimension: String, weight: f32 },
+    QuarantineModule(String),
+    RevokeModule(String),
+}
+
+/// Errors from `ErrorityRatchet::apply` and chain verification
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ErrorityError {
+    Forbidden { action: ForbiddenAction, detail: String },
+    /// The operation would leave the envelope unchanged
+    NotATightening(String),
+    InvalidValue { field: String, value: f32 },
+    UnknownPolytope(String),
+    FaceOutOfRange { polytope: String, face: usize, faces: usize },
+    DimensionMismatch { polytope: String, expected: usize, got: usize },
+    BrokenAnchor { seq: u64 },
+    HashMismatch { seq: u64 },
+    /// The state is not its predecessor with its recorded `op` applied
+    NotDerived { seq: u64 },
+    /// The first state is not the genesis of the pinned baseline
+    GenesisMismatch,
+    /// The chain does not end at the pinned head (truncated, extended or swapped)
+    HeadMismatch { expected: String, got: String },
+}
+
+impl fmt::Display for ErrorityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorityError::Forbidden { action, detail } => {
+                write!(f, "Forbidden Errority action {}: {}", action.as_str(), detail)
+            },
+            ErrorityError::NotATightening(d) => write!(f, "NotATightening: {}", d),
+            ErrorityError::InvalidValue { field, value } => write!(f, "InvalidValue: {} = {}", field, value),
+            ErrorityError::UnknownPolytope(p) => write!(f, "UnknownPolytope: {}", p),
+            ErrorityError::FaceOutOfRange { polytope, face, faces } => {
+                write!(f, "FaceOutOfRange: {} face {} (has {})", polytope, face, faces)
+            },
+            ErrorityError::DimensionMismatch { polytope, expected, got } => {
+                write!(f, "DimensionMismatch: {} expects {} coefficients, got {}", polytope, expected, got)
+            },
+            ErrorityError::BrokenAnchor { seq } => write!(f, "BrokenAnchor at state {}", seq),
+            ErrorityError::HashMismatch { seq } => write!(f, "HashMismatch at state {}", seq),
+            ErrorityError::NotDerived { seq } => write!(f, "NotDerived: state {} does not follow from its op", seq),
+            ErrorityError::GenesisMismatch => write!(f, "GenesisMismatch: chain does not start at the pinned baseline"),
+            ErrorityError::HeadMismatch { expected, got } => {
+                write!(f, "HeadMismatch: chain ends at {}, pinned head is {}", got, expected)
+            },
+        }
+    }
+}
+
+impl std::error::Error for ErrorityError {}
+
+/// Envelope in force before any Errority event
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ErrorityBaseline {
+    pub bci_ceiling: f32,
+    pub roh_ceiling: f32,
+    pub polytopes: BTreeMap<String, Vec<PolytopeFace>>,
+    pub hazard_weights: BTreeMap<String, f32>,
+}
+
+impl ErrorityBaseline {
+    /// Seq-0 state of every chain grown from this baseline
+    pub fn genesis(&self) -> ErrorityState {
+        let mut genesis = ErrorityState {
+            seq: 0,
+            bci_ceiling: self.bci_ceiling,
+            roh_ceiling: self.roh_ceiling,
+            polytopes: self.polytopes.clone(),
+            hazard_weights: self.hazard_weights.clone(),
+            quarantined_modules: BTreeSet::new(),
+            revoked_modules: BTreeSet::new(),
+            errority_id: None,
+            op: None,
+            merkle_anchor: GENESIS_HASH.to_string(),
+            state_hash: String::new(),
+        };
+        genesis.state_hash = genesis.compute_hash();
+        genesis
+    }
+}
+
+/// One link of the ratchet chain (`evolution_audit_record` shape)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorityState {
+    pub seq: u64,
+    pub bci_ceiling: f32,
+    pub roh_ceiling: f32,
+    pub polytopes: BTreeMap<String, Vec<PolytopeFace>>,
+    pub hazard_weights: BTreeMap<String, f32>,
+    pub quarantined_modules: BTreeSet<String>,
+    pub revoked_modules: BTreeSet<String>,
+    pub errority_id: Option<String>,  // incident that caused this state; None for genesis
+    pub op: Option<ErrorityOp>,
+    pub merkle_anchor: String,        // state_hash of the predecessor
+    pub state_hash: String,
+}
+
+impl ErrorityState {
+    pub fn compute_hash(&self) -> String {
+        canonical_hash_excluding(self, "state_hash")
+    }
+
+    /// Quarantined and revoked modules are both denied load/execute/actuate
+    pub fn is_module_blocked(&self, module_id: &str) -> bool {
+        self.quarantined_modules.contains(module_id) || self.revoked_modules.contains(module_id)
+    }
+
+    /// Same constraints in force, ignoring chain bookkeeping
+    fn same_envelope(&self, other: &ErrorityState) -> bool {
+        self.bci_ceiling == other.bci_ceiling
+            && self.roh_ceiling == other.roh_ceiling
+            && self.polytopes == other.polytopes
+            && self.hazard_weights == other.hazard_weights
+            && self.quarantined_modules == other.quarantined_modules
+            && self.revoked_modules == other.revoked_modules
+    }
+}
+
+/// Append-only chain of Errority states; the only mutator is `apply`.
+/// Not `Clone`, so a chain cannot be forked, and not `Deserialize`: a persisted
+/// chain comes back as an `ErrorityChain` and is restored against its pins.
+#[derive(Debug, Serialize)]
+pub struct ErrorityRatchet {
+    states: Vec<ErrorityState>,
+}
+
+/// Serialized form of an `ErrorityRatchet`, unverified until `restore`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorityChain {
+    pub states: Vec<ErrorityState>,
+}
+
+impl ErrorityChain {
+    /// See `ErrorityRatchet::from_states`
+    pub fn restore(self, baseline: &ErrorityBaseline, head_hash: &str) -> Result<ErrorityRatchet, ErrorityError> {
+        ErrorityRatchet::from_states(baseline, head_hash, self.states)
+    }
+}
+
+impl ErrorityRatchet {
+    pub fn new(baseline: ErrorityBaseline) -> Self {
+        Self { states: vec![baseline.genesis()] }
+    }
+
+    /// Restore a persisted chain that must start at `baseline`'s genesis and end at
+    /// the pinned `head_hash`, verifying every anchor and hash in between.
+    /// Without both pins a truncated prefix or a chain from another baseline would verify.
+    pub fn from_states(
+        baseline: &ErrorityBaseline,
+        head_hash: &str,
+        states: Vec<ErrorityState>,
+    ) -> Result<Self, ErrorityError> {
+        let Some(first) = states.first() else {
+            return Err(ErrorityError::Forbidden {
+                action: ForbiddenAction::RemoveOrTruncateAuditTrails,
+                detail: "empty Errority chain".to_string(),
+            });
+        };
+        if first.state_hash != baseline.genesis().state_hash {
+            return Err(ErrorityError::GenesisMismatch);
+        }
+        let ratchet = Self { states };
+        ratchet.verify_chain()?;
+        if ratchet.head_hash() != head_hash {
+            return Err(ErrorityError::HeadMismatch {
+                expected: head_hash.to_string(),
+                got: ratchet.head_hash().to_string(),
+            });
+        }
+        Ok(ratchet)
+    }
+
+    /// Hash to pin alongside the baseline when persisting the chain
+    pub fn head_hash(&self) -> &str {
+        &self.current().state_hash
+    }
+
+    pub fn current(&self) -> &ErrorityState {
+        self.states.last().expect("ratchet always holds a genesis state")
+    }
+
+    pub fn states(&self) -> &[ErrorityState] {
+        &self.states
+    }
+
+    /// Apply one tightening caused by `errority_id`; rejected ops leave the chain untouched
+    pub fn apply(&mut self, errority_id: &str, op: ErrorityOp) -> Result<&ErrorityState, ErrorityError> {
+        let prev = self.current();
+        let mut next = prev.clone();
+        tighten(&mut next, &op)?;
+
+        next.seq = prev.seq + 1;
+        next.errority_id = Some(errority_id.to_string());
+        next.op = Some(op);
+        next.merkle_anchor = prev.state_hash.clone();
+        next.state_hash = next.compute_hash();
+        self.states.push(next);
+        Ok(self.current())
+    }
+
+    /// Apply a `roh_policy!` tightening as a Lower_RoH_Ceiling step
+    pub fn apply_policy_tightening(
+        &mut self,
+        errority_id: &str,
+        tightening: &PolicyTightening,
+    ) -> Result<&ErrorityState, ErrorityError> {
+        self.apply(errority_id, ErrorityOp::LowerRohCeiling(tightening.new_roh_ceiling))
+    }
+
+    /// Check every anchor and hash, and that each state is its predecessor
+    /// tightened by its recorded op (so a re-hashed relaxation is still caught)
+    pub fn verify_chain(&self) -> Result<(), ErrorityError> {
+        let mut anchor = GENESIS_HASH;
+        for (i, state) in self.states.iter().enumerate() {
+            if state.seq != i as u64 || state.merkle_anchor != anchor {
+                return Err(ErrorityError::BrokenAnchor { seq: i as u64 });
+            }
+            if state.compute_hash() != state.state_hash {
+                return Err(ErrorityError::HashMismatch { seq: state.seq });
+            }
+            if i > 0 {
+                let op = state.op.as_ref().ok_or(ErrorityError::NotDerived { seq: state.seq })?;
+                let mut expected = self.states[i - 1].clone();
+                tighten(&mut expected, op)?;
+                if !expected.same_envelope(state) {
+                    return Err(ErrorityError::NotDerived { seq: state.seq });
+                }
+            }
+            anchor = &state.state_hash;
+        }
+        Ok(())
+    }
+}
+
+fn check_value(field: &str, value: f32) -> Result<(), ErrorityError> {
+    if value.is_finite() && value >= 0.0 {
+        Ok(())
+    } else {
+        Err(ErrorityError::InvalidValue { field: field.to_string(), value })
+    }
+}
+
+fn lower_ceiling(field: &str, current: &mut f32, proposed: f32) -> Result<(), ErrorityError> {
+    check_value(field, proposed)?;
+    if proposed > *current {
+        return Err(ErrorityError::Forbidden {
+            action: ForbiddenAction::RelaxSafetyCeiling,
+            detail: format!("{} {} -> {}", field, current, proposed),
+        });
+    }
+    if proposed == *current {
+        return Err(ErrorityError::NotATightening(format!("{} already {}", field, current)));
+    }
+    *current = proposed;
+    Ok(())
+}
+
+fn tighten(state: &mut ErrorityState, op: &ErrorityOp) -> Result<(), ErrorityError> {
+    match op {
+        ErrorityOp::LowerBciCeiling(v) => lower_ceiling("bci_ceiling", &mut state.bci_ceiling, *v),
+        ErrorityOp::LowerRohCeiling(v) => lower_ceiling("roh_ceiling", &mut state.roh_ceiling, *v),
+        ErrorityOp::ShrinkPolytopeFace { polytope, face, b } => {
+            let faces = state
+                .polytopes
+                .get_mut(polytope)
+                .ok_or_else(|| ErrorityError::UnknownPolytope(polytope.clone()))?;
+            let count = faces.len();
+            let target = faces.get_mut(*face).ok_or_else(|| ErrorityError::FaceOutOfRange {
+                polytope: polytope.clone(),
+                face: *face,
+                faces: count,
+            })?;
+            if !b.is_finite() {
+                return Err(ErrorityError::InvalidValue { field: format!("{}[{}].b", polytope, face), value: *b });
+            }
+            if *b > target.b {
+                return Err(ErrorityError::Forbidden {
+                    action: ForbiddenAction::WidenPolytopeFaces,
+                    detail: format!("{}[{}].b {} -> {}", polytope, face, target.b, b),
+                });
+            }
+            if *b == target.b {
+                return Err(ErrorityError::NotATightening(format!("{}[{}].b already {}", polytope, face, b)));
+            }
+            target.b = *b;
+            Ok(())
+        },
+        ErrorityOp::AddPolytopeFace { polytope, face } => {
+            let faces = state
+                .polytopes
+                .get_mut(polytope)
+                .ok_or_else(|| ErrorityError::UnknownPolytope(polytope.clone()))?;
+            let expected = faces.first().map(|f| f.a.len()).unwrap_or(face.a.len());
+            if face.a.len() != expected {
+                return Err(ErrorityError::DimensionMismatch {
+                    polytope: polytope.clone(),
+                    expected,
+                    got: face.a.len(),
+                });
+            }
+            if !face.b.is_finite() || face.a.iter().any(|c| !c.is_finite()) {
+                return Err(ErrorityError::InvalidValue { field: format!("{}.new_face", polytope), value: face.b });
+            }
+            faces.push(face.clone());
+            Ok(())
+        },
+        ErrorityOp::IncreaseHazardWeight { dimension, weight } => {
+            let lowered = dimension.to_lowercase();
+            if NEURAL_MARKERS.iter().any(|m| lowered.contains(m)) {
+                return Err(ErrorityError::Forbidden {
+                    action: ForbiddenAction::IntroduceNeuralScoringChannels,
+                    detail: format!("hazard dimension {}", dimension),
+                });
+            }
+            let current = state.hazard_weights.get_mut(dimension).ok_or_else(|| ErrorityError::Forbidden {
+                action: ForbiddenAction::AddNewGovernanceScores,
+                detail: format!("hazard dimension {} is not part of the baseline", dimension),
+            })?;
+            check_value(dimension, *weight)?;
+            if *weight < *current {
+                return Err(ErrorityError::Forbidden {
+                    action: ForbiddenAction::RelaxSafetyCeiling,
+                    detail: format!("hazard weight {} {} -> {}", dimension, current, weight),
+                });
+            }
+            if *weight == *current {
+                return Err(ErrorityError::NotATightening(format!("hazard weight {} already {}", dimension, weight)));
+            }
+            *current = *weight;
+            Ok(())
+        },
+        ErrorityOp::QuarantineModule(module) => {
+            if state.is_module_blocked(module) {
+                return Err(ErrorityError::NotATightening(format!("module {} already blocked", module)));
+            }
+            state.quarantined_modules.insert(module.clone());
+            Ok(())
+        },
+        ErrorityOp::RevokeModule(module) => {
+            if !state.revoked_modules.insert(module.clone()) {
+                return Err(ErrorityError::NotATightening(format!("module {} already revoked", module)));
+            }
+            state.quarantined_modules.remove(module);
+            Ok(())
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn baseline() -> ErrorityBaseline {
+        let mut polytopes = BTreeMap::new();
+        polytopes.insert(
+            "Pbee".to_string(),
+            vec![
+                PolytopeFace { a: vec![1.0, 0.0], b: 15.0 },
+                PolytopeFace { a: vec![0.0, 1.0], b: 0.4 },
+            ],
+        );
+        let mut hazard_weights = BTreeMap::new();
+        hazard_weights.insert("rf_bee".to_string(), 1.5);
+        ErrorityBaseline { bci_ceiling: 0.30, roh_ceiling: 0.30, polytopes, hazard_weights }
+    }
+
+    #[test]
+    fn test_allowed_tightenings_are_hash_linked() {
+        let mut ratchet = ErrorityRatchet::new(baseline());
+        ratchet.apply("ERR-1", ErrorityOp::LowerRohCeiling(0.28)).unwrap();
+        ratchet
+            .apply("ERR-2", ErrorityOp::ShrinkPolytopeFace { polytope: "Pbee".into(), face: 0, b: 8.0 })
+            .unwrap();
+        ratchet
+            .apply("ERR-2", ErrorityOp::IncreaseHazardWeight { dimension: "rf_bee".into(), weight: 2.0 })
+            .unwrap();
+        ratchet.apply("ERR-3", ErrorityOp::QuarantineModule("nanoswarm-ctl".into())).unwrap();
+
+        let state = ratchet.current();
+        assert_eq!(state.seq, 4);
+        assert_eq!(state.merkle_anchor, ratchet.states()[3].state_hash);
+        assert!(state.is_module_blocked("nanoswarm-ctl"));
+        assert!(ratchet.verify_chain().is_ok());
+
+        let mut tampered = ratchet.states().to_vec();
+        tampered[1].roh_ceiling = 0.30;
+        assert_eq!(
+            ErrorityRatchet::from_states(&baseline(), ratchet.head_hash(), tampered).unwrap_err(),
+            ErrorityError::HashMismatch { seq: 1 }
+        );
+    }
+
+    /// Re-link `states` after an edit so only the monotonicity check can catch it
+    fn relink(states: &mut [ErrorityState]) {
+        let mut anchor = GENESIS_HASH.to_string();
+        for state in states {
+            state.merkle_anchor = anchor;
+            state.state_hash = state.compute_hash();
+            anchor = state.state_hash.clone();
+        }
+    }
+
+    fn restore(json: serde_json::Value, head: &str) -> Result<ErrorityRatchet, ErrorityError> {
+        serde_json::from_value::<ErrorityChain>(json).unwrap().restore(&baseline(), head)
+    }
+
+    #[test]
+    fn test_deserialize_verifies_chain() {
+        let mut ratchet = ErrorityRatchet::new(baseline());
+        ratchet.apply("ERR-1", ErrorityOp::LowerRohCeiling(0.25)).unwrap();
+        ratchet.apply("ERR-2", ErrorityOp::QuarantineModule("nanoswarm-ctl".into())).unwrap();
+        let head = ratchet.head_hash();
+        let json = serde_json::to_value(&ratchet).unwrap();
+        let restored = restore(json.clone(), head).unwrap();
+        assert_eq!(restored.states(), ratchet.states());
+
+        // Edited without re-hashing
+        let mut tampered = json.clone();
+        tampered["states"][1]["roh_ceiling"] = 0.30.into();
+        assert_eq!(restore(tampered, head).unwrap_err(), ErrorityError::HashMismatch { seq: 1 });
+
+        // Relaxed and fully re-linked: every hash checks out, the ceiling went back up
+        let mut states = ratchet.states().to_vec();
+        states[2].roh_ceiling = 0.30;
+        relink(&mut states);
+        assert_eq!(
+            ErrorityRatchet::from_states(&baseline(), head, states).unwrap_err(),
+            ErrorityError::NotDerived { seq: 2 }
+        );
+
+        // Op rewritten to match a relaxed state
+        let mut states = ratchet.states().to_vec();
+        states[1].roh_ceiling = 0.35;
+        states[1].op = Some(ErrorityOp::LowerRohCeiling(0.35));
+        states[2].roh_ceiling = 0.35;
+        relink(&mut states);
+        let err = ErrorityRatchet::from_states(&baseline(), head, states).unwrap_err();
+        assert!(matches!(err, ErrorityError::Forbidden { action: ForbiddenAction::RelaxSafetyCeiling, .. }));
+
+        // Truncated to nothing
+        let err = restore(serde_json::json!({ "states": [] }), head).unwrap_err();
+        assert!(err.to_string().contains("Remove_or_Truncate_Audit_Trails"));
+    }
+
+    #[test]
+    fn test_restore_requires_pinned_genesis_and_head() {
+        let mut ratchet = ErrorityRatchet::new(baseline());
+        ratchet.apply("ERR-1", ErrorityOp::LowerRohCeiling(0.25)).unwrap();
+        ratchet.apply("ERR-2", ErrorityOp::RevokeModule("nanoswarm-ctl".into())).unwrap();
+        let head = ratchet.head_hash().to_string();
+
+        // A valid prefix that drops the revocation
+        let truncated = ratchet.states()[..2].to_vec();
+        let err = ErrorityRatchet::from_states(&baseline(), &head, truncated.clone()).unwrap_err();
+        assert!(matches!(err, ErrorityError::HeadMismatch { ref expected, .. } if *expected == head));
+        let prefix_head = truncated[1].state_hash.clone();
+        assert!(ErrorityRatchet::from_states(&baseline(), &prefix_head, truncated).is_ok());
+
+        // A self-consistent chain grown from a looser baseline
+        let mut loose = baseline();
+        loose.roh_ceiling = 0.50;
+        let mut foreign = ErrorityRatchet::new(loose);
+        foreign.apply("ERR-1", ErrorityOp::LowerRohCeiling(0.45)).unwrap();
+        let foreign_head = foreign.head_hash().to_string();
+        assert_eq!(
+            ErrorityRatchet::from_states(&baseline(), &foreign_head, foreign.states().to_vec()).unwrap_err(),
+            ErrorityError::GenesisMismatch
+        );
+    }
+
+    #[test]
+    fn test_forbidden_operations_are_typed() {
+        let mut ratchet = ErrorityRatchet::new(baseline());
+        ratchet.apply("ERR-1", ErrorityOp::LowerRohCeiling(0.25)).unwrap();
+
+        let forbidden = |r: Result<&ErrorityState, ErrorityError>| match r {
+            Err(ErrorityError::Forbidden { action, .. }) => Some(action),
+            _ => None,
+        };
+        assert_eq!(
+            forbidden(ratchet.apply("ERR-2", ErrorityOp::LowerRohCeiling(0.30))),
+            Some(ForbiddenAction::RelaxSafetyCeiling)
+        );
+        assert_eq!(
+            forbidden(ratchet.apply(
+                "ERR-2",
+                ErrorityOp::ShrinkPolytopeFace { polytope: "Pbee".into(), face: 1, b: 0.6 }
+            )),
+            Some(ForbiddenAction::WidenPolytopeFaces)
+        );
+        assert_eq!(
+            forbidden(ratchet.apply(
+                "ERR-2",
+                ErrorityOp::IncreaseHazardWeight { dimension: "eeg_affect".into(), weight: 3.0 }
+            )),
+            Some(ForbiddenAction::IntroduceNeuralScoringChannels)
+        );
+        assert_eq!(
+            forbidden(ratchet.apply(
+                "ERR-2",
+                ErrorityOp::IncreaseHazardWeight { dimension: "loyalty".into(), weight: 3.0 }
+            )),
+            Some(ForbiddenAction::AddNewGovernanceScores)
+        );
+        assert_eq!(ratchet.states().len(), 2);
+    }
+
+    fn arb_op() -> impl Strategy<Value = ErrorityOp> {
+        prop_oneof![
+            (0.0f32..0.5).prop_map(ErrorityOp::LowerBciCeiling),
+            (0.0f32..0.5).prop_map(ErrorityOp::LowerRohCeiling),
+            (0usize..3, 0.0f32..20.0)
+                .prop_map(|(face, b)| ErrorityOp::ShrinkPolytopeFace { polytope: "Pbee".into(), face, b }),
+            (0.0f32..4.0)
+                .prop_map(|weight| ErrorityOp::IncreaseHazardWeight { dimension: "rf_bee".into(), weight }),
+            (0u8..4).prop_map(|m| ErrorityOp::QuarantineModule(format!("mod-{}", m))),
+            (0u8..4).prop_map(|m| ErrorityOp::RevokeModule(format!("mod-{}", m))),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn prop_ceilings_never_increase(ops in proptest::collection::vec(arb_op(), 0..40)) {
+            let mut ratchet = ErrorityRatchet::new(baseline());
+            for (i, op) in ops.into_iter().enumerate() {
+                let _ = ratchet.apply(&format!("ERR-{}", i), op);
+            }
+            prop_assert!(ratchet.verify_chain().is_ok());
+            for pair in ratchet.states().windows(2) {
+                let (prev, next) = (&pair[0], &pair[1]);
+                prop_assert!(next.bci_ceiling <= prev.bci_ceiling);
+                prop_assert!(next.roh_ceiling <= prev.roh_ceiling);
+                prop_assert!(next.hazard_weights["rf_bee"] >= prev.hazard_weights["rf_bee"]);
+                for (p, n) in prev.polytopes["Pbee"].iter().zip(&next.polytopes["Pbee"]) {
+                    prop_assert!(n.b <= p.b);
+                }
+                prop_assert!(prev.revoked_modules.is_subset(&next.revoked_modules));
+            }
+        }
+    }
+}
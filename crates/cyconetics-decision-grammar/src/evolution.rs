@@ -0,0 +1,171 @@
+//! Runtime support for `evolutiongraph!` state machines.
+//! The macro proves RoH bounds at compile time; this module enforces declared transitions at runtime.
+
+use std::fmt;
+
+use crate::types::DecisionKind;
+
+/// RoH ceiling every unguarded evolution path must stay below
+pub const EVOLUTION_ROH_CEILING: f32 = 0.30;
+
+/// One declared transition; `roh_delta` includes the target node's own delta
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EvolutionEdge<N: 'static> {
+    pub from: N,
+    pub to: N,
+    pub kind: &'static str,  // DecisionKind::verb()
+    pub roh_delta: f32,
+}
+
+impl<N> EvolutionEdge<N> {
+    /// Reject and Escalate hand the path to a human or end it, so they are exempt from the ceiling
+    pub fn is_guard(&self) -> bool {
+        self.kind == "Reject" || self.kind == "Escalate"
+    }
+}
+
+/// Errors raised when driving an evolution state machine
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransitionError {
+    /// No edge `from -> to` with this decision kind was declared
+    Undeclared { from: String, to: String, kind: String },
+    /// An Authorize/Approve/Defer edge would push the live RoH to the ceiling
+    RoHCeiling { from: String, to: String, predicted: f32 },
+}
+
+impl fmt::Display for TransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransitionError::Undeclared { from, to, kind } => {
+                write!(f, "undeclared transition {} -[{}]-> {}", from, kind, to)
+            },
+            TransitionError::RoHCeiling { from, to, predicted } => write!(
+                f,
+                "transition {} -> {} would reach RoH {:.3} (ceiling {:.2})",
+                from, to, predicted, EVOLUTION_ROH_CEILING
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TransitionError {}
+
+/// Runtime machine over a macro-generated node enum and edge table
+#[derive(Debug, Clone)]
+pub struct EvolutionMachine<N: 'static> {
+    edges: &'static [EvolutionEdge<N>],
+    node: N,
+    roh: f32,
+}
+
+impl<N: Copy + PartialEq + fmt::Debug + 'static> EvolutionMachine<N> {
+    pub fn new(edges: &'static [EvolutionEdge<N>], initial: N) -> Self {
+        Self { edges, node: initial, roh: 0.0 }
+    }
+
+    /// Resume at `node` with the host's currently observed RoH
+    pub fn resume(edges: &'static [EvolutionEdge<N>], node: N, roh: f32) -> Self {
+        Self { edges, node, roh }
+    }
+
+    pub fn node(&self) -> N {
+        self.node
+    }
+
+    pub fn roh(&self) -> f32 {
+        self.roh
+    }
+
+    /// Declared edges leaving the current node
+    pub fn available(&self) -> impl Iterator<Item = &'static EvolutionEdge<N>> + '_ {
+        self.edges.iter().filter(move |e| e.from == self.node)
+    }
+
+    pub fn can_take(&self, kind: &DecisionKind, to: N) -> bool {
+        self.find(kind, to).is_some()
+    }
+
+    /// Take a declared transition, returning the new cumulative RoH
+    pub fn take(&mut self, kind: &DecisionKind, to: N) -> Result<f32, TransitionError> {
+        let edge = self.find(kind, to).ok_or_else(|| TransitionError::Undeclared {
+            from: format!("{:?}", self.node),
+            to: format!("{:?}", to),
+            kind: kind.verb().to_string(),
+        })?;
+
+        let predicted = self.roh + edge.roh_delta;
+        if !edge.is_guard() && predicted >= EVOLUTION_ROH_CEILING {
+            return Err(TransitionError::RoHCeiling {
+                from: format!("{:?}", self.node),
+                to: format!("{:?}", to),
+                predicted,
+            });
+        }
+
+        self.node = to;
+        self.roh = predicted.max(0.0);
+        Ok(self.roh)
+    }
+
+    fn find(&self, kind: &DecisionKind, to: N) -> Option<&'static EvolutionEdge<N>> {
+        let verb = kind.verb();
+        self.edges
+            .iter()
+            .find(|e| e.from == self.node && e.to == to && e.kind == verb)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evolutiongraph;
+
+    evolutiongraph! {
+        evolution PhoenixBCIPath {
+            node Init;
+            node Mild;
+            node Advanced;
+
+            edge Init -> Mild { kind: Authorize, roh_delta: 0.08 }
+            edge Mild -> Advanced { kind: Authorize, roh_delta: 0.15 }
+            edge Mild -> Advanced { kind: Escalate, roh_delta: 0.25 }
+            edge Advanced -> Init { kind: Reject, roh_delta: -0.20 }
+        }
+    }
+
+    #[test]
+    fn test_runtime_machine_only_takes_declared_edges() {
+        use phoenix_bci_path::Node;
+
+        let mut machine = phoenix_bci_path::machine();
+        assert_eq!(machine.node(), Node::Init);
+        assert!(machine.take(&DecisionKind::Authorize, Node::Advanced).is_err());
+
+        machine.take(&DecisionKind::Authorize, Node::Mild).unwrap();
+        machine.take(&DecisionKind::Authorize, Node::Advanced).unwrap();
+        assert!((machine.roh() - 0.23).abs() < 1e-6);
+        assert!(machine.take(&DecisionKind::Approve, Node::Init).is_err());
+        machine.take(&DecisionKind::Reject, Node::Init).unwrap();
+        assert!((phoenix_bci_path::MAX_UNGUARDED_ROH - 0.23).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_runtime_ceiling_uses_live_roh() {
+        use phoenix_bci_path::Node;
+
+        let mut machine = EvolutionMachine::resume(phoenix_bci_path::EDGES, Node::Mild, 0.20);
+        let err = machine.take(&DecisionKind::Authorize, Node::Advanced).unwrap_err();
+        assert!(matches!(err, TransitionError::RoHCeiling { .. }));
+        assert!(machine.take(&DecisionKind::Escalate, Node::Advanced).is_ok());
+    }
+
+    #[test]
+    fn test_typestate_transitions() {
+        let init = phoenix_bci_path::Init::new();
+        let mild = init.authorize_to_mild();
+        let advanced = mild.escalate_to_advanced();
+        assert!((advanced.roh() - 0.33).abs() < 1e-6);
+        let back = advanced.reject_to_init();
+        assert!(back.roh() < 0.30);
+    }
+}
@@ -0,0 +1,419 @@
+//! Cross-zone "strictest-envelope-wins" harmonization.
+//! global_ceiling = min_z RoH_ceiling[z], global_polytope = ⋂_z P_corr[z], classes intersected.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::aln_shards::{DecisionGrammarPolicyShard, ZonePolicy};
+use crate::errority::PolytopeFace;
+use crate::PolicyTightening;
+
+/// A constraint in the effective policy and the zone it came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum HarmonizedConstraint {
+    RoHCeiling(f32),
+    ClassCeiling { class: String, ceiling: f32 },
+    ExcludedClass(String),
+    CorridorFace(PolytopeFace),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contribution {
+    pub zone_id: String,  // or the shard name for the grammar-wide ceiling
+    pub constraint: HarmonizedConstraint,
+}
+
+/// Strictest envelope over a host's active zones
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EffectivePolicy {
+    pub zones: Vec<String>,
+    pub roh_ceiling: f32,
+    pub allowed_upgrade_classes: Vec<String>,
+    pub class_ceilings: BTreeMap<String, f32>,  // only classes stricter than roh_ceiling
+    pub corridor_faces: Vec<PolytopeFace>,
+    pub contributions: Vec<Contribution>,
+}
+
+impl EffectivePolicy {
+    /// RoH ceiling that applies to `upgrade_class`
+    pub fn ceiling_for(&self, upgrade_class: &str) -> f32 {
+        self.class_ceilings.get(upgrade_class).copied().unwrap_or(self.roh_ceiling)
+    }
+
+    pub fn admits(&self, upgrade_class: &str, predicted_roh: f32) -> bool {
+        predicted_roh < self.ceiling_for(upgrade_class)
+            && self.allowed_upgrade_classes.iter().any(|c| c == upgrade_class)
+    }
+
+    /// Zone that set the effective RoH ceiling
+    pub fn ceiling_source(&self) -> Option<&str> {
+        self.contributions.iter().find_map(|c| match c.constraint {
+            HarmonizedConstraint::RoHCeiling(_) => Some(c.zone_id.as_str()),
+            _ => None,
+        })
+    }
+
+    /// One line per contributing constraint, for operator review
+    pub fn explain(&self) -> Vec<String> {
+        self.contributions
+            .iter()
+            .map(|c| match &c.constraint {
+                HarmonizedConstraint::RoHCeiling(v) => format!("roh_ceiling {:.2} from {}", v, c.zone_id),
+                HarmonizedConstraint::ClassCeiling { class, ceiling } => {
+                    format!("roh_ceiling {:.2} for class {} from {}", ceiling, class, c.zone_id)
+                },
+                HarmonizedConstraint::ExcludedClass(class) => format!("class {} excluded by {}", class, c.zone_id),
+                HarmonizedConstraint::CorridorFace(face) => {
+                    format!("corridor face {:?}·x <= {} from {}", face.a, face.b, c.zone_id)
+                },
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HarmonizeError {
+    UnknownZone(String),
+    NoActiveZones,
+    DimensionMismatch { zone_id: String, expected: usize, got: usize },
+    /// A zone update would loosen its envelope (strictest-envelope-wins is one-way)
+    Relaxation { zone_id: String, detail: String },
+}
+
+impl fmt::Display for HarmonizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HarmonizeError::UnknownZone(z) => write!(f, "UnknownZone: {}", z),
+            HarmonizeError::NoActiveZones => write!(f, "NoActiveZones"),
+            HarmonizeError::DimensionMismatch { zone_id, expected, got } => {
+                write!(f, "DimensionMismatch: zone {} face has {} coefficients, expected {}", zone_id, got, expected)
+            },
+            HarmonizeError::Relaxation { zone_id, detail } => write!(f, "Relaxation in zone {}: {}", zone_id, detail),
+        }
+    }
+}
+
+impl std::error::Error for HarmonizeError {}
+
+/// Zone policies plus each host's active zone set; effective policies are kept current
+#[derive(Debug, Clone)]
+pub struct ZoneHarmonizer {
+    source: String,
+    base_ceiling: f32,
+    zones: BTreeMap<String, ZonePolicy>,
+    hosts: BTreeMap<String, BTreeSet<String>>,
+    effective: BTreeMap<String, EffectivePolicy>,
+}
+
+impl ZoneHarmonizer {
+    pub fn from_shard(shard: &DecisionGrammarPolicyShard) -> Self {
+        Self {
+            source: shard.shard_name.clone(),
+            base_ceiling: shard.roh_ceiling,
+            zones: shard.zone_policies.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+            hosts: BTreeMap::new(),
+            effective: BTreeMap::new(),
+        }
+    }
+
+    pub fn zone(&self, zone_id: &str) -> Option<&ZonePolicy> {
+        self.zones.get(zone_id)
+    }
+
+    /// Effective policy for a set of zones, without tracking a host
+    pub fn harmonize<'a>(&self, zone_ids: impl IntoIterator<Item = &'a str>) -> Result<EffectivePolicy, HarmonizeError> {
+        let mut active = Vec::new();
+        for id in zone_ids.into_iter().collect::<BTreeSet<_>>() {
+            active.push(self.zones.get(id).ok_or_else(|| HarmonizeError::UnknownZone(id.to_string()))?);
+        }
+        if active.is_empty() {
+            return Err(HarmonizeError::NoActiveZones);
+        }
+
+        let mut contributions = Vec::new();
+
+        // Ceiling: the grammar-wide ceiling wins ties so zone overrides only show when stricter
+        let mut ceiling = (self.source.as_str(), self.base_ceiling);
+        for zone in &active {
+            if zone.roh_ceiling < ceiling.1 {
+                ceiling = (zone.zone_id.as_str(), zone.roh_ceiling);
+            }
+        }
+        contributions.push(Contribution {
+            zone_id: ceiling.0.to_string(),
+            constraint: HarmonizedConstraint::RoHCeiling(ceiling.1),
+        });
+
+        // Classes: every zone must allow a class for it to survive
+        let universe: BTreeSet<&String> = active.iter().flat_map(|z| &z.allowed_upgrade_classes).collect();
+        let mut allowed = Vec::new();
+        for class in universe {
+            let excluding: Vec<&&ZonePolicy> =
+                active.iter().filter(|z| !z.allowed_upgrade_classes.contains(class)).collect();
+            if excluding.is_empty() {
+                allowed.push(class.clone());
+            }
+            contributions.extend(excluding.into_iter().map(|z| Contribution {
+                zone_id: z.zone_id.clone(),
+                constraint: HarmonizedConstraint::ExcludedClass(class.clone()),
+            }));
+        }
+
+        // Class ceilings: kept only for surviving classes and only where stricter than the ceiling
+        let mut class_ceilings = BTreeMap::new();
+        for class in &allowed {
+            let mut strictest = (ceiling.0, ceiling.1);
+            for zone in &active {
+                let zone_ceiling = zone.ceiling_for(class);
+                if zone_ceiling < strictest.1 {
+                    strictest = (zone.zone_id.as_str(), zone_ceiling);
+                }
+            }
+            if strictest.1 < ceiling.1 {
+                class_ceilings.insert(class.clone(), strictest.1);
+                contributions.push(Contribution {
+                    zone_id: strictest.0.to_string(),
+                    constraint: HarmonizedConstraint::ClassCeiling { class: class.clone(), ceiling: strictest.1 },
+                });
+            }
+        }
+
+        // Polytope: intersecting H-polytopes is concatenating their faces
+        let mut faces: Vec<PolytopeFace> = Vec::new();
+        for zone in &active {
+            for face in &zone.corridor_faces {
+                if let Some(expected) = faces.first().map(|f| f.a.len()) {
+                    if face.a.len() != expected {
+                        return Err(HarmonizeError::DimensionMismatch {
+                            zone_id: zone.zone_id.clone(),
+                            expected,
+                            got: face.a.len(),
+                        });
+                    }
+                }
+                faces.push(face.clone());
+                contributions.push(Contribution {
+                    zone_id: zone.zone_id.clone(),
+                    constraint: HarmonizedConstraint::CorridorFace(face.clone()),
+                });
+            }
+        }
+
+        Ok(EffectivePolicy {
+            zones: active.iter().map(|z| z.zone_id.clone()).collect(),
+            roh_ceiling: ceiling.1,
+            allowed_upgrade_classes: allowed,
+            class_ceilings,
+            corridor_faces: faces,
+            contributions,
+        })
+    }
+
+    /// Record the zones a host is active in and return its effective policy
+    pub fn set_active_zones(&mut self, host_did: &str, zone_ids: &[&str]) -> Result<&EffectivePolicy, HarmonizeError> {
+        let policy = self.harmonize(zone_ids.iter().copied())?;
+        self.hosts
+            .insert(host_did.to_string(), zone_ids.iter().map(|z| z.to_string()).collect());
+        self.effective.insert(host_did.to_string(), policy);
+        Ok(&self.effective[host_did])
+    }
+
+    pub fn effective(&self, host_did: &str) -> Option<&EffectivePolicy> {
+        self.effective.get(host_did)
+    }
+
+    /// Replace a zone with a stricter version; returns the hosts whose effective policy changed
+    pub fn tighten_zone(&mut self, policy: ZonePolicy) -> Result<Vec<String>, HarmonizeError> {
+        let current = self
+            .zones
+            .get(&policy.zone_id)
+            .ok_or_else(|| HarmonizeError::UnknownZone(policy.zone_id.clone()))?;
+        check_no_relaxation(current, &policy)?;
+
+        let zone_id = policy.zone_id.clone();
+        let previous = self.zones.insert(zone_id.clone(), policy);
+        match self.reharmonize(&zone_id) {
+            Ok(changed) => Ok(changed),
+            Err(e) => {
+                // Keep zones and effective policies consistent if the new faces do not fit
+                if let Some(previous) = previous {
+                    self.zones.insert(zone_id, previous);
+                }
+                Err(e)
+            },
+        }
+    }
+
+    /// Lower a zone's RoH ceiling from a `roh_policy!` tightening, for its
+    /// `affected_upgrade_classes` only. A tightening that covers every class the
+    /// zone allows lowers the zone-wide ceiling; one that is already met is a no-op.
+    pub fn apply_tightening(&mut self, zone_id: &str, tightening: &PolicyTightening) -> Result<Vec<String>, HarmonizeError> {
+        let mut policy = self
+            .zones
+            .get(zone_id)
+            .cloned()
+            .ok_or_else(|| HarmonizeError::UnknownZone(zone_id.to_string()))?;
+        let new_ceiling = tightening.new_roh_ceiling;
+        let affected = &tightening.affected_upgrade_classes;
+
+        if policy.allowed_upgrade_classes.iter().all(|c| affected.contains(c)) {
+            policy.roh_ceiling = policy.roh_ceiling.min(new_ceiling);
+        } else {
+            for class in affected {
+                if new_ceiling < policy.ceiling_for(class) {
+                    policy.class_ceilings.insert(class.clone(), new_ceiling);
+                }
+            }
+        }
+        self.tighten_zone(policy)
+    }
+
+    fn reharmonize(&mut self, zone_id: &str) -> Result<Vec<String>, HarmonizeError> {
+        let mut updated = Vec::new();
+        for (host, zones) in self.hosts.iter().filter(|(_, zones)| zones.contains(zone_id)) {
+            let policy = self.harmonize(zones.iter().map(String::as_str))?;
+            updated.push((host.clone(), policy));
+        }
+
+        let mut changed = Vec::new();
+        for (host, policy) in updated {
+            if self.effective.get(&host) != Some(&policy) {
+                changed.push(host.clone());
+            }
+            self.effective.insert(host, policy);
+        }
+        Ok(changed)
+    }
+}
+
+fn check_no_relaxation(current: &ZonePolicy, proposed: &ZonePolicy) -> Result<(), HarmonizeError> {
+    let relaxation = |detail: String| Err(HarmonizeError::Relaxation { zone_id: current.zone_id.clone(), detail });
+    if proposed.roh_ceiling > current.roh_ceiling {
+        return relaxation(format!("roh_ceiling {} -> {}", current.roh_ceiling, proposed.roh_ceiling));
+    }
+    if let Some(class) = current
+        .class_ceilings
+        .keys()
+        .find(|c| proposed.ceiling_for(c) > current.ceiling_for(c))
+    {
+        return relaxation(format!(
+            "roh_ceiling for class {} {} -> {}",
+            class,
+            current.ceiling_for(class),
+            proposed.ceiling_for(class)
+        ));
+    }
+    if let Some(class) = proposed
+        .allowed_upgrade_classes
+        .iter()
+        .find(|c| !current.allowed_upgrade_classes.contains(c))
+    {
+        return relaxation(format!("adds upgrade class {}", class));
+    }
+    if let Some(face) = current.corridor_faces.iter().find(|f| !proposed.corridor_faces.contains(f)) {
+        return relaxation(format!("drops corridor face {:?}·x <= {}", face.a, face.b));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zone(id: &str, ceiling: f32, classes: &[&str], faces: Vec<PolytopeFace>) -> ZonePolicy {
+        ZonePolicy {
+            zone_id: id.to_string(),
+            roh_ceiling: ceiling,
+            allowed_upgrade_classes: classes.iter().map(|c| c.to_string()).collect(),
+            jurisdiction: "US-AZ".to_string(),
+            neuroights_requirements: "neurorights.envelope.citizen.v1".to_string(),
+            corridor_faces: faces,
+            class_ceilings: BTreeMap::new(),
+        }
+    }
+
+    fn harmonizer() -> ZoneHarmonizer {
+        let mut shard = DecisionGrammarPolicyShard::new();
+        shard.add_zone_policy(zone(
+            "phoenix-west",
+            0.25,
+            &["BCI", "XR"],
+            vec![PolytopeFace { a: vec![1.0, 0.0], b: 15.0 }],
+        ));
+        shard.add_zone_policy(zone(
+            "san-jolla",
+            0.28,
+            &["XR", "EXO"],
+            vec![PolytopeFace { a: vec![0.0, 1.0], b: 0.4 }],
+        ));
+        ZoneHarmonizer::from_shard(&shard)
+    }
+
+    #[test]
+    fn test_strictest_envelope_wins_with_explanation() {
+        let h = harmonizer();
+        let policy = h.harmonize(["phoenix-west", "san-jolla"]).unwrap();
+        assert!((policy.roh_ceiling - 0.25).abs() < 1e-6);
+        assert_eq!(policy.ceiling_source(), Some("phoenix-west"));
+        assert_eq!(policy.allowed_upgrade_classes, vec!["XR".to_string()]);
+        assert_eq!(policy.corridor_faces.len(), 2);
+        assert!(policy.explain().contains(&"class BCI excluded by san-jolla".to_string()));
+        assert!(policy.admits("XR", 0.2) && !policy.admits("BCI", 0.1));
+
+        assert!(matches!(h.harmonize(["mars"]), Err(HarmonizeError::UnknownZone(_))));
+    }
+
+    #[test]
+    fn test_zone_tightening_reharmonizes_hosts() {
+        let mut h = harmonizer();
+        h.set_active_zones("did:host:a", &["phoenix-west", "san-jolla"]).unwrap();
+        h.set_active_zones("did:host:b", &["phoenix-west"]).unwrap();
+
+        let tightening = PolicyTightening {
+            old_roh_ceiling: 0.28,
+            new_roh_ceiling: 0.22,
+            affected_upgrade_classes: vec!["XR".to_string(), "EXO".to_string()],
+            reason: "3 incidents within 24h".to_string(),
+        };
+        let changed = h.apply_tightening("san-jolla", &tightening).unwrap();
+        assert_eq!(changed, vec!["did:host:a".to_string()]);
+        assert_eq!(h.effective("did:host:a").unwrap().ceiling_source(), Some("san-jolla"));
+        assert!((h.effective("did:host:b").unwrap().roh_ceiling - 0.25).abs() < 1e-6);
+
+        let relaxed = zone("san-jolla", 0.30, &["XR", "EXO"], vec![PolytopeFace { a: vec![0.0, 1.0], b: 0.4 }]);
+        assert!(matches!(h.tighten_zone(relaxed), Err(HarmonizeError::Relaxation { .. })));
+    }
+
+    #[test]
+    fn test_tightening_applies_only_to_affected_classes() {
+        let mut h = harmonizer();
+        h.set_active_zones("did:host:a", &["phoenix-west"]).unwrap();
+        h.set_active_zones("did:host:b", &["san-jolla"]).unwrap();
+
+        let tightening = PolicyTightening {
+            old_roh_ceiling: 0.25,
+            new_roh_ceiling: 0.20,
+            affected_upgrade_classes: vec!["BCI".to_string()],
+            reason: "3 incidents within 24h".to_string(),
+        };
+        let changed = h.apply_tightening("phoenix-west", &tightening).unwrap();
+        assert_eq!(changed, vec!["did:host:a".to_string()]);
+
+        let policy = h.effective("did:host:a").unwrap();
+        assert!((policy.roh_ceiling - 0.25).abs() < 1e-6);
+        assert!((policy.ceiling_for("BCI") - 0.20).abs() < 1e-6);
+        assert!(policy.admits("XR", 0.22) && !policy.admits("BCI", 0.22));
+        assert!(policy.explain().contains(&"roh_ceiling 0.20 for class BCI from phoenix-west".to_string()));
+
+        // A class the other zone excludes never reaches the harmonized policy
+        let both = h.harmonize(["phoenix-west", "san-jolla"]).unwrap();
+        assert!(both.class_ceilings.is_empty());
+
+        // Re-applying is a no-op, and the class ceiling cannot be raised again
+        assert!(h.apply_tightening("phoenix-west", &tightening).unwrap().is_empty());
+        let reset = zone("phoenix-west", 0.25, &["BCI", "XR"], vec![PolytopeFace { a: vec![1.0, 0.0], b: 15.0 }]);
+        assert!(matches!(h.tighten_zone(reset), Err(HarmonizeError::Relaxation { .. })));
+    }
+}
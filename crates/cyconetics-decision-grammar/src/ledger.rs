@@ -2,9 +2,11 @@
 //! All decisions are cryptographically stamped and immutably logged.
 
 use serde::{Serialize, Deserialize};
-use sha2::{Sha256, Digest};
 use chrono::Utc;
 
+use crate::canonical::{canonical_hash, canonical_hash_excluding};
+use crate::types::{DecisionKind, DecisionLedgerKey, KsrBand};
+
 /// Evidence bundle: 10-element collection of biomarkers for one decision
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EvidenceBiomarkers {
@@ -59,12 +61,9 @@ impl EvidenceBundle {
         }
     }
 
-    /// Compute SHA256 hash of this bundle for blockchain stamping
+    /// Compute SHA256 hash of this bundle (canonical JSON) for blockchain stamping
     pub fn hash(&self) -> String {
-        let json = serde_json::to_string(self).unwrap_or_default();
-        let mut hasher = Sha256::new();
-        hasher.update(json.as_bytes());
-        hex::encode(hasher.finalize())
+        canonical_hash(self)
     }
 
     /// Check if any biomarker is critically high
@@ -93,7 +92,7 @@ pub struct BloodSpendProof {
 pub struct DecisionLedgerEntry {
     pub key: DecisionLedgerKey,
     pub final_decision: DecisionKind,
-    pub roh_band: RoHBand,
+    pub roh_band: KsrBand,
     pub evidence_bundle: EvidenceBundle,
     pub blood_coupling: Option<BloodSpendProof>,
     pub incident_flags: bool,           // true if organism distress detected (for BFC)
@@ -105,7 +104,7 @@ impl DecisionLedgerEntry {
     pub fn new(
         key: DecisionLedgerKey,
         decision: DecisionKind,
-        roh: RoHBand,
+        roh: KsrBand,
         evidence: EvidenceBundle,
     ) -> Self {
         let entry = DecisionLedgerEntry {
@@ -118,18 +117,25 @@ impl DecisionLedgerEntry {
             created_at: Utc::now().timestamp(),
             ledger_entry_hash: String::new(),
         };
-        // Compute and set hash
         let mut entry = entry;
-        entry.ledger_entry_hash = entry.compute_hash();
+        entry.seal();
         entry
     }
 
-    /// Compute SHA256 hash of this entire entry
+    /// Compute SHA256 hash over every field except `ledger_entry_hash` (canonical JSON)
     pub fn compute_hash(&self) -> String {
-        let json = serde_json::to_string(self).unwrap_or_default();
-        let mut hasher = Sha256::new();
-        hasher.update(json.as_bytes());
-        hex::encode(hasher.finalize())
+        canonical_hash_excluding(self, "ledger_entry_hash")
+    }
+
+    /// Recompute and store `ledger_entry_hash`; call after any field changes before commit
+    pub fn seal(&mut self) -> &str {
+        self.ledger_entry_hash = self.compute_hash();
+        &self.ledger_entry_hash
+    }
+
+    /// True if the stored hash matches the entry content
+    pub fn verify(&self) -> bool {
+        !self.ledger_entry_hash.is_empty() && self.ledger_entry_hash == self.compute_hash()
     }
 
     /// Serialize for ALN shard commit
@@ -156,8 +162,29 @@ pub struct ALNDecisionLedgerV1 {
     pub timestamp: i64,
 }
 
-// Re-export types needed by this module
-use crate::types::{DecisionKind, DecisionLedgerKey, RoHBand};
+/// Stored vs recomputed hash for an entry that failed verification
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerHashMismatch {
+    pub index: usize,
+    pub key: String,        // host_did:upgrade_id:evolution_id
+    pub stored: String,
+    pub computed: String,
+}
+
+/// Verify every entry of a ledger, returning all mismatches (empty if intact)
+pub fn verify_all(entries: &[DecisionLedgerEntry]) -> Vec<LedgerHashMismatch> {
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| !entry.verify())
+        .map(|(index, entry)| LedgerHashMismatch {
+            index,
+            key: entry.key.to_string(),
+            stored: entry.ledger_entry_hash.clone(),
+            computed: entry.compute_hash(),
+        })
+        .collect()
+}
 
 #[cfg(test)]
 mod tests {
@@ -178,6 +205,7 @@ mod tests {
 
         let mut bundle2 = EvidenceBundle::new("test-zone".to_string());
         bundle2.biomarkers.il6_level = 10.0; // Normal
+        bundle2.biomarkers.glucose_blood = 100.0; // Unset glucose (0) reads as hypoglycemic
         assert!(!bundle2.has_critical_biomarker());
     }
 
@@ -192,9 +220,36 @@ mod tests {
         let entry = DecisionLedgerEntry::new(
             key,
             DecisionKind::Approve,
-            RoHBand::default(),
+            KsrBand::default(),
             bundle,
         );
         assert!(!entry.ledger_entry_hash.is_empty());
+        assert!(entry.verify());
+    }
+
+    #[test]
+    fn test_verify_all_reports_tampered_entries() {
+        let entries: Vec<DecisionLedgerEntry> = (0..3)
+            .map(|i| {
+                let key = DecisionLedgerKey {
+                    host_did: "test-host".to_string(),
+                    upgrade_id: format!("upgrade-{:03}", i),
+                    evolution_id: "evo-001".to_string(),
+                };
+                let bundle = EvidenceBundle::new("test-zone".to_string());
+                DecisionLedgerEntry::new(key, DecisionKind::Approve, KsrBand::default(), bundle)
+            })
+            .collect();
+        assert!(verify_all(&entries).is_empty());
+
+        let mut tampered = entries.clone();
+        tampered[1].final_decision = DecisionKind::Authorize;
+        let mismatches = verify_all(&tampered);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].index, 1);
+        assert_eq!(mismatches[0].key, "test-host:upgrade-001:evo-001");
+
+        tampered[1].seal();
+        assert!(verify_all(&tampered).is_empty());
     }
 }
@@ -0,0 +1,150 @@
+//! DecisionLedgerEntry schema generated at build time from `aln/decision.ledger.entry.v1.aln`.
+//! The struct, its key/field lists and its validators come from the shard; only the
+//! violation type and the bridge from the hand-written `DecisionLedgerEntry` live here.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::ledger::DecisionLedgerEntry;
+
+include!(concat!(env!("OUT_DIR"), "/aln_generated.rs"));
+
+/// A shard constraint the entry does not satisfy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LedgerConstraintViolation {
+    EmptyKey { field: &'static str },
+    RohOutOfRange { field: &'static str, value: f64, min: f64, max: f64 },
+    OutOfOrder { previous_ms: i64, created_at_ms: i64 },
+    DuplicateRecord { hash: String },
+}
+
+impl fmt::Display for LedgerConstraintViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LedgerConstraintViolation::EmptyKey { field } => write!(f, "ledger key `{}` is empty", field),
+            LedgerConstraintViolation::RohOutOfRange { field, value, min, max } => {
+                write!(f, "{} = {} outside [{}, {}]", field, value, min, max)
+            }
+            LedgerConstraintViolation::OutOfOrder { previous_ms, created_at_ms } => write!(
+                f,
+                "append-only ledger: entry at {} ms precedes last entry at {} ms",
+                created_at_ms, previous_ms
+            ),
+            LedgerConstraintViolation::DuplicateRecord { hash } => {
+                write!(f, "append-only ledger: decision record {} already committed", hash)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LedgerConstraintViolation {}
+
+/// Who decided, under which scheduler and RoH reading; the parts of a shard entry
+/// that the in-memory `DecisionLedgerEntry` does not carry itself
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LedgerAuthorship {
+    pub decided_by_did: String,
+    pub decided_by_role: String,
+    pub scheduler_id: String,
+    pub roh_at_decision: f64,
+    pub roh_delta: f64,
+    pub biokarma_snapshot_id: String,
+    pub biomarker_envelope_id: String,
+}
+
+impl DecisionLedgerEntry {
+    /// Shard form of a sealed entry; fails if the result breaks a shard constraint
+    pub fn to_schema_entry(&self, authorship: &LedgerAuthorship) -> Result<DecisionLedgerEntryV1, LedgerConstraintViolation> {
+        let entry = DecisionLedgerEntryV1 {
+            host_did: self.key.host_did.clone(),
+            upgrade_id: self.key.upgrade_id.clone(),
+            evolution_id: self.key.evolution_id.clone(),
+            decision_kind: self.final_decision.clone(),
+            decision_record_hash: self.ledger_entry_hash.clone(),
+            decided_by_did: authorship.decided_by_did.clone(),
+            decided_by_role: authorship.decided_by_role.clone(),
+            zone_id: self.evidence_bundle.zone_id.clone(),
+            scheduler_id: authorship.scheduler_id.clone(),
+            roh_at_decision: authorship.roh_at_decision,
+            roh_delta: authorship.roh_delta,
+            biokarma_snapshot_id: authorship.biokarma_snapshot_id.clone(),
+            biomarker_envelope_id: authorship.biomarker_envelope_id.clone(),
+            created_at_ms: self.created_at.saturating_mul(1000),
+            hexstamp: DECISION_LEDGER_ENTRY_HEXSTAMP.to_string(),
+        };
+        entry.validate()?;
+        Ok(entry)
+    }
+}
+
+/// Shards this crate was compiled against
+pub const COMPILED_SHARDS: &[aln::CompiledShard] = &[DECISION_LEDGER_ENTRY_SHARD];
+
+/// Refuse to run against deployed shards that differ from the compiled ones
+pub fn check_compiled_shards(registry: &aln::AlnRegistry) -> Result<(), aln::RegistryError> {
+    registry.check_compiled(COMPILED_SHARDS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::EvidenceBundle;
+    use crate::types::{DecisionKind, DecisionLedgerKey, KsrBand};
+
+    fn sealed(evolution_id: &str) -> DecisionLedgerEntry {
+        let key = DecisionLedgerKey {
+            host_did: "did:bostrom:host".to_string(),
+            upgrade_id: "upgrade-001".to_string(),
+            evolution_id: evolution_id.to_string(),
+        };
+        DecisionLedgerEntry::new(key, DecisionKind::Approve, KsrBand::default(), EvidenceBundle::new("phoenix-west".to_string()))
+    }
+
+    fn authorship(roh: f64) -> LedgerAuthorship {
+        LedgerAuthorship {
+            decided_by_did: "did:bostrom:host".to_string(),
+            decided_by_role: "HostSelf".to_string(),
+            roh_at_decision: roh,
+            ..LedgerAuthorship::default()
+        }
+    }
+
+    #[test]
+    fn test_generated_schema_matches_shard() {
+        assert_eq!(DECISION_LEDGER_ENTRY_ID, "decision.ledger.entry.v1");
+        assert_eq!(DecisionLedgerEntryV1::KEYS, ["host_did", "upgrade_id", "evolution_id"]);
+        assert!(DecisionLedgerEntryV1::FIELDS.contains(&("created_at_ms", "integer")));
+        assert_eq!((DECISION_LEDGER_MIN_ROH, DECISION_LEDGER_MAX_ROH, DECISION_LEDGER_APPEND_ONLY), (0.0, 1.0, true));
+
+        let entry = sealed("evo-001").to_schema_entry(&authorship(0.12)).unwrap();
+        assert_eq!(entry.key(), sealed("evo-001").key);
+        assert_eq!(entry.hexstamp, DECISION_LEDGER_ENTRY_HEXSTAMP);
+    }
+
+    #[test]
+    fn test_constraints_become_validators() {
+        let err = sealed("evo-001").to_schema_entry(&authorship(1.5)).unwrap_err();
+        assert!(matches!(err, LedgerConstraintViolation::RohOutOfRange { field: "roh_at_decision", .. }));
+        assert!(sealed("evo-001").to_schema_entry(&authorship(f64::NAN)).is_err());
+
+        let mut first = sealed("evo-001").to_schema_entry(&authorship(0.1)).unwrap();
+        let ledger = vec![first.clone()];
+        assert!(matches!(first.validate_append(&ledger), Err(LedgerConstraintViolation::DuplicateRecord { .. })));
+
+        first.decision_record_hash = "other".to_string();
+        first.created_at_ms -= 1;
+        assert!(matches!(first.validate_append(&ledger), Err(LedgerConstraintViolation::OutOfOrder { .. })));
+
+        first.host_did.clear();
+        assert_eq!(first.validate(), Err(LedgerConstraintViolation::EmptyKey { field: "host_did" }));
+    }
+
+    #[test]
+    fn test_binary_matches_deployed_shards() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../aln");
+        let mut registry = aln::AlnRegistry::new();
+        registry.load_dir(&dir).unwrap();
+        assert_eq!(check_compiled_shards(&registry), Ok(()));
+        assert!(check_compiled_shards(&aln::AlnRegistry::new()).is_err());
+    }
+}
@@ -13,96 +13,60 @@
 //! 4. **Incident-driven tightening**: SafetyEpoch logs trigger automatic policy updates when near-misses occur.
 //! 5. **Blockchain-authored**: All decisions are stamped into cyberswarm.decision.ledger.v1 for audit.
 
+extern crate self as cyconetics_decision_grammar;
+
 pub mod types;
 pub mod roles;
 pub mod roh_guard;
 pub mod ledger;
+pub mod ledger_schema;
 pub mod validators;
 pub mod aln_shards;
-pub mod ci_hooks;
+pub mod registry;
+pub mod query;
+pub mod merkle;
+pub mod canonical;
+pub mod evolution;
+pub mod roh_policy;
+pub mod errority;
+pub mod harmonizer;
 
 // Re-export macros
 pub use cyconetics_decision_grammar_macros::*;
 
 // Re-export key types
 pub use types::{
-    DecisionKind, DecisionRecord, RoHBound, RoHGuardedHostState, KsrBand, NeurorightsTag,
-    NeuroEntityType, NeuroConsentRecord, BFCBroadcastProposal,
+    DecisionKind, DecisionRecord, RoHBound, RoHZone, RoHGuardedHostState, KsrBand, NeurorightsTag,
+    NeuroEntityType, NeuroConsentRecord, BFCBroadcastProposal, BrainSpecs, DecisionLedgerKey,
 };
 pub use roles::{
     DecisionContext, NeurorightsDecider, SafetyDecider, HostSelfDecider, GovSafetyDecider,
+    RoleMarker, Decides, HostVeto, RolePermission, RolePermissionTable, RoleTableError,
 };
-pub use roh_guard::{BrainSpecs, predict_roh, roh_from_biokarma};
+pub use roh_guard::{predict_roh, roh_from_biokarma};
 pub use ledger::{
-    DecisionLedgerEntry, DecisionLedgerKey, EvidenceBundle, EvidenceBiomarkers,
-    BloodSpendProof,
+    DecisionLedgerEntry, EvidenceBundle, EvidenceBiomarkers,
+    BloodSpendProof, LedgerHashMismatch,
+};
+pub use ledger_schema::{
+    check_compiled_shards, DecisionLedgerEntryV1, LedgerAuthorship, LedgerConstraintViolation, COMPILED_SHARDS,
 };
 pub use validators::{
     validate_roh_complies_with_zone, validate_decision_record, NeuroConsentViolation,
 };
-pub use aln_shards::{DecisionLedgerShard, NeurorightsConsentShard, neurorights_broadcast_ledger};
+pub use aln_shards::{DecisionLedgerShard, NeurorightsConsentShard, ShardRootMismatch, neurorights_broadcast_ledger};
+pub use registry::{DecisionRegistry, FileDecisionRegistry, ChainError, RegistryError};
+pub use query::{DecisionQuery, DecisionPage};
+pub use merkle::{InclusionProof, MerkleAccumulator};
+pub use canonical::{to_canonical_json, canonical_hash};
+pub use evolution::{EvolutionEdge, EvolutionMachine, TransitionError};
+pub use roh_policy::{RohPolicyError, RohPolicySpec, RohRatchet, ROH_CEILING_FLOOR, ROH_CEILING_MAX};
+pub use errority::{
+    ErrorityBaseline, ErrorityChain, ErrorityError, ErrorityOp, ErrorityRatchet, ErrorityState, ForbiddenAction, PolytopeFace,
+};
+pub use harmonizer::{Contribution, EffectivePolicy, HarmonizeError, HarmonizedConstraint, ZoneHarmonizer};
 
-use std::collections::HashMap;
-use sha2::{Sha256, Digest};
 use serde::{Serialize, Deserialize};
-use chrono::Utc;
-
-/// Global decision registry for audit and traceability
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DecisionRegistry {
-    /// Immutable append-only log of all decisions
-    pub decisions: Vec<DecisionRecord>,
-    /// Index for fast lookup by host_did, upgrade_id
-    pub index: HashMap<String, Vec<usize>>,
-    /// Last known SafetyEpoch that tightened policy
-    pub last_policy_tightening: Option<String>,
-}
-
-impl DecisionRegistry {
-    pub fn new() -> Self {
-        Self {
-            decisions: Vec::new(),
-            index: HashMap::new(),
-            last_policy_tightening: None,
-        }
-    }
-
-    /// Append a decision record (append-only, never overwrite)
-    pub fn append(&mut self, record: DecisionRecord) -> String {
-        let idx = self.decisions.len();
-        let key = format!("{}:{}:{}", record.host_did, record.upgrade_id, record.evolution_id);
-        self.index.entry(key).or_insert_with(Vec::new).push(idx);
-        self.decisions.push(record);
-        
-        // Return hash of this entry for blockchain stamping
-        let mut hasher = Sha256::new();
-        hasher.update(serde_json::to_string(&self.decisions[idx]).unwrap().as_bytes());
-        hex::encode(hasher.finalize())
-    }
-
-    /// Fetch all decisions for a given host_did and upgrade_id
-    pub fn lookup(&self, host_did: &str, upgrade_id: &str) -> Vec<&DecisionRecord> {
-        let key = format!("{}:{}", host_did, upgrade_id);
-        self.index
-            .get(&key)
-            .map(|indices| {
-                indices
-                    .iter()
-                    .filter_map(|&idx| self.decisions.get(idx))
-                    .collect()
-            })
-            .unwrap_or_default()
-    }
-
-    /// Serialize for blockchain commit
-    pub fn to_jsonl(&self) -> String {
-        self.decisions
-            .iter()
-            .filter_map(|d| serde_json::to_string(d).ok())
-            .collect::<Vec<_>>()
-            .join("\n")
-    }
-}
 
 /// Safety-Epoch incident tracker for policy tightening
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -125,6 +89,15 @@ pub enum IncidentType {
     BloodTokenOverdraw,   // CSP spending exceeded
 }
 
+impl IncidentType {
+    /// Incidents that reflect host RoH, and so count toward a RoH ceiling ratchet by default
+    pub const ROH: [IncidentType; 3] = [
+        IncidentType::RoHTrendingHigh,
+        IncidentType::HemodynamicDeviation,
+        IncidentType::MetabolicStrain,
+    ];
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyTightening {
     pub old_roh_ceiling: f32,
@@ -160,36 +133,9 @@ pub fn compute_incident_roh(
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_decision_registry_append() {
-        let mut registry = DecisionRegistry::new();
-        let record = DecisionRecord {
-            host_did: "bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7".to_string(),
-            upgrade_id: "upgrade-001".to_string(),
-            evolution_id: "evo-001".to_string(),
-            decision: DecisionKind::Authorize,
-            pre_roh: 0.15,
-            post_roh: 0.22,
-            brain_specs: BrainSpecs::default(),
-            host_budget: Default::default(),
-            bci_snapshot: Default::default(),
-            ksr_band: KsrBand::default(),
-            ledger_key: DecisionLedgerKey {
-                host_did: "bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7".to_string(),
-                upgrade_id: "upgrade-001".to_string(),
-                evolution_id: "evo-001".to_string(),
-            },
-            timestamp: Utc::now().timestamp(),
-        };
-
-        let hash = registry.append(record);
-        assert!(!hash.is_empty());
-        assert_eq!(registry.decisions.len(), 1);
-    }
-
     #[test]
     fn test_roh_computation() {
         let roh = compute_incident_roh(0.15, 0.12, 0.18, 0.20);
-        assert!(roh >= 0.0 && roh <= 0.5);
+        assert!((0.0..=0.5).contains(&roh));
     }
 }
@@ -2,92 +2,6 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, Ident, ItemStruct};
 
-/// decision_roles! macro: generates role types and ensures HostSelf veto cannot
-/// be removed without code change and new governance shard.
-#[proc_macro]
-pub fn decision_roles(input: TokenStream) -> TokenStream {
-    // Expected syntax: decision_roles![Neurorights, Safety, HostSelf];
-    let roles = parse_macro_input!(input as syn::ExprArray);
-
-    let mut impls = Vec::new();
-
-    for expr in roles.elems.iter() {
-        if let syn::Expr::Path(path) = expr {
-            let ident = path.path.segments.last().unwrap().ident.clone();
-            let trait_ident = Ident::new(&format!("{}Decider", ident), ident.span());
-
-            // HostSelf gets a hard-coded veto path
-            if ident == "HostSelf" {
-                impls.push(quote! {
-                    pub struct HostSelfRole;
-
-                    impl crate::roles::HostSelfDecider for HostSelfRole {
-                        fn decide_host_self(
-                            &self,
-                            host: &crate::roles::HostIdentity,
-                            ctx: &crate::roles::UpgradeContext,
-                            roh: f32,
-                            roh_token: Option<crate::types::RoHBound<30>>,
-                        ) -> crate::types::DecisionRecord {
-                            crate::types::DecisionRecord {
-                                host_did: host.host_did.clone(),
-                                upgrade_id: ctx.upgrade_id.clone(),
-                                evolution_id: ctx.evolution_id.clone(),
-                                kind: crate::types::DecisionKind::Rejected,
-                                decided_by_role: "HostSelf".into(),
-                                decided_by_did: host.host_did.clone(),
-                                predicted_roh: roh,
-                                roh_token,
-                                timestamp_ms: chrono::Utc::now().timestamp_millis(),
-                                evidence_hash: "0xHOST_VETO".into(),
-                            }
-                        }
-                    }
-                });
-            } else {
-                let role_name = Ident::new(&format!("{}Role", ident), ident.span());
-                impls.push(quote! {
-                    pub struct #role_name;
-
-                    impl crate::roles::#trait_ident for #role_name {
-                        fn decide_#ident:lower(
-                            &self,
-                            host: &crate::roles::HostIdentity,
-                            ctx: &crate::roles::UpgradeContext,
-                            roh: f32,
-                            roh_token: Option<crate::types::RoHBound<30>>,
-                        ) -> crate::types::DecisionRecord {
-                            let kind = if roh < 0.30 {
-                                crate::types::DecisionKind::Approved
-                            } else {
-                                crate::types::DecisionKind::Escalated
-                            };
-
-                            crate::types::DecisionRecord {
-                                host_did: host.host_did.clone(),
-                                upgrade_id: ctx.upgrade_id.clone(),
-                                evolution_id: ctx.evolution_id.clone(),
-                                kind,
-                                decided_by_role: stringify!(#trait_ident).into(),
-                                decided_by_did: host.host_did.clone(),
-                                predicted_roh: roh,
-                                roh_token,
-                                timestamp_ms: chrono::Utc::now().timestamp_millis(),
-                                evidence_hash: "0xROLE_DECISION".into(),
-                            }
-                        }
-                    }
-                });
-            }
-        }
-    }
-
-    let tokens = quote! {
-        #(#impls)*
-    };
-    tokens.into()
-}
-
 /// scheduler_policy! macro: generates per-host/zone policy state machines that
 /// only allow Authorize/Approve when predicted_roh < 0.30 at compile-time proof
 /// surfaces (via type presence) and runtime check.
@@ -117,22 +31,6 @@ pub fn scheduler_policy(attr: TokenStream, item: TokenStream) -> TokenStream {
     };
     expanded.into()
 }
-/// evolutiongraph! macro: defines a static evolution graph and fails build if
-/// any path accumulates RoH > 0.30 without passing through Reject or Escalate.
-/// For simplicity we use const assertions; in production you can expand this.
-#[proc_macro]
-pub fn evolutiongraph(input: TokenStream) -> TokenStream {
-    // Expect: evolutiongraph! { path "A" => [(Proposed, 0.02), (Approved, 0.05), ...]; ... }
-    let _ = input;
-    let tokens = quote! {
-        // Placeholder compile-time guard: you can extend this to parse the full graph
-        const _: () = {
-            // Example hex proof that RoH path constraints were validated.
-            const _EVOLUTIONGRAPH_HEXSTAMP: &str = "0x4c21d7f8a30b9e5d77c2ab18f9012e6b";
-        };
-    };
-    tokens.into()
-}
 //! Procedural macros for Cyconetics decision grammar (stubs for outline).
 //! These macros expand into type-checked decision validators at compile time.
 //! 
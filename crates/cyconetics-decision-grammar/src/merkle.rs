@@ -0,0 +1,204 @@
+//! Incremental Merkle accumulator (RFC 6962 tree shape) for append-only shards.
+//! Appends are amortised O(1); roots and inclusion proofs are O(log n).
+
+use serde::{Serialize, Deserialize};
+use sha2::{Sha256, Digest};
+
+pub type MerkleHash = [u8; 32];
+
+/// Domain-separated leaf hash: SHA256(0x00 || data)
+pub fn leaf_hash(data: &[u8]) -> MerkleHash {
+    let mut hasher = Sha256::new();
+    hasher.update([0x00]);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Domain-separated interior node hash: SHA256(0x01 || left || right)
+pub fn node_hash(left: &MerkleHash, right: &MerkleHash) -> MerkleHash {
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Proof that one leaf is included in a tree of `tree_size` leaves
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub leaf_index: u64,
+    pub tree_size: u64,
+    pub path: Vec<String>,  // hex sibling hashes, leaf to root
+}
+
+/// Append-only Merkle accumulator.
+///
+/// `levels[0]` holds the leaf hashes and `levels[k]` the roots of every complete
+/// subtree of `2^k` leaves, so nothing is ever re-hashed after it is sealed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MerkleAccumulator {
+    levels: Vec<Vec<MerkleHash>>,
+}
+
+impl MerkleAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.levels.first().map(|l| l.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append a leaf and return its index
+    pub fn push(&mut self, data: &[u8]) -> usize {
+        let index = self.len();
+        let mut node = leaf_hash(data);
+        let mut level = 0;
+        loop {
+            if self.levels.len() == level {
+                self.levels.push(Vec::new());
+            }
+            self.levels[level].push(node);
+            let nodes = &self.levels[level];
+            if nodes.len() % 2 == 1 {
+                break;
+            }
+            node = node_hash(&nodes[nodes.len() - 2], &nodes[nodes.len() - 1]);
+            level += 1;
+        }
+        index
+    }
+
+    /// Current root; the empty tree hashes to SHA256("")
+    pub fn root(&self) -> MerkleHash {
+        if self.is_empty() {
+            return Sha256::digest(b"").into();
+        }
+        self.subtree_root(0, self.len())
+    }
+
+    pub fn root_hex(&self) -> String {
+        hex::encode(self.root())
+    }
+
+    /// Audit path for `index`, or `None` if out of range
+    pub fn inclusion_proof(&self, index: usize) -> Option<InclusionProof> {
+        let size = self.len();
+        if index >= size {
+            return None;
+        }
+        let mut path = Vec::new();
+        self.collect_path(index, 0, size, &mut path);
+        Some(InclusionProof {
+            leaf_index: index as u64,
+            tree_size: size as u64,
+            path: path.iter().map(hex::encode).collect(),
+        })
+    }
+
+    /// Check `proof` for leaf `data` against a hex `root` (RFC 9162 §2.1.3.2)
+    pub fn verify_inclusion(data: &[u8], proof: &InclusionProof, root: &str) -> bool {
+        if proof.leaf_index >= proof.tree_size {
+            return false;
+        }
+        let mut fnode = proof.leaf_index;
+        let mut snode = proof.tree_size - 1;
+        let mut hash = leaf_hash(data);
+
+        for sibling in &proof.path {
+            let sibling: MerkleHash = match hex::decode(sibling).ok().and_then(|b| b.try_into().ok()) {
+                Some(h) => h,
+                None => return false,
+            };
+            if snode == 0 {
+                return false;
+            }
+            if fnode & 1 == 1 || fnode == snode {
+                hash = node_hash(&sibling, &hash);
+                while fnode & 1 == 0 && fnode != 0 {
+                    fnode >>= 1;
+                    snode >>= 1;
+                }
+            } else {
+                hash = node_hash(&hash, &sibling);
+            }
+            fnode >>= 1;
+            snode >>= 1;
+        }
+
+        snode == 0 && hex::encode(hash) == root
+    }
+
+    /// Root of leaves `[start, start + size)`; served from the sealed levels
+    /// whenever the range is a complete, aligned subtree.
+    fn subtree_root(&self, start: usize, size: usize) -> MerkleHash {
+        if size.is_power_of_two() && start.is_multiple_of(size) {
+            let level = size.trailing_zeros() as usize;
+            return self.levels[level][start / size];
+        }
+        let split = split_point(size);
+        node_hash(
+            &self.subtree_root(start, split),
+            &self.subtree_root(start + split, size - split),
+        )
+    }
+
+    fn collect_path(&self, index: usize, start: usize, size: usize, path: &mut Vec<MerkleHash>) {
+        if size == 1 {
+            return;
+        }
+        let split = split_point(size);
+        if index < split {
+            self.collect_path(index, start, split, path);
+            path.push(self.subtree_root(start + split, size - split));
+        } else {
+            self.collect_path(index - split, start + split, size - split, path);
+            path.push(self.subtree_root(start, split));
+        }
+    }
+}
+
+/// Largest power of two strictly smaller than `n` (n > 1)
+fn split_point(n: usize) -> usize {
+    let mut k = 1;
+    while k << 1 < n {
+        k <<= 1;
+    }
+    k
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_leaf_proves_inclusion() {
+        for size in 1..=17usize {
+            let mut acc = MerkleAccumulator::new();
+            for i in 0..size {
+                acc.push(format!("leaf-{}", i).as_bytes());
+            }
+            let root = acc.root_hex();
+            for i in 0..size {
+                let proof = acc.inclusion_proof(i).unwrap();
+                let leaf = format!("leaf-{}", i);
+                assert!(MerkleAccumulator::verify_inclusion(leaf.as_bytes(), &proof, &root));
+                assert!(!MerkleAccumulator::verify_inclusion(b"forged", &proof, &root));
+            }
+        }
+    }
+
+    #[test]
+    fn test_root_matches_rfc6962_shape() {
+        let mut acc = MerkleAccumulator::new();
+        for data in [b"a", b"b", b"c"] {
+            acc.push(data);
+        }
+        let (a, b, c) = (leaf_hash(b"a"), leaf_hash(b"b"), leaf_hash(b"c"));
+        assert_eq!(acc.root(), node_hash(&node_hash(&a, &b), &c));
+    }
+}
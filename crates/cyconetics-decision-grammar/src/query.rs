@@ -0,0 +1,343 @@
+//! Secondary indexes and query/pagination API over `DecisionRegistry`.
+//! Results are always yielded in append (sequence) order.
+
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
+
+use serde::{Serialize, Deserialize};
+
+use crate::types::{DecisionKind, DecisionRecord, RoHZone};
+
+/// Secondary indexes maintained on every append
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DecisionIndex {
+    pub by_host: HashMap<String, Vec<usize>>,
+    pub by_upgrade: HashMap<String, Vec<usize>>,
+    pub by_evolution: HashMap<String, Vec<usize>>,
+    pub by_host_upgrade: HashMap<String, Vec<usize>>,   // host:upgrade
+    pub by_ledger_key: HashMap<String, Vec<usize>>,     // host:upgrade:evolution
+    pub by_kind: HashMap<String, Vec<usize>>,           // DecisionKind::verb()
+    pub by_zone: HashMap<RoHZone, Vec<usize>>,          // zone of post_roh
+    pub by_time: BTreeMap<i64, Vec<usize>>,
+}
+
+impl DecisionIndex {
+    pub fn insert(&mut self, idx: usize, record: &DecisionRecord) {
+        push(&mut self.by_host, record.host_did.clone(), idx);
+        push(&mut self.by_upgrade, record.upgrade_id.clone(), idx);
+        push(&mut self.by_evolution, record.evolution_id.clone(), idx);
+        push(
+            &mut self.by_host_upgrade,
+            host_upgrade_key(&record.host_did, &record.upgrade_id),
+            idx,
+        );
+        push(
+            &mut self.by_ledger_key,
+            format!("{}:{}:{}", record.host_did, record.upgrade_id, record.evolution_id),
+            idx,
+        );
+        push(&mut self.by_kind, record.decision.verb().to_string(), idx);
+        self.by_zone.entry(RoHZone::from_roh(record.post_roh)).or_default().push(idx);
+        self.by_time.entry(record.timestamp).or_default().push(idx);
+    }
+
+    /// Rebuild all indexes from scratch
+    pub fn rebuild(records: &[DecisionRecord]) -> Self {
+        let mut index = Self::default();
+        for (idx, record) in records.iter().enumerate() {
+            index.insert(idx, record);
+        }
+        index
+    }
+
+    /// Smallest sorted candidate set that can satisfy `query`, or `None` when
+    /// the query has no indexed predicate and every record is a candidate.
+    fn candidates(&self, query: &DecisionQuery) -> Option<Vec<usize>> {
+        let mut keys: Vec<Option<&Vec<usize>>> = Vec::new();
+        match (&query.host_did, &query.upgrade_id) {
+            (Some(host), Some(upgrade)) => {
+                keys.push(self.by_host_upgrade.get(&host_upgrade_key(host, upgrade)))
+            },
+            (Some(host), None) => keys.push(self.by_host.get(host)),
+            (None, Some(upgrade)) => keys.push(self.by_upgrade.get(upgrade)),
+            (None, None) => {},
+        }
+        if let Some(evolution) = &query.evolution_id {
+            keys.push(self.by_evolution.get(evolution));
+        }
+        if let Some(kind) = &query.kind {
+            keys.push(self.by_kind.get(kind));
+        }
+        if let Some(zone) = &query.zone {
+            keys.push(self.by_zone.get(zone));
+        }
+
+        // An indexed predicate with no posting list can never match
+        let lists = match keys.into_iter().collect::<Option<Vec<_>>>() {
+            Some(lists) => lists,
+            None => return Some(Vec::new()),
+        };
+
+        let smallest = lists.into_iter().min_by_key(|list| list.len());
+        let time_bounded = query.from.is_some() || query.until.is_some();
+
+        match (smallest, time_bounded) {
+            (Some(list), _) => Some(list.to_vec()),
+            (None, true) => {
+                let lower = query.from.map(Bound::Included).unwrap_or(Bound::Unbounded);
+                let upper = query.until.map(Bound::Excluded).unwrap_or(Bound::Unbounded);
+                if let (Bound::Included(a), Bound::Excluded(b)) = (lower, upper) {
+                    if a >= b {
+                        return Some(Vec::new());
+                    }
+                }
+                let mut hits: Vec<usize> = self
+                    .by_time
+                    .range((lower, upper))
+                    .flat_map(|(_, list)| list.iter().copied())
+                    .collect();
+                hits.sort_unstable();
+                Some(hits)
+            },
+            (None, false) => None,
+        }
+    }
+}
+
+fn push(map: &mut HashMap<String, Vec<usize>>, key: String, idx: usize) {
+    map.entry(key).or_default().push(idx);
+}
+
+pub(crate) fn host_upgrade_key(host_did: &str, upgrade_id: &str) -> String {
+    format!("{}:{}", host_did, upgrade_id)
+}
+
+/// Conjunctive filter over decision records; unset fields match everything
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DecisionQuery {
+    pub host_did: Option<String>,
+    pub upgrade_id: Option<String>,
+    pub evolution_id: Option<String>,
+    pub kind: Option<String>,       // DecisionKind::verb(), payload ignored
+    pub zone: Option<RoHZone>,     // RoH band: zone of post_roh, not pre_roh
+    pub from: Option<i64>,          // inclusive
+    pub until: Option<i64>,         // exclusive
+}
+
+impl DecisionQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn host(mut self, host_did: &str) -> Self {
+        self.host_did = Some(host_did.to_string());
+        self
+    }
+
+    pub fn upgrade(mut self, upgrade_id: &str) -> Self {
+        self.upgrade_id = Some(upgrade_id.to_string());
+        self
+    }
+
+    pub fn evolution(mut self, evolution_id: &str) -> Self {
+        self.evolution_id = Some(evolution_id.to_string());
+        self
+    }
+
+    /// Match decisions of the same variant as `kind` (payload is ignored)
+    pub fn kind(mut self, kind: &DecisionKind) -> Self {
+        self.kind = Some(kind.verb().to_string());
+        self
+    }
+
+    /// Match by RoH band, read as the `RoHZone` the decision leaves the host in (`post_roh`)
+    pub fn zone(mut self, zone: RoHZone) -> Self {
+        self.zone = Some(zone);
+        self
+    }
+
+    /// Restrict to `from <= timestamp < until`
+    pub fn between(mut self, from: i64, until: i64) -> Self {
+        self.from = Some(from);
+        self.until = Some(until);
+        self
+    }
+
+    pub fn since(mut self, from: i64) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn until(mut self, until: i64) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn matches(&self, record: &DecisionRecord) -> bool {
+        self.host_did.as_deref().is_none_or(|h| record.host_did == h)
+            && self.upgrade_id.as_deref().is_none_or(|u| record.upgrade_id == u)
+            && self.evolution_id.as_deref().is_none_or(|e| record.evolution_id == e)
+            && self.kind.as_deref().is_none_or(|k| record.decision.verb() == k)
+            && self.zone.is_none_or(|z| RoHZone::from_roh(record.post_roh) == z)
+            && self.from.is_none_or(|t| record.timestamp >= t)
+            && self.until.is_none_or(|t| record.timestamp < t)
+    }
+}
+
+/// Lazy iterator over query hits, yielding `(sequence, record)`
+pub struct DecisionIter<'a> {
+    records: &'a [DecisionRecord],
+    query: DecisionQuery,
+    candidates: Option<Vec<usize>>,
+    pos: usize,
+}
+
+impl<'a> DecisionIter<'a> {
+    pub(crate) fn new(records: &'a [DecisionRecord], index: &DecisionIndex, query: DecisionQuery) -> Self {
+        let candidates = index.candidates(&query);
+        Self { records, query, candidates, pos: 0 }
+    }
+
+    /// Skip ahead so the next hit has sequence number `>= cursor`
+    pub fn starting_at(mut self, cursor: usize) -> Self {
+        self.pos = match &self.candidates {
+            Some(list) => list.partition_point(|&idx| idx < cursor),
+            None => cursor,
+        };
+        self
+    }
+}
+
+impl<'a> Iterator for DecisionIter<'a> {
+    type Item = (usize, &'a DecisionRecord);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let idx = match &self.candidates {
+                Some(list) => *list.get(self.pos)?,
+                None => {
+                    if self.pos >= self.records.len() {
+                        return None;
+                    }
+                    self.pos
+                },
+            };
+            self.pos += 1;
+            if let Some(record) = self.records.get(idx) {
+                if self.query.matches(record) {
+                    return Some((idx, record));
+                }
+            }
+        }
+    }
+}
+
+/// One page of query results; pass `next_cursor` back to fetch the next page
+#[derive(Debug, Clone, Serialize)]
+pub struct DecisionPage<'a> {
+    pub items: Vec<&'a DecisionRecord>,
+    pub next_cursor: Option<usize>,
+}
+
+impl<'a> DecisionPage<'a> {
+    pub(crate) fn collect(iter: DecisionIter<'a>, limit: usize) -> Self {
+        let mut items = Vec::new();
+        let mut next_cursor = None;
+        // A zero-size page would otherwise hand back the first hit as a cursor forever
+        if limit == 0 {
+            return Self { items, next_cursor };
+        }
+        for (idx, record) in iter {
+            if items.len() == limit {
+                next_cursor = Some(idx);
+                break;
+            }
+            items.push(record);
+        }
+        Self { items, next_cursor }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::DecisionRegistry;
+    use crate::types::{BrainSpecs, DecisionLedgerKey, KsrBand};
+
+    fn record(host: &str, upgrade: &str, evo: &str, decision: DecisionKind, post_roh: f32, ts: i64) -> DecisionRecord {
+        DecisionRecord {
+            host_did: host.to_string(),
+            upgrade_id: upgrade.to_string(),
+            evolution_id: evo.to_string(),
+            decision,
+            pre_roh: 0.10,
+            post_roh,
+            brain_specs: BrainSpecs::default(),
+            host_budget: Default::default(),
+            bci_snapshot: Default::default(),
+            ksr_band: KsrBand::default(),
+            ledger_key: DecisionLedgerKey {
+                host_did: host.to_string(),
+                upgrade_id: upgrade.to_string(),
+                evolution_id: evo.to_string(),
+            },
+            timestamp: ts,
+        }
+    }
+
+    fn registry() -> DecisionRegistry {
+        let mut registry = DecisionRegistry::new();
+        registry.append(record("host-a", "bci-1", "evo-1", DecisionKind::Authorize, 0.10, 100));
+        registry.append(record("host-a", "bci-1", "evo-2", DecisionKind::Approve, 0.20, 200));
+        registry.append(record("host-b", "bci-1", "evo-1", DecisionKind::Escalate, 0.27, 300));
+        registry.append(record("host-a", "xr-2", "evo-1", DecisionKind::Reject, 0.31, 400));
+        registry.append(record("host-b", "xr-2", "evo-3", DecisionKind::Approve, 0.18, 500));
+        registry
+    }
+
+    #[test]
+    fn test_lookup_spans_evolutions() {
+        let registry = registry();
+        let hits = registry.lookup("host-a", "bci-1");
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].evolution_id, "evo-1");
+        assert_eq!(hits[1].evolution_id, "evo-2");
+    }
+
+    #[test]
+    fn test_query_combines_filters() {
+        let registry = registry();
+        let approvals: Vec<_> = registry
+            .query(DecisionQuery::new().kind(&DecisionKind::Approve).zone(RoHZone::Caution))
+            .map(|(idx, _)| idx)
+            .collect();
+        assert_eq!(approvals, vec![1, 4]);
+
+        let window: Vec<_> = registry
+            .query(DecisionQuery::new().host("host-a").between(150, 450))
+            .map(|(idx, _)| idx)
+            .collect();
+        assert_eq!(window, vec![1, 3]);
+
+        assert_eq!(registry.query(DecisionQuery::new().evolution("evo-9")).count(), 0);
+        assert_eq!(registry.query(DecisionQuery::new().upgrade("xr-2")).count(), 2);
+    }
+
+    #[test]
+    fn test_pagination_cursor() {
+        let registry = registry();
+        let first = registry.page(&DecisionQuery::new(), None, 2);
+        assert_eq!(first.items.len(), 2);
+        assert_eq!(first.next_cursor, Some(2));
+
+        let second = registry.page(&DecisionQuery::new(), first.next_cursor, 2);
+        assert_eq!(second.items[0].host_did, "host-b");
+        let third = registry.page(&DecisionQuery::new(), second.next_cursor, 2);
+        assert_eq!(third.items.len(), 1);
+        assert_eq!(third.next_cursor, None);
+
+        let empty = registry.page(&DecisionQuery::new(), None, 0);
+        assert!(empty.items.is_empty());
+        assert_eq!(empty.next_cursor, None);
+    }
+}
@@ -0,0 +1,620 @@
+//! Hash-chained decision registry with segmented on-disk persistence.
+//! Every entry commits to the hash of its predecessor, so editing, dropping or
+//! reordering a stored record breaks the chain and is caught by `verify_chain()`.
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Serialize, Deserialize};
+use sha2::{Sha256, Digest};
+
+use crate::canonical::canonical_bytes;
+use crate::query::{DecisionIndex, DecisionIter, DecisionPage, DecisionQuery};
+use crate::types::DecisionRecord;
+
+/// `prev_hash` of the first entry in every chain
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Default number of entries per on-disk segment
+pub const DEFAULT_SEGMENT_CAPACITY: usize = 1024;
+
+const INDEX_FILE: &str = "index.json";
+
+/// Chain metadata for one registry entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainLink {
+    pub seq: u64,
+    pub prev_hash: String,
+    pub entry_hash: String,
+}
+
+impl ChainLink {
+    /// Hash committing to the position, the predecessor and the record itself
+    pub fn compute_hash(seq: u64, prev_hash: &str, record: &DecisionRecord) -> String {
+        let json = canonical_bytes(record).expect("decision records always serialize");
+        let mut hasher = Sha256::new();
+        hasher.update(seq.to_be_bytes());
+        hasher.update(prev_hash.as_bytes());
+        hasher.update(&json);
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// One persisted line of a segment file: the record plus its chain link
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainedDecision {
+    pub seq: u64,
+    pub prev_hash: String,
+    pub entry_hash: String,
+    pub record: DecisionRecord,
+}
+
+/// Borrowed form of `ChainedDecision` used when writing segments
+#[derive(Serialize)]
+struct ChainedDecisionRef<'a> {
+    seq: u64,
+    prev_hash: &'a str,
+    entry_hash: &'a str,
+    record: &'a DecisionRecord,
+}
+
+/// First integrity violation found while walking a chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChainError {
+    /// Entry carries the wrong sequence number (dropped or reordered record)
+    SequenceMismatch { index: usize, found: u64 },
+    /// Entry does not point at its predecessor's hash
+    BrokenLink { index: usize, expected_prev: String, found_prev: String },
+    /// Stored hash does not match the record content (edited record)
+    HashMismatch { index: usize, expected: String, found: String },
+    /// Record and chain vectors disagree in length
+    LengthMismatch { records: usize, links: usize },
+}
+
+impl ChainError {
+    /// Position of the first offending entry
+    pub fn index(&self) -> usize {
+        match self {
+            ChainError::SequenceMismatch { index, .. }
+            | ChainError::BrokenLink { index, .. }
+            | ChainError::HashMismatch { index, .. } => *index,
+            ChainError::LengthMismatch { records, links } => (*records).min(*links),
+        }
+    }
+}
+
+impl fmt::Display for ChainError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChainError::SequenceMismatch { index, found } => {
+                write!(f, "entry {} carries sequence number {}", index, found)
+            },
+            ChainError::BrokenLink { index, expected_prev, found_prev } => write!(
+                f,
+                "entry {} links to {} but predecessor hash is {}",
+                index, found_prev, expected_prev
+            ),
+            ChainError::HashMismatch { index, expected, found } => write!(
+                f,
+                "entry {} hash mismatch: stored {}, recomputed {}",
+                index, found, expected
+            ),
+            ChainError::LengthMismatch { records, links } => {
+                write!(f, "{} records but {} chain links", records, links)
+            },
+        }
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+/// Global decision registry for audit and traceability
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionRegistry {
+    /// Immutable append-only log of all decisions
+    pub decisions: Vec<DecisionRecord>,
+    /// Chain link for each entry in `decisions`
+    pub chain: Vec<ChainLink>,
+    /// Secondary indexes for lookup and queries
+    pub index: DecisionIndex,
+    /// Last known SafetyEpoch that tightened policy
+    pub last_policy_tightening: Option<String>,
+}
+
+impl Default for DecisionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DecisionRegistry {
+    pub fn new() -> Self {
+        Self {
+            decisions: Vec::new(),
+            chain: Vec::new(),
+            index: DecisionIndex::default(),
+            last_policy_tightening: None,
+        }
+    }
+
+    /// Rebuild a registry from persisted entries, rejecting any broken chain
+    pub fn from_chain(entries: Vec<ChainedDecision>) -> Result<Self, ChainError> {
+        let mut registry = Self::new();
+        for entry in entries {
+            let link = ChainLink {
+                seq: entry.seq,
+                prev_hash: entry.prev_hash,
+                entry_hash: entry.entry_hash,
+            };
+            registry.push(link, entry.record);
+        }
+        registry.verify_chain()?;
+        Ok(registry)
+    }
+
+    /// Append a decision record (append-only, never overwrite)
+    /// Returns the chained entry hash for blockchain stamping.
+    pub fn append(&mut self, record: DecisionRecord) -> String {
+        let link = self.next_link(&record);
+        let hash = link.entry_hash.clone();
+        self.push(link, record);
+        hash
+    }
+
+    /// Fetch all decisions for a given host_did and upgrade_id, across evolutions
+    pub fn lookup(&self, host_did: &str, upgrade_id: &str) -> Vec<&DecisionRecord> {
+        self.query(DecisionQuery::new().host(host_did).upgrade(upgrade_id))
+            .map(|(_, record)| record)
+            .collect()
+    }
+
+    /// Lazily iterate over records matching `query`, in append order
+    pub fn query(&self, query: DecisionQuery) -> DecisionIter<'_> {
+        DecisionIter::new(&self.decisions, &self.index, query)
+    }
+
+    /// Fetch up to `limit` matches starting at `cursor` (a sequence number
+    /// returned as `next_cursor` by the previous page); `limit == 0` yields an
+    /// empty page with no cursor
+    pub fn page(&self, query: &DecisionQuery, cursor: Option<usize>, limit: usize) -> DecisionPage<'_> {
+        let iter = self.query(query.clone()).starting_at(cursor.unwrap_or(0));
+        DecisionPage::collect(iter, limit)
+    }
+
+    /// Recompute secondary indexes, e.g. after deserializing an older snapshot
+    pub fn rebuild_index(&mut self) {
+        self.index = DecisionIndex::rebuild(&self.decisions);
+    }
+
+    pub fn len(&self) -> usize {
+        self.decisions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.decisions.is_empty()
+    }
+
+    /// Hash of the latest entry, or `GENESIS_HASH` for an empty registry
+    pub fn head_hash(&self) -> &str {
+        self.chain
+            .last()
+            .map(|link| link.entry_hash.as_str())
+            .unwrap_or(GENESIS_HASH)
+    }
+
+    /// Walk the whole chain and report the first corrupted or reordered entry
+    pub fn verify_chain(&self) -> Result<(), ChainError> {
+        if self.decisions.len() != self.chain.len() {
+            return Err(ChainError::LengthMismatch {
+                records: self.decisions.len(),
+                links: self.chain.len(),
+            });
+        }
+
+        let mut prev_hash = GENESIS_HASH;
+        for (index, (link, record)) in self.chain.iter().zip(&self.decisions).enumerate() {
+            if link.seq != index as u64 {
+                return Err(ChainError::SequenceMismatch { index, found: link.seq });
+            }
+            if link.prev_hash != prev_hash {
+                return Err(ChainError::BrokenLink {
+                    index,
+                    expected_prev: prev_hash.to_string(),
+                    found_prev: link.prev_hash.clone(),
+                });
+            }
+            let expected = ChainLink::compute_hash(link.seq, &link.prev_hash, record);
+            if link.entry_hash != expected {
+                return Err(ChainError::HashMismatch {
+                    index,
+                    expected,
+                    found: link.entry_hash.clone(),
+                });
+            }
+            prev_hash = &link.entry_hash;
+        }
+        Ok(())
+    }
+
+    /// Serialize for blockchain commit
+    pub fn to_jsonl(&self) -> String {
+        self.decisions
+            .iter()
+            .filter_map(|d| serde_json::to_string(d).ok())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn next_link(&self, record: &DecisionRecord) -> ChainLink {
+        let seq = self.decisions.len() as u64;
+        let prev_hash = self.head_hash().to_string();
+        let entry_hash = ChainLink::compute_hash(seq, &prev_hash, record);
+        ChainLink { seq, prev_hash, entry_hash }
+    }
+
+    fn push(&mut self, link: ChainLink, record: DecisionRecord) {
+        self.index.insert(self.decisions.len(), &record);
+        self.chain.push(link);
+        self.decisions.push(record);
+    }
+}
+
+/// Errors raised by the file-backed registry
+#[derive(Debug)]
+pub enum RegistryError {
+    Io(io::Error),
+    Serialization(serde_json::Error),
+    /// A segment line could not be parsed
+    CorruptSegment { segment: String, line: usize, reason: String },
+    /// Segments parsed but the hash chain is broken
+    Chain(ChainError),
+    /// Index claims more entries or a different head than the segments hold
+    IndexMismatch { indexed: u64, stored: u64 },
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegistryError::Io(e) => write!(f, "registry io error: {}", e),
+            RegistryError::Serialization(e) => write!(f, "registry serialization error: {}", e),
+            RegistryError::CorruptSegment { segment, line, reason } => {
+                write!(f, "corrupt segment {} line {}: {}", segment, line, reason)
+            },
+            RegistryError::Chain(e) => write!(f, "decision chain broken: {}", e),
+            RegistryError::IndexMismatch { indexed, stored } => write!(
+                f,
+                "index lists {} entries but segments hold {}",
+                indexed, stored
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+impl From<io::Error> for RegistryError {
+    fn from(e: io::Error) -> Self {
+        RegistryError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for RegistryError {
+    fn from(e: serde_json::Error) -> Self {
+        RegistryError::Serialization(e)
+    }
+}
+
+impl From<ChainError> for RegistryError {
+    fn from(e: ChainError) -> Self {
+        RegistryError::Chain(e)
+    }
+}
+
+/// Metadata for one JSONL segment file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentMeta {
+    pub file: String,
+    pub first_seq: u64,
+    pub count: u64,
+    pub last_hash: String,
+}
+
+/// `index.json`: segment layout plus the chain head for quick startup checks
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentIndex {
+    pub segments: Vec<SegmentMeta>,
+    pub len: u64,
+    pub head_hash: String,
+}
+
+/// File-backed registry: `segment-NNNNNNNN.jsonl` files plus `index.json`
+///
+/// Segments are the source of truth. On open every entry is re-verified against
+/// the chain; the index is only trusted as a lower bound, so a crash between a
+/// segment write and an index write is recovered, while truncation is not.
+#[derive(Debug)]
+pub struct FileDecisionRegistry {
+    dir: PathBuf,
+    segment_capacity: usize,
+    index: SegmentIndex,
+    registry: DecisionRegistry,
+}
+
+impl FileDecisionRegistry {
+    /// Open (or create) a registry directory and reload every segment
+    pub fn open<P: AsRef<Path>>(dir: P) -> Result<Self, RegistryError> {
+        Self::open_with_capacity(dir, DEFAULT_SEGMENT_CAPACITY)
+    }
+
+    pub fn open_with_capacity<P: AsRef<Path>>(
+        dir: P,
+        segment_capacity: usize,
+    ) -> Result<Self, RegistryError> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        let stored_index = match fs::read_to_string(dir.join(INDEX_FILE)) {
+            Ok(text) => Some(serde_json::from_str::<SegmentIndex>(&text)?),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut entries = Vec::new();
+        let mut segments = Vec::new();
+        for file in segment_files(&dir)? {
+            let loaded = read_segment(&dir, &file)?;
+            if let (Some(first), Some(last)) = (loaded.first(), loaded.last()) {
+                segments.push(SegmentMeta {
+                    file,
+                    first_seq: first.seq,
+                    count: loaded.len() as u64,
+                    last_hash: last.entry_hash.clone(),
+                });
+            }
+            entries.extend(loaded);
+        }
+
+        let registry = DecisionRegistry::from_chain(entries)?;
+
+        if let Some(stored) = &stored_index {
+            let stored_len = registry.len() as u64;
+            let head_matches = stored.len == 0
+                || registry
+                    .chain
+                    .get(stored.len as usize - 1)
+                    .map(|link| link.entry_hash == stored.head_hash)
+                    .unwrap_or(false);
+            if stored.len > stored_len || !head_matches {
+                return Err(RegistryError::IndexMismatch {
+                    indexed: stored.len,
+                    stored: stored_len,
+                });
+            }
+        }
+
+        let index = SegmentIndex {
+            segments,
+            len: registry.len() as u64,
+            head_hash: registry.head_hash().to_string(),
+        };
+
+        let store = Self {
+            dir,
+            segment_capacity: segment_capacity.max(1),
+            index,
+            registry,
+        };
+        if stored_index.as_ref() != Some(&store.index) {
+            store.write_index()?;
+        }
+        Ok(store)
+    }
+
+    /// Append a record durably; the in-memory chain only advances once the
+    /// segment line has been written and synced.
+    pub fn append(&mut self, record: DecisionRecord) -> Result<String, RegistryError> {
+        let link = self.registry.next_link(&record);
+        let line = serde_json::to_string(&ChainedDecisionRef {
+            seq: link.seq,
+            prev_hash: &link.prev_hash,
+            entry_hash: &link.entry_hash,
+            record: &record,
+        })?;
+
+        let rotate = self
+            .index
+            .segments
+            .last()
+            .map(|s| s.count as usize >= self.segment_capacity)
+            .unwrap_or(true);
+        if rotate {
+            self.index.segments.push(SegmentMeta {
+                file: segment_name(self.index.segments.len()),
+                first_seq: link.seq,
+                count: 0,
+                last_hash: String::new(),
+            });
+        }
+
+        let segment = self.index.segments.last_mut().expect("segment just ensured");
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.join(&segment.file))?;
+        writeln!(file, "{}", line)?;
+        file.sync_data()?;
+
+        segment.count += 1;
+        segment.last_hash = link.entry_hash.clone();
+        self.index.len = link.seq + 1;
+        self.index.head_hash = link.entry_hash.clone();
+
+        let hash = link.entry_hash.clone();
+        self.registry.push(link, record);
+        self.write_index()?;
+        Ok(hash)
+    }
+
+    /// Read-only view of the in-memory chain
+    pub fn registry(&self) -> &DecisionRegistry {
+        &self.registry
+    }
+
+    pub fn index(&self) -> &SegmentIndex {
+        &self.index
+    }
+
+    pub fn verify_chain(&self) -> Result<(), ChainError> {
+        self.registry.verify_chain()
+    }
+
+    fn write_index(&self) -> Result<(), RegistryError> {
+        let tmp = self.dir.join(format!("{}.tmp", INDEX_FILE));
+        fs::write(&tmp, serde_json::to_vec_pretty(&self.index)?)?;
+        fs::rename(&tmp, self.dir.join(INDEX_FILE))?;
+        Ok(())
+    }
+}
+
+fn segment_name(n: usize) -> String {
+    format!("segment-{:08}.jsonl", n)
+}
+
+/// Segment file names in the directory, in sequence order
+fn segment_files(dir: &Path) -> Result<Vec<String>, RegistryError> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name.starts_with("segment-") && name.ends_with(".jsonl") {
+            files.push(name);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn read_segment(dir: &Path, file: &str) -> Result<Vec<ChainedDecision>, RegistryError> {
+    let reader = BufReader::new(File::open(dir.join(file))?);
+    let mut entries = Vec::new();
+    for (n, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(&line).map_err(|e| RegistryError::CorruptSegment {
+            segment: file.to_string(),
+            line: n + 1,
+            reason: e.to_string(),
+        })?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BrainSpecs, DecisionKind, DecisionLedgerKey, KsrBand};
+    use chrono::Utc;
+
+    fn record(upgrade_id: &str) -> DecisionRecord {
+        DecisionRecord {
+            host_did: "bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7".to_string(),
+            upgrade_id: upgrade_id.to_string(),
+            evolution_id: "evo-001".to_string(),
+            decision: DecisionKind::Authorize,
+            pre_roh: 0.15,
+            post_roh: 0.22,
+            brain_specs: BrainSpecs::default(),
+            host_budget: Default::default(),
+            bci_snapshot: Default::default(),
+            ksr_band: KsrBand::default(),
+            ledger_key: DecisionLedgerKey {
+                host_did: "bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7".to_string(),
+                upgrade_id: upgrade_id.to_string(),
+                evolution_id: "evo-001".to_string(),
+            },
+            timestamp: Utc::now().timestamp(),
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "cyconetics-registry-{}-{}",
+            name,
+            uuid::Uuid::new_v4()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_decision_registry_append() {
+        let mut registry = DecisionRegistry::new();
+        let hash = registry.append(record("upgrade-001"));
+        assert!(!hash.is_empty());
+        assert_eq!(registry.decisions.len(), 1);
+        assert_eq!(registry.chain[0].prev_hash, GENESIS_HASH);
+        assert_eq!(registry.head_hash(), hash);
+    }
+
+    #[test]
+    fn test_verify_chain_pinpoints_edit_and_reorder() {
+        let mut registry = DecisionRegistry::new();
+        for i in 0..4 {
+            registry.append(record(&format!("upgrade-{:03}", i)));
+        }
+        assert!(registry.verify_chain().is_ok());
+
+        let mut edited = registry.clone();
+        edited.decisions[2].post_roh = 0.29;
+        let err = edited.verify_chain().unwrap_err();
+        assert!(matches!(err, ChainError::HashMismatch { index: 2, .. }));
+
+        let mut reordered = registry.clone();
+        reordered.decisions.swap(1, 2);
+        reordered.chain.swap(1, 2);
+        assert_eq!(reordered.verify_chain().unwrap_err().index(), 1);
+    }
+
+    #[test]
+    fn test_file_registry_reloads_across_segments() {
+        let dir = temp_dir("reload");
+        let head = {
+            let mut store = FileDecisionRegistry::open_with_capacity(&dir, 2).unwrap();
+            for i in 0..5 {
+                store.append(record(&format!("upgrade-{:03}", i))).unwrap();
+            }
+            assert_eq!(store.index().segments.len(), 3);
+            store.registry().head_hash().to_string()
+        };
+
+        let reopened = FileDecisionRegistry::open_with_capacity(&dir, 2).unwrap();
+        assert_eq!(reopened.registry().len(), 5);
+        assert_eq!(reopened.registry().head_hash(), head);
+        assert!(reopened.verify_chain().is_ok());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_registry_rejects_tampered_segment() {
+        let dir = temp_dir("tamper");
+        {
+            let mut store = FileDecisionRegistry::open(&dir).unwrap();
+            store.append(record("upgrade-001")).unwrap();
+            store.append(record("upgrade-002")).unwrap();
+        }
+
+        let segment = dir.join(segment_name(0));
+        let text = fs::read_to_string(&segment).unwrap();
+        fs::write(&segment, text.replace("\"post_roh\":0.22", "\"post_roh\":0.12")).unwrap();
+
+        match FileDecisionRegistry::open(&dir) {
+            Err(RegistryError::Chain(err)) => assert_eq!(err.index(), 0),
+            other => panic!("expected chain error, got {:?}", other.map(|_| ())),
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
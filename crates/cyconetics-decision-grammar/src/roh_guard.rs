@@ -6,7 +6,7 @@ use crate::types::*;
 use serde::{Serialize, Deserialize};
 
 /// BioKarma risk vector: multi-axis risk representation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BioKarmaRiskVector {
     pub metabolic_risk: f32,       // 0.0–1.0: glucose, protein, hydration strain
     pub hemodynamic_risk: f32,     // 0.0–1.0: BP, HR, perfusion stress
@@ -87,9 +87,7 @@ pub fn roh_from_biokarma(vec: &BioKarmaRiskVector) -> f32 {
 
 /// Predict RoH for an upgrade given current host state
 pub fn predict_roh(state: &RoHGuardedHostState, delta_from_upgrade: f32) -> f32 {
-    let current = state.current_roh;
-    let predicted = (current + delta_from_upgrade).max(0.0).min(1.0);
-    predicted
+    (state.current_roh + delta_from_upgrade).clamp(0.0, 1.0)
 }
 
 /// Try to construct a RoHBound<30> capability if RoH is provably < 0.3
@@ -120,7 +118,7 @@ pub struct UpgradeDescriptor {
     pub blood_token_cost: f32,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum UpgradeClass {
     BCI,    // Brain-computer interface
     EXO,    // Exoskeleton/mechanical
@@ -128,6 +126,23 @@ pub enum UpgradeClass {
     GOV,    // Governance/policy
 }
 
+impl UpgradeClass {
+    pub const ALL: [UpgradeClass; 4] = [UpgradeClass::BCI, UpgradeClass::EXO, UpgradeClass::XR, UpgradeClass::GOV];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpgradeClass::BCI => "BCI",
+            UpgradeClass::EXO => "EXO",
+            UpgradeClass::XR => "XR",
+            UpgradeClass::GOV => "GOV",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|c| c.as_str() == name)
+    }
+}
+
 /// Decision enum returned from upgrade safety evaluation
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UpgradeDecision {
@@ -220,7 +235,7 @@ mod tests {
             psych_risk: 0.08,
         };
         let composite = vec.composite_score();
-        assert!(composite >= 0.0 && composite <= 0.3);
+        assert!((0.0..=0.3).contains(&composite));
     }
 
     #[test]
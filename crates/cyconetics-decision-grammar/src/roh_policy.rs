@@ -0,0 +1,272 @@
+//! Runtime side of `roh_policy!`: an incident-driven RoH ceiling ratchet.
+//! N SafetyEpoch incidents within T hours lower the ceiling by delta, never below the floor.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::roh_guard::UpgradeClass;
+use crate::{IncidentType, PolicyTightening, SafetyEpochLog};
+
+/// Errority floor: no ratchet lowers the RoH ceiling past this value
+pub const ROH_CEILING_FLOOR: f32 = 0.15;
+
+/// Hard RoH ceiling; a ratchet never starts above it
+pub const ROH_CEILING_MAX: f32 = 0.30;
+
+/// A hand-built `RohPolicySpec` that `roh_policy!` would have refused
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RohPolicyError {
+    /// Zero or negative would make the ratchet a no-op or a relaxation
+    InvalidDelta(f32),
+    InvalidInitialCeiling(f32),
+}
+
+impl fmt::Display for RohPolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RohPolicyError::InvalidDelta(d) => write!(
+                f,
+                "ceiling_delta {} must be in (0, {:.2}]",
+                d,
+                ROH_CEILING_MAX - ROH_CEILING_FLOOR
+            ),
+            RohPolicyError::InvalidInitialCeiling(c) => write!(f, "initial_ceiling {} is not a number", c),
+        }
+    }
+}
+
+impl std::error::Error for RohPolicyError {}
+
+/// Declared parameters of one `roh_policy!`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RohPolicySpec {
+    pub name: &'static str,
+    pub window_hours: u32,
+    pub incident_count_threshold: u32,
+    pub ceiling_delta: f32,
+    pub initial_ceiling: f32,
+    pub affected_classes: &'static [UpgradeClass],
+    pub incident_types: &'static [IncidentType],  // incidents that count toward the threshold
+    pub zone: Option<&'static str>,               // None: incidents in any zone count
+}
+
+impl RohPolicySpec {
+    fn window_secs(&self) -> i64 {
+        i64::from(self.window_hours) * 3600
+    }
+
+    /// True when `log` is a RoH incident this ratchet is responsible for
+    pub fn counts(&self, log: &SafetyEpochLog) -> bool {
+        self.incident_types.contains(&log.incident_type)
+            && self.zone.is_none_or(|zone| log.affected_zones.iter().any(|z| z == zone))
+    }
+}
+
+/// Monotone RoH ceiling driven by `SafetyEpochLog` events.
+/// There is deliberately no setter, reset, or Clone: the ceiling only moves down.
+#[derive(Debug)]
+pub struct RohRatchet {
+    spec: RohPolicySpec,
+    ceiling: f32,
+    window: VecDeque<(i64, String)>,  // (timestamp, epoch_id) of incidents not yet acted on
+    history: Vec<PolicyTightening>,
+}
+
+impl RohRatchet {
+    /// Re-checks what `roh_policy!` checks at compile time, since `RohPolicySpec` can be
+    /// built by hand: the delta must be positive, and the starting ceiling is clamped
+    /// to [`ROH_CEILING_FLOOR`, `ROH_CEILING_MAX`].
+    pub fn new(spec: RohPolicySpec) -> Result<Self, RohPolicyError> {
+        if !(spec.ceiling_delta > 0.0 && spec.ceiling_delta <= ROH_CEILING_MAX - ROH_CEILING_FLOOR) {
+            return Err(RohPolicyError::InvalidDelta(spec.ceiling_delta));
+        }
+        if spec.initial_ceiling.is_nan() {
+            return Err(RohPolicyError::InvalidInitialCeiling(spec.initial_ceiling));
+        }
+        Ok(Self {
+            ceiling: spec.initial_ceiling.clamp(ROH_CEILING_FLOOR, ROH_CEILING_MAX),
+            spec,
+            window: VecDeque::new(),
+            history: Vec::new(),
+        })
+    }
+
+    /// Rebuild the ratchet state by replaying an incident log in order
+    pub fn replay<'a>(
+        spec: RohPolicySpec,
+        logs: impl IntoIterator<Item = &'a SafetyEpochLog>,
+    ) -> Result<Self, RohPolicyError> {
+        let mut ratchet = Self::new(spec)?;
+        for log in logs {
+            ratchet.observe(log);
+        }
+        Ok(ratchet)
+    }
+
+    pub fn spec(&self) -> &RohPolicySpec {
+        &self.spec
+    }
+
+    pub fn ceiling(&self) -> f32 {
+        self.ceiling
+    }
+
+    /// True when the predicted RoH stays strictly below the current ceiling
+    pub fn admits(&self, predicted_roh: f32) -> bool {
+        predicted_roh < self.ceiling
+    }
+
+    /// Every tightening emitted so far, oldest first
+    pub fn history(&self) -> &[PolicyTightening] {
+        &self.history
+    }
+
+    /// Record an incident; returns a tightening when the threshold is reached inside the window.
+    /// Incidents that triggered a tightening are consumed, so the next step needs a fresh N.
+    /// Incidents of another type or outside the ratchet's zone are ignored.
+    pub fn observe(&mut self, log: &SafetyEpochLog) -> Option<PolicyTightening> {
+        if !self.spec.counts(log) {
+            return None;
+        }
+        let cutoff = log.timestamp - self.spec.window_secs();
+        self.window.retain(|(ts, _)| *ts > cutoff);
+        self.window.push_back((log.timestamp, log.epoch_id.clone()));
+
+        if (self.window.len() as u32) < self.spec.incident_count_threshold {
+            return None;
+        }
+
+        let new_ceiling = (self.ceiling - self.spec.ceiling_delta).max(ROH_CEILING_FLOOR);
+        let epochs: Vec<String> = self.window.drain(..).map(|(_, id)| id).collect();
+        if new_ceiling >= self.ceiling {
+            return None;  // already at the floor
+        }
+
+        let tightening = PolicyTightening {
+            old_roh_ceiling: self.ceiling,
+            new_roh_ceiling: new_ceiling,
+            affected_upgrade_classes: self
+                .spec
+                .affected_classes
+                .iter()
+                .map(|c| c.as_str().to_string())
+                .collect(),
+            reason: format!(
+                "{}: {} incidents within {}h ({})",
+                self.spec.name,
+                epochs.len(),
+                self.spec.window_hours,
+                epochs.join(", ")
+            ),
+        };
+        self.ceiling = new_ceiling;
+        self.history.push(tightening.clone());
+        Some(tightening)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IncidentType;
+
+    crate::roh_policy! {
+        policy PhoenixErrority {
+            window_hours: 24,
+            incident_count_threshold: 3,
+            ceiling_delta: 0.05,
+            classes: [BCI, XR],
+            zone: "phoenix-west",
+        }
+    }
+
+    fn incident(epoch: &str, timestamp: i64) -> SafetyEpochLog {
+        SafetyEpochLog {
+            epoch_id: epoch.to_string(),
+            timestamp,
+            incident_type: IncidentType::RoHTrendingHigh,
+            affected_zones: vec!["phoenix-west".to_string()],
+            roh_measurement: 0.27,
+            policy_action: None,
+            evidence_hash: "0xabc".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_threshold_within_window_tightens() {
+        let mut policy = PhoenixErrority::new();
+        assert!((policy.ceiling() - 0.30).abs() < 1e-6);
+
+        let t0 = 1708357200;
+        assert!(policy.observe(&incident("E1", t0)).is_none());
+        assert!(policy.observe(&incident("E2", t0 + 3600)).is_none());
+        let tightening = policy.observe(&incident("E3", t0 + 7200)).unwrap();
+        assert!((tightening.new_roh_ceiling - 0.25).abs() < 1e-6);
+        assert_eq!(tightening.affected_upgrade_classes, vec!["BCI", "XR"]);
+        assert!(!policy.admits(0.26));
+
+        // Incidents spread wider than the window never accumulate
+        let t1 = t0 + 10 * 86_400;
+        for day in 0..5 {
+            assert!(policy.observe(&incident("E", t1 + day * 86_400)).is_none());
+        }
+        assert_eq!(policy.history().len(), 1);
+    }
+
+    #[test]
+    fn test_only_roh_incidents_in_zone_count() {
+        let mut policy = PhoenixErrority::new();
+        let t0 = 1708357200;
+
+        let mut consent = incident("N1", t0);
+        consent.incident_type = IncidentType::NeuroconsentViolation;
+        let mut overdraw = incident("B1", t0 + 60);
+        overdraw.incident_type = IncidentType::BloodTokenOverdraw;
+        let mut elsewhere = incident("Z1", t0 + 120);
+        elsewhere.affected_zones = vec!["tempe-east".to_string()];
+        for log in [&consent, &overdraw, &elsewhere] {
+            assert!(policy.observe(log).is_none());
+        }
+
+        let mut strain = incident("M1", t0 + 180);
+        strain.incident_type = IncidentType::MetabolicStrain;
+        assert!(policy.observe(&strain).is_none());
+        assert!(policy.observe(&incident("E1", t0 + 240)).is_none());
+        let tightening = policy.observe(&incident("E2", t0 + 300)).unwrap();
+        assert!(tightening.reason.contains("M1, E1, E2"));
+        assert_eq!(policy.history().len(), 1);
+    }
+
+    #[test]
+    fn test_ceiling_never_passes_floor() {
+        let logs: Vec<SafetyEpochLog> =
+            (0..30).map(|i| incident(&format!("E{}", i), 1708357200 + i * 60)).collect();
+        let policy = PhoenixErrority::replay(&logs);
+        assert!((policy.ceiling() - ROH_CEILING_FLOOR).abs() < 1e-6);
+        assert!(policy
+            .history()
+            .windows(2)
+            .all(|w| w[1].new_roh_ceiling < w[0].new_roh_ceiling));
+        assert_eq!(PhoenixErrority::WINDOW_HOURS, 24);
+    }
+
+    #[test]
+    fn test_hand_built_spec_is_checked() {
+        let spec = RohPolicySpec { initial_ceiling: 0.90, ..PhoenixErrority::SPEC };
+        assert!((RohRatchet::new(spec).unwrap().ceiling() - ROH_CEILING_MAX).abs() < 1e-6);
+        let spec = RohPolicySpec { initial_ceiling: 0.0, ..PhoenixErrority::SPEC };
+        assert!((RohRatchet::new(spec).unwrap().ceiling() - ROH_CEILING_FLOOR).abs() < 1e-6);
+        let spec = RohPolicySpec { initial_ceiling: f32::NAN, ..PhoenixErrority::SPEC };
+        assert!(matches!(RohRatchet::new(spec), Err(RohPolicyError::InvalidInitialCeiling(_))));
+
+        for delta in [0.0, -0.05, f32::NAN, 0.5] {
+            let spec = RohPolicySpec { ceiling_delta: delta, ..PhoenixErrority::SPEC };
+            assert!(matches!(RohRatchet::new(spec), Err(RohPolicyError::InvalidDelta(_))));
+        }
+
+        // A negative delta would otherwise raise the ceiling on every threshold hit
+        let spec = RohPolicySpec { ceiling_delta: -0.05, ..PhoenixErrority::SPEC };
+        let logs: Vec<SafetyEpochLog> = (0..3).map(|i| incident(&format!("E{}", i), 1708357200 + i)).collect();
+        assert!(RohRatchet::replay(spec, &logs).is_err());
+    }
+}
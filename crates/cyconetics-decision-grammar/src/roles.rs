@@ -2,7 +2,9 @@
 //! Each role can only emit allowed decision verbs for its domain.
 
 use crate::types::*;
+use crate::roh_guard::UpgradeClass;
 use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fmt;
 
 /// Context for a decision: host state, evidence, and authorization chain
@@ -30,7 +32,7 @@ impl fmt::Display for DecisionContext {
 }
 
 /// Error types for decision role violations
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RoleViolation {
     UnauthorizedVerb(String),
     RoHViolation(f32, f32),             // current, ceiling
@@ -183,8 +185,7 @@ impl HostSelfDecider for HostSelfImpl {
         if ctx.predicted_post_roh > 0.3 {
             return Err(RoleViolation::RoHViolation(ctx.predicted_post_roh, 0.3));
         }
-        let mut ksr = KsrBand::default();
-        ksr.risk = 0x28; // Higher risk for host override
+        let ksr = KsrBand { risk: 0x28, ..KsrBand::default() }; // Higher risk for host override
         let record = decision_record_from_role(ctx, DecisionKind::Authorize, ksr);
         Ok(record)
     }
@@ -203,8 +204,7 @@ impl HostSelfDecider for HostSelfImpl {
     }
 
     fn escalate_to_neurorights(&self, ctx: &DecisionContext) -> Result<DecisionRecord, RoleViolation> {
-        let mut ksr = KsrBand::default();
-        ksr.risk = 0x2B;
+        let ksr = KsrBand { risk: 0x2B, ..KsrBand::default() };
         let record = decision_record_from_role(ctx, DecisionKind::Escalate, ksr);
         Ok(record)
     }
@@ -226,8 +226,7 @@ impl SafetyDecider for SafetyDaemonImpl {
     }
 
     fn escalate_to_humans(&self, ctx: &DecisionContext) -> Result<DecisionRecord, RoleViolation> {
-        let mut ksr = KsrBand::default();
-        ksr.risk = 0x29;
+        let ksr = KsrBand { risk: 0x29, ..KsrBand::default() };
         let record = decision_record_from_role(ctx, DecisionKind::Escalate, ksr);
         Ok(record)
     }
@@ -237,3 +236,267 @@ impl SafetyDecider for SafetyDaemonImpl {
         Ok(record)
     }
 }
+
+/// Verb marker types; `decision_roles!` implements `Decides<V>` only for declared verbs
+pub mod verbs {
+    use crate::types::DecisionKind;
+
+    pub trait Verb {
+        const NAME: &'static str;
+        fn kind() -> DecisionKind;
+    }
+
+    macro_rules! verb_markers {
+        ($($verb:ident),*) => {
+            $(
+                #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+                pub struct $verb;
+
+                impl Verb for $verb {
+                    const NAME: &'static str = stringify!($verb);
+                    fn kind() -> DecisionKind {
+                        DecisionKind::$verb
+                    }
+                }
+            )*
+        };
+    }
+
+    verb_markers!(Approve, Authorize, Defer, Reject, Escalate);
+
+    /// Table verbs in canonical (DecisionKind declaration) order
+    pub const ALL: [&str; 5] = ["Approve", "Authorize", "Defer", "Reject", "Escalate"];
+}
+
+/// Seal for the role traits; only `decision_roles!` expansions implement it
+#[doc(hidden)]
+pub mod __private {
+    pub trait Sealed<V = ()> {}
+
+    /// Seal parameter for `HostVeto`
+    pub struct Veto;
+}
+
+/// A role within one upgrade class, generated by `decision_roles!`
+pub trait RoleMarker: __private::Sealed {
+    const ROLE: &'static str;
+    const CLASS: UpgradeClass;
+}
+
+/// Implemented only for the (role, verb) pairs declared in a `decision_roles!` table
+pub trait Decides<V: verbs::Verb>: RoleMarker + __private::Sealed<V> {
+    fn decide(&self, ctx: &DecisionContext, _verb: V) -> DecisionRecord {
+        decision_record_from_role(ctx, V::kind(), KsrBand::default())
+    }
+}
+
+/// HostSelf structural veto; `decision_roles!` refuses any class whose HostSelf cannot Reject
+pub trait HostVeto: Decides<verbs::Reject> + __private::Sealed<__private::Veto> {
+    fn veto(&self, ctx: &DecisionContext) -> DecisionRecord {
+        self.decide(ctx, verbs::Reject)
+    }
+}
+
+/// One row of a `decision_roles!` table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RolePermission {
+    pub class: UpgradeClass,
+    pub role: &'static str,
+    pub verbs: &'static [&'static str],
+}
+
+/// Errors when reading a role table from `DecisionGrammarPolicyShard.role_permissions`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoleTableError {
+    UnknownClass(String),
+    UnknownVerb { key: String, verb: String },
+    MissingHostVeto(UpgradeClass),
+}
+
+impl fmt::Display for RoleTableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RoleTableError::UnknownClass(c) => write!(f, "UnknownClass: {}", c),
+            RoleTableError::UnknownVerb { key, verb } => write!(f, "UnknownVerb: {} in {}", verb, key),
+            RoleTableError::MissingHostVeto(c) => {
+                write!(f, "MissingHostVeto: HostSelf cannot Reject in class {}", c.as_str())
+            },
+        }
+    }
+}
+
+impl std::error::Error for RoleTableError {}
+
+/// Role -> verb permissions per upgrade class, in the shape shared by
+/// `decision_roles!` and `DecisionGrammarPolicyShard.role_permissions`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RolePermissionTable {
+    entries: BTreeMap<(UpgradeClass, String), BTreeSet<String>>,
+}
+
+impl RolePermissionTable {
+    pub fn from_static(rows: &[RolePermission]) -> Self {
+        let mut table = Self::default();
+        for row in rows {
+            table
+                .entries
+                .entry((row.class, row.role.to_string()))
+                .or_default()
+                .extend(row.verbs.iter().map(|v| v.to_string()));
+        }
+        table
+    }
+
+    /// Parse shard permissions keyed `CLASS:Role`; a bare `Role` key applies to every class
+    pub fn from_shard_permissions(map: &HashMap<String, Vec<String>>) -> Result<Self, RoleTableError> {
+        let mut table = Self::default();
+        for (key, verbs) in map {
+            let (classes, role) = match key.split_once(':') {
+                Some((class, role)) => {
+                    let class = UpgradeClass::from_name(class)
+                        .ok_or_else(|| RoleTableError::UnknownClass(class.to_string()))?;
+                    (vec![class], role)
+                },
+                None => (UpgradeClass::ALL.to_vec(), key.as_str()),
+            };
+            for verb in verbs {
+                if !verbs::ALL.contains(&verb.as_str()) {
+                    return Err(RoleTableError::UnknownVerb { key: key.clone(), verb: verb.clone() });
+                }
+            }
+            for class in classes {
+                table
+                    .entries
+                    .entry((class, role.to_string()))
+                    .or_default()
+                    .extend(verbs.iter().cloned());
+            }
+        }
+        table.check_host_veto()?;
+        Ok(table)
+    }
+
+    /// Shard form: `CLASS:Role` -> verbs in canonical order
+    pub fn to_shard_permissions(&self) -> HashMap<String, Vec<String>> {
+        self.entries
+            .iter()
+            .map(|((class, role), granted)| {
+                let verbs = verbs::ALL
+                    .iter()
+                    .filter(|v| granted.contains(**v))
+                    .map(|v| v.to_string())
+                    .collect();
+                (format!("{}:{}", class.as_str(), role), verbs)
+            })
+            .collect()
+    }
+
+    pub fn can_decide(&self, role: &str, verb: &DecisionKind, class: UpgradeClass) -> bool {
+        self.entries
+            .get(&(class, role.to_string()))
+            .map(|granted| granted.contains(verb.verb()))
+            .unwrap_or(false)
+    }
+
+    /// Every class in the table must let HostSelf Reject
+    pub fn check_host_veto(&self) -> Result<(), RoleTableError> {
+        let classes: BTreeSet<UpgradeClass> = self.entries.keys().map(|(c, _)| *c).collect();
+        for class in classes {
+            if !self.can_decide("HostSelf", &DecisionKind::Reject, class) {
+                return Err(RoleTableError::MissingHostVeto(class));
+            }
+        }
+        Ok(())
+    }
+
+    /// Human-readable differences against another table (empty when identical)
+    pub fn diff(&self, other: &RolePermissionTable) -> Vec<String> {
+        let keys: BTreeSet<&(UpgradeClass, String)> =
+            self.entries.keys().chain(other.entries.keys()).collect();
+        keys.into_iter()
+            .filter_map(|key| {
+                let (ours, theirs) = (self.entries.get(key), other.entries.get(key));
+                (ours != theirs).then(|| {
+                    format!("{}:{}: {:?} != {:?}", key.0.as_str(), key.1, ours, theirs)
+                })
+            })
+            .collect()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aln_shards::DecisionGrammarPolicyShard;
+
+    crate::decision_roles! {
+        class BCI {
+            HostSelf => { Approve, Authorize, Reject, Escalate, Defer },
+            NeurorightsBoard => { Approve, Reject, Escalate },
+            SafetyDaemon => { Reject, Escalate }
+        }
+
+        class XR {
+            HostSelf => { Authorize, Reject, Escalate },
+            GovSafetyOS => { Approve, Escalate }
+        }
+    }
+
+    fn ctx() -> DecisionContext {
+        DecisionContext {
+            host_did: "did:bostrom:phx-host-001".to_string(),
+            upgrade_id: "UP-1".to_string(),
+            evolution_id: "EVO-1".to_string(),
+            host_state: RoHGuardedHostState::default(),
+            current_roh: 0.10,
+            predicted_post_roh: 0.18,
+            evidence_bundle_hash: None,
+            zone_id: "XR-ZONE-CA-SJO".to_string(),
+            decision_timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_can_role_decide_follows_table() {
+        assert!(can_role_decide(Role::SafetyDaemon, &DecisionKind::Reject, UpgradeClass::BCI));
+        assert!(!can_role_decide(Role::SafetyDaemon, &DecisionKind::Approve, UpgradeClass::BCI));
+        assert!(!can_role_decide(Role::SafetyDaemon, &DecisionKind::Reject, UpgradeClass::XR));
+        assert!(Role::GovSafetyOS.can_decide(&DecisionKind::Approve, UpgradeClass::XR));
+        assert!(!Role::HostSelf.can_decide(&DecisionKind::Approve, UpgradeClass::XR));
+        assert_eq!(Role::NeurorightsBoard.name(), "NeurorightsBoard");
+    }
+
+    #[test]
+    fn test_role_markers_decide_and_veto() {
+        let record = bci::NeurorightsBoard.decide(&ctx(), verbs::Approve);
+        assert!(matches!(record.decision, DecisionKind::Approve));
+        assert_eq!(<xr::GovSafetyOS as RoleMarker>::CLASS, UpgradeClass::XR);
+
+        let veto = xr::HostSelf.veto(&ctx());
+        assert!(matches!(veto.decision, DecisionKind::Reject));
+    }
+
+    #[test]
+    fn test_role_table_round_trips_through_shard() {
+        let mut shard = DecisionGrammarPolicyShard::new();
+        shard.apply_role_table(&role_table());
+        assert!(shard.role_permissions["BCI:HostSelf"].contains(&"Reject".to_string()));
+
+        let restored = shard.role_table().unwrap();
+        assert!(restored.diff(&role_table()).is_empty());
+        assert!(restored.can_decide("GovSafetyOS", &DecisionKind::Approve, UpgradeClass::XR));
+    }
+
+    #[test]
+    fn test_shard_without_host_veto_is_rejected() {
+        assert!(DecisionGrammarPolicyShard::new().role_table().is_ok());
+
+        let mut permissions = HashMap::new();
+        permissions.insert("BCI:HostSelf".to_string(), vec!["Approve".to_string()]);
+        assert_eq!(
+            RolePermissionTable::from_shard_permissions(&permissions),
+            Err(RoleTableError::MissingHostVeto(UpgradeClass::BCI))
+        );
+    }
+}
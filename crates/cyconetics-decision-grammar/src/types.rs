@@ -4,8 +4,10 @@
 use serde::{Serialize, Deserialize};
 use std::fmt;
 
+use crate::canonical::canonical_hash_excluding;
+
 /// Decision verb enum: the allowed actions in the governance grammar
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DecisionKind {
     /// Host or authorized role approves an upgrade; passes all checks
     Approve,
@@ -46,6 +48,45 @@ impl fmt::Display for DecisionKind {
     }
 }
 
+impl DecisionKind {
+    /// Variant name without payload, used as a stable index/query key
+    pub fn verb(&self) -> &'static str {
+        match self {
+            DecisionKind::Approve => "Approve",
+            DecisionKind::Authorize => "Authorize",
+            DecisionKind::Defer => "Defer",
+            DecisionKind::Reject => "Reject",
+            DecisionKind::Escalate => "Escalate",
+            DecisionKind::BroadcastBFC { .. } => "BroadcastBFC",
+            DecisionKind::BroadcastWithModulation { .. } => "BroadcastWithModulation",
+            DecisionKind::BroadcastWithClosedLoop { .. } => "BroadcastWithClosedLoop",
+        }
+    }
+}
+
+/// RoH zones from the README safety model (upper bounds exclusive)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum RoHZone {
+    Safe,      // 0.00 – 0.15, host self-approval
+    Caution,   // 0.15 – 0.25, neuroboard required
+    Critical,  // 0.25 – 0.30, escalate or reject
+    Blocked,   // ≥ 0.30
+}
+
+impl RoHZone {
+    pub fn from_roh(roh: f32) -> Self {
+        if roh < 0.15 {
+            RoHZone::Safe
+        } else if roh < 0.25 {
+            RoHZone::Caution
+        } else if roh < 0.30 {
+            RoHZone::Critical
+        } else {
+            RoHZone::Blocked
+        }
+    }
+}
+
 /// RoHBound<const N: u8> is a zero-sized token that proves RoH < N/100
 /// Only constructors that mathematically verify the bound may return this token.
 /// This is a type-level capability: if you hold RoHBound<30>, you have proven RoH < 0.3.
@@ -132,6 +173,7 @@ impl NeuroConsentRecord {
             audit_hash: String::new(),
             timestamp: chrono::Utc::now().timestamp(),
         }
+        .sealed()
     }
 
     /// For host brain: allow modulation and closed-loop
@@ -145,6 +187,28 @@ impl NeuroConsentRecord {
             audit_hash: String::new(),
             timestamp: chrono::Utc::now().timestamp(),
         }
+        .sealed()
+    }
+
+    /// SHA256 over every field except `audit_hash` (canonical JSON)
+    pub fn compute_audit_hash(&self) -> String {
+        canonical_hash_excluding(self, "audit_hash")
+    }
+
+    /// Recompute and store `audit_hash`
+    pub fn seal(&mut self) -> &str {
+        self.audit_hash = self.compute_audit_hash();
+        &self.audit_hash
+    }
+
+    fn sealed(mut self) -> Self {
+        self.seal();
+        self
+    }
+
+    /// True if the stored audit hash matches the record content
+    pub fn verify(&self) -> bool {
+        !self.audit_hash.is_empty() && self.audit_hash == self.compute_audit_hash()
     }
 }
 
@@ -266,3 +330,21 @@ impl<T> HostValidated<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neuro_consent_record_sealed() {
+        let mut record = NeuroConsentRecord::zero_touch(
+            NeuroEntityType::NonhostNervousSystem,
+            "zone-phoenix-west".to_string(),
+        );
+        assert_eq!(record.audit_hash.len(), 64);
+        assert!(record.verify());
+
+        record.can_modulate = true;
+        assert!(!record.verify());
+    }
+}
@@ -90,17 +90,13 @@ pub fn validate_roh_complies_with_zone(
 pub fn validate_decision_record(record: &DecisionRecord) -> Result<(), String> {
     // 1. Check that pre_roh and post_roh are monotone for Reject/Approve
     match record.decision {
-        DecisionKind::Reject => {
-            // Reject should not increase RoH
-            if record.post_roh > record.pre_roh {
-                return Err("Reject should not increase RoH".to_string());
-            }
+        // Reject should not increase RoH
+        DecisionKind::Reject if record.post_roh > record.pre_roh => {
+            return Err("Reject should not increase RoH".to_string());
         },
-        DecisionKind::Approve | DecisionKind::Authorize => {
-            // Should stay below 0.3
-            if record.post_roh >= 0.3 {
-                return Err(format!("Post-RoH {:.3} >= 0.3 ceiling", record.post_roh));
-            }
+        // Should stay below 0.3
+        DecisionKind::Approve | DecisionKind::Authorize if record.post_roh >= 0.3 => {
+            return Err(format!("Post-RoH {:.3} >= 0.3 ceiling", record.post_roh));
         },
         _ => {}
     }
@@ -185,15 +181,15 @@ pub fn validate_upgrade_metadata(record: &DecisionRecord) -> Result<(), String>
 
 /// Electrocardiogram (ECG) safety check: ensure heart rate is in safe range
 pub fn validate_ecg_safe(heart_rate: f32, systolic_bp: f32, diastolic_bp: f32) -> Result<(), String> {
-    if heart_rate < 40.0 || heart_rate > 140.0 {
+    if !(40.0..=140.0).contains(&heart_rate) {
         return Err(format!("Heart rate {} out of safe range [40, 140] BPM", heart_rate));
     }
 
-    if systolic_bp < 80.0 || systolic_bp > 160.0 {
+    if !(80.0..=160.0).contains(&systolic_bp) {
         return Err(format!("Systolic BP {} out of safe range", systolic_bp));
     }
 
-    if diastolic_bp < 50.0 || diastolic_bp > 100.0 {
+    if !(50.0..=100.0).contains(&diastolic_bp) {
         return Err(format!("Diastolic BP {} out of safe range", diastolic_bp));
     }
 
@@ -213,7 +209,7 @@ pub fn validate_glucose_safe(glucose: f32) -> Result<(), String> {
 
 /// Temperature safety check
 pub fn validate_temperature_safe(core_temp: f32) -> Result<(), String> {
-    if core_temp < 36.0 || core_temp > 39.0 {
+    if !(36.0..=39.0).contains(&core_temp) {
         Err(format!("Core temp {} out of safe range [36.0, 39.0]°C", core_temp))
     } else {
         Ok(())
@@ -0,0 +1,7 @@
+//! Role traits are sealed; only a `decision_roles!` expansion can grant a verb.
+
+#[test]
+fn role_traits_cannot_be_implemented_outside_the_macro() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,15 @@
+use cyconetics_decision_grammar::roh_guard::UpgradeClass;
+use cyconetics_decision_grammar::roles::{verbs, Decides, HostVeto, RoleMarker};
+
+struct Impostor;
+
+impl RoleMarker for Impostor {
+    const ROLE: &'static str = "HostSelf";
+    const CLASS: UpgradeClass = UpgradeClass::BCI;
+}
+
+impl Decides<verbs::Reject> for Impostor {}
+
+impl HostVeto for Impostor {}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use cyconetics_decision_grammar::decision_roles;
+use cyconetics_decision_grammar::roles::{verbs, Decides};
+
+decision_roles! {
+    class BCI {
+        HostSelf => { Approve, Authorize, Reject, Escalate, Defer },
+        SafetyDaemon => { Reject, Escalate }
+    }
+}
+
+// SafetyDaemon may not Approve; granting it by hand must not compile.
+impl Decides<verbs::Approve> for bci::SafetyDaemon {}
+
+fn main() {}
@@ -6,5 +6,24 @@ pub fn bayes_posterior(
 ) -> f32 {
     // returns P(H|D)
     // P(H|D) = P(D|H) P(H) / [P(D|H)P(H) + P(D|~H)P(~H)]
-    unimplemented!()
+    // Data impossible under both hypotheses carries no information: keep the prior.
+    let evidence = likelihood * prior + alt_likelihood * alt_prior;
+    if evidence > 0.0 {
+        likelihood * prior / evidence
+    } else {
+        prior
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_posterior_known_values() {
+        assert!((bayes_posterior(0.5, 0.8, 0.5, 0.2) - 0.8).abs() < 1e-6);
+        // 1% base rate, 90% sensitive, 5% false positives.
+        assert!((bayes_posterior(0.01, 0.9, 0.99, 0.05) - 0.009 / 0.0585).abs() < 1e-6);
+        assert_eq!(bayes_posterior(0.3, 0.0, 0.7, 0.0), 0.3);
+    }
 }
@@ -12,6 +12,7 @@ pub struct DdmState {
     pub decided: bool,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DecisionOutcome {
     Upper,
     Lower,
@@ -26,5 +27,52 @@ pub fn ddm_step(
 ) -> (DdmState, DecisionOutcome) {
     // dx_t = μ dt + σ sqrt(dt) ξ
     // with absorbing bounds at upper/lower
-    unimplemented!()
+    let x = if state.decided {
+        state.x
+    } else {
+        state.x + params.drift * dt + params.noise * dt.sqrt() * gaussian_noise_sample
+    };
+    let outcome = if x >= params.upper {
+        DecisionOutcome::Upper
+    } else if x <= params.lower {
+        DecisionOutcome::Lower
+    } else {
+        DecisionOutcome::Undecided
+    };
+    let decided = outcome != DecisionOutcome::Undecided;
+    (DdmState { x: x.clamp(params.lower, params.upper), decided }, outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: DdmParams = DdmParams { drift: 0.3, noise: 1.0, upper: 1.0, lower: -1.0 };
+
+    #[test]
+    fn test_ddm_step_known_values() {
+        // x = 0.3·0.25 + 1·0.5·ξ
+        let (state, outcome) = ddm_step(DdmState { x: 0.0, decided: false }, PARAMS, 0.25, 0.0);
+        assert!((state.x - 0.075).abs() < 1e-6);
+        assert_eq!(outcome, DecisionOutcome::Undecided);
+
+        let (state, outcome) = ddm_step(DdmState { x: 0.0, decided: false }, PARAMS, 0.25, -0.5);
+        assert!((state.x - -0.175).abs() < 1e-6);
+        assert_eq!(outcome, DecisionOutcome::Undecided);
+    }
+
+    #[test]
+    fn test_ddm_bounds_absorb() {
+        let (state, outcome) = ddm_step(DdmState { x: 0.0, decided: false }, PARAMS, 0.25, 2.0);
+        assert_eq!(outcome, DecisionOutcome::Upper);
+        assert_eq!((state.x, state.decided), (1.0, true));
+
+        // Once decided, later noise cannot move the walker off its bound.
+        let (state, outcome) = ddm_step(state, PARAMS, 0.25, -10.0);
+        assert_eq!(outcome, DecisionOutcome::Upper);
+        assert_eq!(state.x, 1.0);
+
+        let (_, outcome) = ddm_step(DdmState { x: -0.9, decided: false }, PARAMS, 0.25, -1.0);
+        assert_eq!(outcome, DecisionOutcome::Lower);
+    }
 }
@@ -1,6 +1,6 @@
 pub fn entropy_binary(p: f32) -> f32 {
     // H(p) = - p log2 p - (1-p) log2 (1-p)
-    unimplemented!()
+    plog2p(p) + plog2p(1.0 - p)
 }
 
 pub fn mutual_information_2x2(
@@ -10,5 +10,50 @@ pub fn mutual_information_2x2(
     p11: f32,
 ) -> f32 {
     // I(S;R) for a 2x2 joint distribution
-    unimplemented!()
+    // I = Σ p(s,r) log2 [p(s,r) / (p(s) p(r))]
+    let joint = [[p00, p01], [p10, p11]];
+    let ps = [p00 + p01, p10 + p11];
+    let pr = [p00 + p10, p01 + p11];
+    let mut mi = 0.0;
+    for s in 0..2 {
+        for r in 0..2 {
+            let p = joint[s][r];
+            if p > 0.0 {
+                mi += p * (p / (ps[s] * pr[r])).log2();
+            }
+        }
+    }
+    mi
+}
+
+// -p log2 p, with 0 log 0 = 0.
+fn plog2p(p: f32) -> f32 {
+    if p > 0.0 {
+        -p * p.log2()
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_entropy_known_values() {
+        assert!((entropy_binary(0.5) - 1.0).abs() < 1e-6);
+        assert_eq!(entropy_binary(0.0), 0.0);
+        assert_eq!(entropy_binary(1.0), 0.0);
+        // H(0.11) ≈ 0.5 bits
+        assert!((entropy_binary(0.11) - 0.4999).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_mutual_information_known_values() {
+        assert!(mutual_information_2x2(0.25, 0.25, 0.25, 0.25).abs() < 1e-6);
+        assert!((mutual_information_2x2(0.5, 0.0, 0.0, 0.5) - 1.0).abs() < 1e-6);
+        // Binary symmetric channel, flip 0.11, uniform input: 1 - H(0.11)
+        let mi = mutual_information_2x2(0.445, 0.055, 0.055, 0.445);
+        assert!((mi - (1.0 - entropy_binary(0.11))).abs() < 1e-5);
+    }
 }
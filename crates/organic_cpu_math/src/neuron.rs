@@ -20,6 +20,39 @@ pub fn lif_step(
 ) -> (LifState, bool) {
     // implements: τ_m dV/dt = -(V - V_rest) + R_m I(t)
     // returns (new_state, fired)
-    // body to be filled in repository
-    unimplemented!()
+    let dv = (-(state.v - params.v_rest) + params.r_m * input_current) / params.tau_m;
+    let v = state.v + dt * dv;
+    if v >= params.v_thresh {
+        (LifState { v: params.v_reset }, true)
+    } else {
+        (LifState { v }, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: LifParams = LifParams { tau_m: 20.0, v_rest: -65.0, r_m: 10.0, v_thresh: -50.0, v_reset: -65.0 };
+
+    #[test]
+    fn test_lif_step_known_values() {
+        // dV = (0 + 10·2) / 20 = 1 per unit time
+        let (state, fired) = lif_step(LifState { v: -65.0 }, PARAMS, 2.0, 0.1);
+        assert!((state.v - -64.9).abs() < 1e-5);
+        assert!(!fired);
+
+        // Leak only: dV = -(-55 + 65) / 20 = -0.5
+        let (state, fired) = lif_step(LifState { v: -55.0 }, PARAMS, 0.0, 1.0);
+        assert!((state.v - -55.5).abs() < 1e-5);
+        assert!(!fired);
+    }
+
+    #[test]
+    fn test_lif_step_fires_and_resets() {
+        // dV = (-14.9 + 100) / 20 = 4.255 crosses -50
+        let (state, fired) = lif_step(LifState { v: -50.1 }, PARAMS, 10.0, 1.0);
+        assert!(fired);
+        assert_eq!(state.v, PARAMS.v_reset);
+    }
 }
@@ -18,7 +18,7 @@ pub struct WilsonCowanState {
 
 pub fn sigmoid(x: f32, beta: f32, theta: f32) -> f32 {
     // S(x) = 1 / (1 + exp(-β (x - θ)))
-    unimplemented!()
+    1.0 / (1.0 + (-beta * (x - theta)).exp())
 }
 
 pub fn wilson_cowan_step(
@@ -30,5 +30,44 @@ pub fn wilson_cowan_step(
 ) -> WilsonCowanState {
     // τ_E dE/dt = -E + S(w_EE E - w_EI I + P_E)
     // τ_I dI/dt = -I + S(w_IE E - w_II I + P_I)
-    unimplemented!()
+    let (e, i) = (state.e, state.i);
+    let de = (-e + sigmoid(params.w_ee * e - params.w_ei * i + params.p_e, beta, theta)) / params.tau_e;
+    let di = (-i + sigmoid(params.w_ie * e - params.w_ii * i + params.p_i, beta, theta)) / params.tau_i;
+    WilsonCowanState { e: e + dt * de, i: i + dt * di }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sigmoid_known_values() {
+        assert_eq!(sigmoid(0.0, 1.0, 0.0), 0.5);
+        assert_eq!(sigmoid(3.0, 4.0, 3.0), 0.5);
+        assert!((sigmoid(2.0, 1.0, 0.0) - 0.880_797).abs() < 1e-6);
+        assert!((sigmoid(0.0, 2.0, 1.0) - 0.119_203).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_wilson_cowan_step_known_values() {
+        let params = WilsonCowanParams {
+            tau_e: 10.0,
+            tau_i: 20.0,
+            w_ee: 12.0,
+            w_ei: 4.0,
+            w_ie: 13.0,
+            w_ii: 11.0,
+            p_e: 0.0,
+            p_i: 0.0,
+        };
+        // From rest both drives are S(0) = 0.5: dE = 0.5/10, dI = 0.5/20.
+        let next = wilson_cowan_step(WilsonCowanState { e: 0.0, i: 0.0 }, params, 1.0, 0.0, 1.0);
+        assert!((next.e - 0.05).abs() < 1e-6);
+        assert!((next.i - 0.025).abs() < 1e-6);
+
+        // E = 0.1, I = 0: dE = (-0.1 + S(1.2)) / 10, dI = (0 + S(1.3)) / 20.
+        let next = wilson_cowan_step(WilsonCowanState { e: 0.1, i: 0.0 }, params, 1.0, 0.0, 1.0);
+        assert!((next.e - (0.1 + (-0.1 + sigmoid(1.2, 1.0, 0.0)) / 10.0)).abs() < 1e-6);
+        assert!((next.i - sigmoid(1.3, 1.0, 0.0) / 20.0).abs() < 1e-6);
+    }
 }
@@ -3,6 +3,8 @@ pub struct RiskEnvelope {
     pub knowledge_factor: f32,
     pub risk_of_harm: f32,
     pub cybostate_factor: f32,
+    /// ALN shard the envelope was checked against, and that shard's hexstamp
+    pub shard_id: &'static str,
     pub hex_stamp: &'static str,
 }
 
@@ -10,5 +12,24 @@ pub const ORGANIC_CPU_MATH_ENVELOPE: RiskEnvelope = RiskEnvelope {
     knowledge_factor: 0.94,
     risk_of_harm: 0.08,
     cybostate_factor: 0.87,
-    hex_stamp: "0xC9F3A8E1DB4E729F",
+    shard_id: "organic.cpu.math.envelope.v1",
+    hex_stamp: "0x5867fc0b26c36ac96bbedfd1568e53da",
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_envelope_matches_its_shard() {
+        let shard = aln::parse(include_str!("../../../aln/organic.cpu.math.envelope.v1.aln")).unwrap();
+        assert_eq!(aln::hexstamp::verify(&shard), Ok(()));
+        assert_eq!(aln::hexstamp::compute(&shard), ORGANIC_CPU_MATH_ENVELOPE.hex_stamp);
+        assert_eq!(shard.get("id").and_then(|n| n.as_str()), Some(ORGANIC_CPU_MATH_ENVELOPE.shard_id));
+
+        let factor = |key: &str| shard.path(key).and_then(|n| n.as_f64()).unwrap() as f32;
+        assert_eq!(factor("factors.knowledge_factor"), ORGANIC_CPU_MATH_ENVELOPE.knowledge_factor);
+        assert_eq!(factor("factors.risk_of_harm"), ORGANIC_CPU_MATH_ENVELOPE.risk_of_harm);
+        assert_eq!(factor("factors.cybostate_factor"), ORGANIC_CPU_MATH_ENVELOPE.cybostate_factor);
+    }
+}
@@ -0,0 +1,7 @@
+#![forbid(unsafe_code)]
+
+pub mod pipeline;
+pub mod router;
+
+pub use pipeline::{run_organic_cpu_sim, OrganicCpuConfig, OrganicCpuResult};
+pub use organic_cpu_math::{DdmParams, DecisionOutcome, LifParams, WilsonCowanParams};
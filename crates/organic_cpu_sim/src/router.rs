@@ -1,5 +1,4 @@
-use neurorights_core::{NeurorightsBound, NeurorightsEnvelope};
-use neurorights_firewall::PromptEnvelope;
+use neurorights_firewall::{NeurorightsBound, NeurorightsEnvelope, PromptEnvelope};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -58,7 +57,7 @@ pub fn handle_organic_cpu_sim(
     // Enforced at type level: this can only be called with a neurorights-verified envelope. [file:3]
 
     // Read args from PromptEnvelope (retrieval-only)
-    let args: &Value = &bound.payload.args;
+    let args: &Value = &bound.payload().args;
     let sim_args: SimArgs = serde_json::from_value(args.clone())
         .map_err(|e| format!("bad args: {e}"))?;
 
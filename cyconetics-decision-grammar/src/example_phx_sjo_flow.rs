@@ -65,16 +65,15 @@ fn predict_roh_for_task(
 #[scheduler_policy(host = "Phoenix", zone = "XR-ZONE-CA-SJO")]
 pub struct PhoenixToSanJollaPolicy;
 
-/// Static evolution graph – build will fail if unsafe paths are added once the
-/// macro is fully extended.
+/// Static evolution graph – build fails if an unguarded path reaches RoH 0.30.
 evolutiongraph! {
     evolution PhoenixSanJollaBCIPath {
         node Proposed;
         node SafetyReviewed;
         node Approved;
 
-        edge Proposed      -> SafetyReviewed { kind: Escalated, roh_delta: 0.00 }
-        edge SafetyReviewed-> Approved       { kind: Authorized, roh_delta: 0.05 }
+        edge Proposed      -> SafetyReviewed { kind: Escalate,  roh_delta: 0.00 }
+        edge SafetyReviewed-> Approved       { kind: Authorize, roh_delta: 0.05 }
     }
 }
 
@@ -1,51 +1,267 @@
-use std::{env, fs, path::PathBuf};
+use std::{env, fmt::Write as _, fs, path::PathBuf};
+
+use aln::{AlnRegistry, Node};
+
+// Sections of the envelope shard holding boolean rights flags.
+const FLAG_SECTIONS: [&str; 2] = ["rights", "governance"];
 
 fn main() {
     let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set"));
 
-    let config = fs::read_to_string("neurorights.toml")
-        .expect("neurorights.toml missing in neurorights-core");
-    let parsed: toml::Value = toml::from_str(&config).expect("invalid neurorights.toml");
+    let config = fs::read_to_string("neurorights.aln")
+        .expect("neurorights.aln missing in neurorights-core");
+    let parsed = match aln::parse(&config) {
+        Ok(doc) => doc,
+        Err(e) => panic!("invalid neurorights.aln:{e}"),
+    };
 
     let policy = parsed
         .get("policy")
-        .expect("[policy] missing in neurorights.toml");
+        .expect("policy block missing in neurorights.aln");
 
-    let id = policy.get("id").and_then(|v| v.as_str()).expect("policy.id missing");
+    let id = policy.get("id").and_then(|v| v.as_text()).expect("policy.id missing");
     let version = policy
         .get("version")
-        .and_then(|v| v.as_str())
+        .and_then(|v| v.as_text())
         .expect("policy.version missing");
     let anchor = policy
         .get("anchor")
-        .and_then(|v| v.as_str())
+        .and_then(|v| v.as_text())
         .expect("policy.anchor missing");
+    let baseline = parsed
+        .get("baseline")
+        .expect("baseline block missing in neurorights.aln");
+
+    // Resolve the envelope shard itself from the local shard directory.
+    let aln_dir = env::var("ALN_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("../aln"));
+    let shard_path = aln_dir.join(format!("{id}.aln"));
+    let source = shard_path.display().to_string();
+    let mut registry = AlnRegistry::new();
+    if let Err(e) = registry.load_file(&shard_path) {
+        panic!("failed to load neurorights shard {id}: {e}");
+    }
+    if let Err(e) = registry.verify_hexstamps() {
+        panic!("{source}: {e}; recompute the hexstamp after editing the shard");
+    }
+    let header = registry
+        .header(id)
+        .unwrap_or_else(|| panic!("{source}: shard id is not {id}"));
+    let shard = registry.get(id).expect("header implies document");
+
+    let text = |path: &str| shard.path(path).and_then(Node::as_text);
+    if header.version.as_deref() != Some(version) {
+        panic!(
+            "{source}: version {:?} does not match pinned {version}",
+            header.version
+        );
+    }
+    if text("anchor") != Some(anchor) {
+        panic!(
+            "{source}: anchor {:?} does not match pinned {anchor}",
+            text("anchor")
+        );
+    }
 
-    // Here you can additionally fetch/validate the remote ALN shard
-    // and derive numeric invariants (e.g., MAX_SCORE) if desired.
+    let weakened = check_baseline(&source, shard, baseline);
+    if !weakened.is_empty() {
+        panic!(
+            "neurorights shard weakens the pinned baseline:\n  {}",
+            weakened.join("\n  ")
+        );
+    }
 
-    let gen = format!(
+    let mut flags = Vec::new();
+    for section in FLAG_SECTIONS {
+        for (key, node) in shard.get(section).and_then(Node::as_map).unwrap_or(&[]) {
+            let value = node.as_bool().unwrap_or_else(|| {
+                panic!(
+                    "{source}:{}:{}: `{section}.{}` must be true or false",
+                    node.span.line, node.span.column, key.name
+                )
+            });
+            flags.push((section, key.name.as_str(), value));
+        }
+    }
+    let max_score = shard
+        .path("limits.max_inner_state_score")
+        .expect("limits.max_inner_state_score missing");
+    let max_score = max_score
+        .as_f64()
+        .filter(|v| *v >= 0.0 && v.fract() == 0.0)
+        .unwrap_or_else(|| {
+            panic!(
+                "{source}:{}:{}: max_inner_state_score must be a non-negative integer",
+                max_score.span.line, max_score.span.column
+            )
+        }) as u32;
+    let forbidden: Vec<&str> = shard
+        .get("forbidden_modules")
+        .and_then(Node::as_list)
+        .unwrap_or(&[])
+        .iter()
+        .filter_map(Node::as_text)
+        .collect();
+
+    let mut gen = format!(
         r#"
-        // @generated by neurorights-core build.rs
+        // @generated by neurorights-core build.rs from {id}
 
         pub const NEURORIGHTS_POLICY_ID: &str = "{id}";
         pub const NEURORIGHTS_POLICY_VERSION: &str = "{version}";
         pub const NEURORIGHTS_POLICY_ANCHOR: &str = "{anchor}";
+        pub const NEURORIGHTS_POLICY_HEXSTAMP: &str = "{hexstamp}";
+"#,
+        hexstamp = header.hexstamp.as_deref().unwrap_or_default(),
+    );
+    for (section, key, value) in &flags {
+        writeln!(
+            gen,
+            "        pub const {}: bool = {value};",
+            const_name(section, key)
+        )
+        .unwrap();
+    }
+    let table: Vec<String> = flags
+        .iter()
+        .map(|(s, k, v)| format!("(\"{s}.{k}\", {v})"))
+        .collect();
+    writeln!(
+        gen,
+        "        pub const NEURORIGHTS_FLAGS: &[(&str, bool)] = &[{}];",
+        table.join(", ")
+    )
+    .unwrap();
+    writeln!(
+        gen,
+        "        pub const FORBIDDEN_MODULES: &[&str] = &{forbidden:?};"
+    )
+    .unwrap();
 
-        // Example compile-time invariant hooks; wire to ALN shard values:
-        pub const MAX_INNER_STATE_SCORE: u32 = 0;
-        pub const ALLOW_NEUROCOERCION: bool = false;
+    let noneurocoercion = flags
+        .iter()
+        .any(|(s, k, v)| *s == "rights" && *k == "noneurocoercion" && *v);
+    writeln!(
+        gen,
+        r#"
+        // Invariants derived from the shard.
+        pub const MAX_INNER_STATE_SCORE: u32 = {max_score};
+        pub const ALLOW_NEUROCOERCION: bool = {allow};
 
-        // Static sanity checks; expand with real invariants as the ALN shard matures.
+        // Pinned baseline from neurorights.aln; the shard may only tighten these.
         const _: () = {{
-            // no inner-state scoring allowed
-            assert!(MAX_INNER_STATE_SCORE == 0);
-            assert!(!ALLOW_NEUROCOERCION);
-        }};
-        "#
-    );
+            assert!(!ALLOW_NEUROCOERCION);"#,
+        allow = !noneurocoercion,
+    )
+    .unwrap();
+    match baseline
+        .path("limits.max_inner_state_score")
+        .and_then(Node::as_f64)
+    {
+        // no inner-state scoring allowed
+        Some(0.0) => {
+            gen.push_str("            assert!(MAX_INNER_STATE_SCORE == 0);\n")
+        }
+        Some(limit) => writeln!(
+            gen,
+            "            assert!(MAX_INNER_STATE_SCORE <= {limit});"
+        )
+        .unwrap(),
+        None => {}
+    }
+    for section in FLAG_SECTIONS {
+        for (key, node) in baseline.get(section).and_then(Node::as_map).unwrap_or(&[]) {
+            if node.as_bool() == Some(true) {
+                writeln!(
+                    gen,
+                    "            assert!({});",
+                    const_name(section, &key.name)
+                )
+                .unwrap();
+            }
+        }
+    }
+    gen.push_str("        };\n");
 
     fs::write(out_dir.join("generated_neurorights.rs"), gen)
         .expect("failed to write generated_neurorights.rs");
-    println!("cargo:rerun-if-changed=neurorights.toml");
+    println!("cargo:rerun-if-changed=neurorights.aln");
+    println!("cargo:rerun-if-changed={source}");
+    println!("cargo:rerun-if-env-changed=ALN_DIR");
+}
+
+/// `rights.noNeuralInputsForGovernance` -> `RIGHTS_NO_NEURAL_INPUTS_FOR_GOVERNANCE`
+fn const_name(section: &str, key: &str) -> String {
+    let mut name = section.to_ascii_uppercase();
+    name.push('_');
+    for c in key.chars() {
+        if c.is_ascii_uppercase() {
+            name.push('_');
+        }
+        name.push(c.to_ascii_uppercase());
+    }
+    name
+}
+
+/// Every way the shard is weaker than the baseline, as `path:line:col: message`
+fn check_baseline(source: &str, shard: &aln::Document, baseline: &Node) -> Vec<String> {
+    let mut weakened = Vec::new();
+    let at = |node: &Node| format!("{source}:{}:{}", node.span.line, node.span.column);
+
+    for section in FLAG_SECTIONS {
+        for (key, required) in baseline.get(section).and_then(Node::as_map).unwrap_or(&[]) {
+            if required.as_bool() != Some(true) {
+                continue;
+            }
+            let path = format!("{section}.{}", key.name);
+            match shard.path(&path) {
+                Some(node) if node.as_bool() == Some(true) => {}
+                Some(node) => weakened.push(format!(
+                    "{}: `{path}` is {}, baseline requires true",
+                    at(node),
+                    node.as_text().unwrap_or(node.type_name())
+                )),
+                None => weakened.push(format!(
+                    "{source}: `{path}` missing, baseline requires true"
+                )),
+            }
+        }
+    }
+
+    for (key, limit) in baseline.get("limits").and_then(Node::as_map).unwrap_or(&[]) {
+        let Some(limit) = limit.as_f64() else {
+            continue;
+        };
+        let path = format!("limits.{}", key.name);
+        match shard.path(&path) {
+            Some(node) if node.as_f64().is_some_and(|v| v <= limit) => {}
+            Some(node) => weakened.push(format!("{}: `{path}` exceeds baseline {limit}", at(node))),
+            None => weakened.push(format!(
+                "{source}: `{path}` missing, baseline requires <= {limit}"
+            )),
+        }
+    }
+
+    let shard_modules = shard.get("forbidden_modules");
+    let forbidden: Vec<&str> = shard_modules
+        .and_then(Node::as_list)
+        .unwrap_or(&[])
+        .iter()
+        .filter_map(Node::as_text)
+        .collect();
+    for module in baseline
+        .get("forbidden_modules")
+        .and_then(Node::as_list)
+        .unwrap_or(&[])
+    {
+        let Some(module) = module.as_text() else {
+            continue;
+        };
+        if !forbidden.contains(&module) {
+            let place = shard_modules.map(at).unwrap_or_else(|| source.to_string());
+            weakened.push(format!("{place}: forbidden module `{module}` was dropped"));
+        }
+    }
+    weakened
 }
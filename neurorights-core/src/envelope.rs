@@ -5,10 +5,13 @@ pub struct NeurorightsEnvelope {
     pub policy_id: &'static str,
     pub policy_version: &'static str,
     pub policy_anchor: &'static str,
+    pub policy_hexstamp: &'static str,
 
     // Invariants mirrored from ALN shard.
     pub max_inner_state_score: u32,
     pub allow_neurocoercion: bool,
+    pub no_neural_inputs_for_governance: bool,
+    pub forbidden_modules: &'static [&'static str],
 }
 
 impl NeurorightsEnvelope {
@@ -17,10 +20,18 @@ impl NeurorightsEnvelope {
             policy_id: crate::NEURORIGHTS_POLICY_ID,
             policy_version: crate::NEURORIGHTS_POLICY_VERSION,
             policy_anchor: crate::NEURORIGHTS_POLICY_ANCHOR,
+            policy_hexstamp: crate::NEURORIGHTS_POLICY_HEXSTAMP,
             max_inner_state_score: crate::MAX_INNER_STATE_SCORE,
             allow_neurocoercion: crate::ALLOW_NEUROCOERCION,
+            no_neural_inputs_for_governance: crate::GOVERNANCE_NO_NEURAL_INPUTS_FOR_GOVERNANCE,
+            forbidden_modules: crate::FORBIDDEN_MODULES,
         }
     }
+
+    /// Whether a module name is on the shard's forbidden list
+    pub fn forbids_module(&self, module: &str) -> bool {
+        self.forbidden_modules.contains(&module)
+    }
 }
 
 // Seal the envelope type.
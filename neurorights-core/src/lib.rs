@@ -0,0 +1,27 @@
+#![forbid(unsafe_code)]
+
+// Neurorights policy surface.
+mod envelope;
+mod profile;
+mod sealed;
+mod version;
+
+pub use envelope::NeurorightsEnvelope;
+pub use profile::NeurorightsProfile;
+pub use sealed::{NeurorightsMarker, NeurorightsMarkerSealed};
+pub use version::{
+    ALLOW_NEUROCOERCION,
+    FORBIDDEN_MODULES,
+    GOVERNANCE_NO_NEURAL_INPUTS_FOR_GOVERNANCE,
+    MAX_INNER_STATE_SCORE,
+    NEURORIGHTS_FLAGS,
+    NEURORIGHTS_POLICY_ANCHOR,
+    NEURORIGHTS_POLICY_HEXSTAMP,
+    NEURORIGHTS_POLICY_ID,
+    NEURORIGHTS_POLICY_VERSION,
+    RIGHTS_AUGMENTATIONCONTINUITY,
+    RIGHTS_MENTALPRIVACY,
+    RIGHTS_NONEUROCOERCION,
+    RIGHTS_NOSCOREFROMINNERSTATE,
+};
+
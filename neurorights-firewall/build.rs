@@ -0,0 +1,40 @@
+use std::{env, fs, path::PathBuf};
+
+use aln::AlnRegistry;
+
+// Shard whose stamp `EvidenceStamp::default_hex` carries.
+const GOVERNANCE_SHARD: &str = "governance.totem.superposition.v1";
+
+fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set"));
+    let aln_dir = env::var("ALN_DIR").map(PathBuf::from).unwrap_or_else(|_| PathBuf::from("../aln"));
+    let path = aln_dir.join(format!("{GOVERNANCE_SHARD}.aln"));
+
+    let mut registry = AlnRegistry::new();
+    if let Err(e) = registry.load_file(&path) {
+        panic!("failed to load {GOVERNANCE_SHARD}: {e}");
+    }
+    if let Err(e) = registry.verify_hexstamps() {
+        panic!("{}: {e}; recompute the hexstamp after editing the shard", path.display());
+    }
+    let header = registry.header(GOVERNANCE_SHARD).expect("loaded above");
+
+    let gen = format!(
+        r#"
+        // @generated by neurorights-firewall build.rs
+
+        pub const GOVERNANCE_SHARD: aln::CompiledShard = aln::CompiledShard {{
+            id: "{id}",
+            version: "{version}",
+            hexstamp: "{hexstamp}",
+        }};
+        "#,
+        id = header.id,
+        version = header.version.as_deref().unwrap_or_default(),
+        hexstamp = header.hexstamp.as_deref().unwrap_or_default(),
+    );
+
+    fs::write(out_dir.join("generated_shards.rs"), gen).expect("failed to write generated_shards.rs");
+    println!("cargo:rerun-if-changed={}", path.display());
+    println!("cargo:rerun-if-env-changed=ALN_DIR");
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+use crate::router::PromptEnvelope;
+use crate::versions::EnvelopeMismatch;
+
+/// Risk model the router runs over a prompt; `wrap_prompt_assessed` calls it on the exact
+/// payload being bound, so the score cannot be supplied separately from the request.
+pub trait RohAssessor {
+    /// Risk-of-harm in `[0, 1]` for `env`.
+    fn assess_roh(&self, env: &PromptEnvelope) -> f64;
+}
+
+/// Why `wrap_prompt_assessed` refused to bind a prompt.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AssessError {
+    Envelope(EnvelopeMismatch),
+    /// The assessor returned a non-finite score or one outside `[0, 1]`.
+    RohOutOfRange { roh: f64 },
+}
+
+impl From<EnvelopeMismatch> for AssessError {
+    fn from(e: EnvelopeMismatch) -> Self {
+        AssessError::Envelope(e)
+    }
+}
+
+impl fmt::Display for AssessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssessError::Envelope(e) => e.fmt(f),
+            AssessError::RohOutOfRange { roh } => write!(f, "assessed roh {} is outside [0, 1]", roh),
+        }
+    }
+}
+
+impl std::error::Error for AssessError {}
@@ -1,3 +1,11 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cyconetics_decision_grammar::canonical::canonical_bytes;
+use neurorights_core::NeurorightsProfile;
+
+use crate::versions::EnvelopeMismatch;
+
 #[derive(Clone, Debug)]
 pub struct Authorship {
     pub user_did: String,
@@ -7,6 +15,9 @@ pub struct Authorship {
     pub neurorights_version: String,
 }
 
+// Governance shard compiled into this crate; its hexstamp is verified by build.rs.
+include!(concat!(env!("OUT_DIR"), "/generated_shards.rs"));
+
 #[derive(Clone, Debug)]
 pub struct EvidenceStamp {
     pub hex_stamp: String,
@@ -15,7 +26,175 @@ pub struct EvidenceStamp {
 impl EvidenceStamp {
     pub fn default_hex() -> Self {
         Self {
-            hex_stamp: "0xaf31c8e924f5703d8c4f2a19e5d44cb7".to_string(),
+            hex_stamp: GOVERNANCE_SHARD.hexstamp.to_string(),
+        }
+    }
+}
+
+/// Signs receipt bytes on behalf of the firewall operator, e.g. with its DID key.
+pub trait ReceiptSigner: Send + Sync {
+    fn sign(&self, data: &[u8]) -> String;
+}
+
+/// Where rejection receipts are recorded for the citizen.
+pub trait AuditSink: Send + Sync {
+    fn record(&self, receipt: &RejectionReceipt) -> Result<(), AuditError>;
+}
+
+#[derive(Debug)]
+pub enum AuditError {
+    Io(std::io::Error),
+}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditError::Io(e) => write!(f, "audit sink: {}", e),
         }
     }
 }
+
+impl std::error::Error for AuditError {}
+
+/// Why `try_wrap_prompt` refused a prompt.
+#[derive(Debug)]
+pub enum WrapRejection {
+    /// Signed receipt, recorded by the sink.
+    Rejected(Box<RejectionReceipt>),
+    /// Signed receipt the sink failed to record; the citizen has no record of the denial
+    /// unless the caller delivers `receipt` some other way.
+    Unrecorded { receipt: Box<RejectionReceipt>, error: AuditError },
+}
+
+impl WrapRejection {
+    pub fn receipt(&self) -> &RejectionReceipt {
+        match self {
+            WrapRejection::Rejected(receipt) | WrapRejection::Unrecorded { receipt, .. } => receipt,
+        }
+    }
+}
+
+impl fmt::Display for WrapRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WrapRejection::Rejected(receipt) => write!(f, "prompt {} rejected: {}", receipt.trace_id, receipt.reason),
+            WrapRejection::Unrecorded { receipt, error } => write!(
+                f,
+                "prompt {} rejected ({}) but the receipt was not recorded: {}",
+                receipt.trace_id, receipt.reason, error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WrapRejection {}
+
+/// Signed record of a prompt the firewall refused to bind.
+#[derive(Clone, Debug)]
+pub struct RejectionReceipt {
+    pub trace_id: String,
+    pub reason: EnvelopeMismatch,
+    pub expected: NeurorightsProfile,
+    pub presented: NeurorightsProfile,
+    pub timestamp_ms: u64,
+    pub authorship: Authorship,
+    pub evidence: EvidenceStamp,
+    pub signature: String,
+}
+
+impl RejectionReceipt {
+    /// Everything but the signature, as RFC 8785 canonical JSON.
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        canonical_bytes(&self.unsigned_json()).expect("a JSON value always serializes")
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut value = self.unsigned_json();
+        value["signature"] = self.signature.clone().into();
+        value
+    }
+
+    fn unsigned_json(&self) -> serde_json::Value {
+        let profile = |p: &NeurorightsProfile| {
+            serde_json::json!({ "id": p.id, "version": p.version, "anchor": p.anchor })
+        };
+        serde_json::json!({
+            "trace_id": self.trace_id,
+            "reason": self.reason.to_string(),
+            "expected": profile(&self.expected),
+            "presented": profile(&self.presented),
+            "timestamp_ms": self.timestamp_ms,
+            "authorship": {
+                "user_did": self.authorship.user_did,
+                "aln": self.authorship.aln,
+                "bostrom_address": self.authorship.bostrom_address,
+                "eibon_label": self.authorship.eibon_label,
+                "neurorights_version": self.authorship.neurorights_version,
+            },
+            "hex_stamp": self.evidence.hex_stamp,
+        })
+    }
+}
+
+/// Who signs rejection receipts and where they are written.
+pub struct RejectionAudit {
+    pub authorship: Authorship,
+    pub evidence: EvidenceStamp,
+    pub signer: Box<dyn ReceiptSigner>,
+    pub sink: Box<dyn AuditSink>,
+}
+
+impl RejectionAudit {
+    /// Sign a receipt for `presented` and write it to the sink.
+    pub fn reject(
+        &self,
+        trace_id: &str,
+        reason: EnvelopeMismatch,
+        presented: NeurorightsProfile,
+    ) -> WrapRejection {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        let mut receipt = RejectionReceipt {
+            trace_id: trace_id.to_string(),
+            reason,
+            expected: NeurorightsProfile::current(),
+            presented,
+            timestamp_ms,
+            authorship: self.authorship.clone(),
+            evidence: self.evidence.clone(),
+            signature: String::new(),
+        };
+        receipt.signature = self.signer.sign(&receipt.signing_bytes());
+        match self.sink.record(&receipt) {
+            Ok(()) => WrapRejection::Rejected(Box::new(receipt)),
+            Err(error) => WrapRejection::Unrecorded { receipt: Box::new(receipt), error },
+        }
+    }
+}
+
+/// Append-only file sink, one signed receipt as JSON per line.
+pub struct FileAuditSink {
+    path: std::path::PathBuf,
+}
+
+impl FileAuditSink {
+    pub fn new<P: Into<std::path::PathBuf>>(path: P) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl AuditSink for FileAuditSink {
+    fn record(&self, receipt: &RejectionReceipt) -> Result<(), AuditError> {
+        use std::fs::OpenOptions;
+        use std::io::Write;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(AuditError::Io)?;
+        writeln!(file, "{}", receipt.to_json()).map_err(AuditError::Io)
+    }
+}
@@ -0,0 +1,52 @@
+use neurorights_core::NeurorightsMarker;
+
+/// Proof that a payload went through the firewall. The private field keeps other crates
+/// from building one, and only this crate can mint it.
+#[derive(Debug)]
+pub struct BindCapability {
+    _sealed: (),
+}
+
+impl BindCapability {
+    pub(crate) fn mint() -> Self {
+        Self { _sealed: () }
+    }
+}
+
+/// Generic gate that binds a payload `P` to a neurorights envelope `N`.
+#[derive(Clone, Debug)]
+pub struct NeurorightsBound<P, N: NeurorightsMarker> {
+    payload: P,
+    envelope: N,
+    roh: Option<f64>,
+}
+
+impl<P, N: NeurorightsMarker> NeurorightsBound<P, N> {
+    /// Spends a `BindCapability`; outside this crate use `wrap_prompt` instead.
+    pub fn new(payload: P, envelope: N, _capability: BindCapability) -> Self {
+        Self { payload, envelope, roh: None }
+    }
+
+    pub(crate) fn with_roh(mut self, roh: f64) -> Self {
+        self.roh = Some(roh);
+        self
+    }
+
+    pub fn payload(&self) -> &P {
+        &self.payload
+    }
+
+    pub fn into_inner(self) -> P {
+        self.payload
+    }
+
+    pub fn neurorights_envelope(&self) -> &N {
+        &self.envelope
+    }
+
+    /// Risk-of-harm the router scored for this request when it was bound, if any.
+    /// Never read from the payload, so a caller cannot declare its own.
+    pub fn roh(&self) -> Option<f64> {
+        self.roh
+    }
+}
@@ -1,8 +1,11 @@
 use neurorights_core::{
+    NeurorightsEnvelope,
     NEURORIGHTS_POLICY_ID,
     NEURORIGHTS_POLICY_VERSION,
 };
 
+use crate::handler::{envelope_forbids, registered_handlers};
+
 pub fn expected_policy_id() -> &'static str {
     NEURORIGHTS_POLICY_ID
 }
@@ -10,3 +13,13 @@ pub fn expected_policy_id() -> &'static str {
 pub fn expected_policy_version() -> &'static str {
     NEURORIGHTS_POLICY_VERSION
 }
+
+/// Handlers whose `forbid` list the compiled envelope does not honour; they would reject
+/// every request, so CI should fail on any entry here.
+pub fn unenforceable_handlers() -> Vec<(&'static str, &'static str)> {
+    let envelope = NeurorightsEnvelope::compiled();
+    registered_handlers()
+        .flat_map(|policy| policy.forbid.iter().map(move |practice| (policy.handler, *practice)))
+        .filter(|(_, practice)| !envelope_forbids(&envelope, practice))
+        .collect()
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use neurorights_core::NeurorightsEnvelope;
+
+use crate::bound::NeurorightsBound;
+
+/// Policy declared by `#[neurorights_handler(...)]`; every annotated handler registers one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HandlerPolicy {
+    /// `module::path::fn_name` of the handler
+    pub handler: &'static str,
+    pub max_roh: Option<f64>,
+    /// Practices the envelope must forbid before the handler runs
+    pub forbid: &'static [&'static str],
+}
+
+inventory::collect!(HandlerPolicy);
+
+/// Every annotated handler linked into this binary, for CI audits.
+pub fn registered_handlers() -> impl Iterator<Item = &'static HandlerPolicy> {
+    inventory::iter::<HandlerPolicy>.into_iter()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum HandlerViolation {
+    RohMissing { handler: &'static str },
+    RohExceeded { handler: &'static str, roh: f64, max_roh: f64 },
+    PracticeAllowed { handler: &'static str, practice: &'static str },
+}
+
+impl fmt::Display for HandlerViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandlerViolation::RohMissing { handler } => {
+                write!(f, "{} declares max_roh but the request was bound without a roh score", handler)
+            }
+            HandlerViolation::RohExceeded { handler, roh, max_roh } => {
+                write!(f, "{}: roh {} exceeds declared max_roh {}", handler, roh, max_roh)
+            }
+            HandlerViolation::PracticeAllowed { handler, practice } => {
+                write!(f, "{} forbids {} but the neurorights envelope allows it", handler, practice)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HandlerViolation {}
+
+/// Whether the envelope rules out `practice`: a named invariant or a forbidden module.
+pub fn envelope_forbids(envelope: &NeurorightsEnvelope, practice: &str) -> bool {
+    match practice {
+        "inner_state_scoring" => envelope.max_inner_state_score == 0,
+        "neurocoercion" => !envelope.allow_neurocoercion,
+        "neural_governance_inputs" => envelope.no_neural_inputs_for_governance,
+        module => envelope.forbids_module(module),
+    }
+}
+
+/// Run by the generated handler prologue before the handler body.
+pub fn enforce<P>(
+    policy: &HandlerPolicy,
+    bound: &NeurorightsBound<P, NeurorightsEnvelope>,
+) -> Result<(), HandlerViolation> {
+    let handler = policy.handler;
+    for practice in policy.forbid {
+        if !envelope_forbids(bound.neurorights_envelope(), practice) {
+            return Err(HandlerViolation::PracticeAllowed { handler, practice });
+        }
+    }
+    if let Some(max_roh) = policy.max_roh {
+        // Fail closed: a handler with a ceiling never runs on an unscored request.
+        match bound.roh() {
+            None => return Err(HandlerViolation::RohMissing { handler }),
+            Some(roh) if roh.is_nan() || roh > max_roh => {
+                return Err(HandlerViolation::RohExceeded { handler, roh, max_roh })
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
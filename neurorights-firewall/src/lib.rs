@@ -1,8 +1,26 @@
 #![forbid(unsafe_code)]
 
+pub mod bound;
 pub mod router;
+pub mod assess;
 pub mod audit;
 pub mod ci_guards;
+pub mod handler;
+pub mod versions;
 
-pub use router::wrap_prompt;
-pub use audit::{Authorship, EvidenceStamp};
+pub use bound::{BindCapability, NeurorightsBound};
+pub use assess::{AssessError, RohAssessor};
+pub use router::{try_wrap_prompt, wrap_prompt, wrap_prompt_assessed, wrap_prompt_with, PromptEnvelope};
+pub use handler::{HandlerPolicy, HandlerViolation};
+pub use versions::{EnvelopeMismatch, EnvelopeRegistry, EnvelopeUpgrade};
+pub use audit::{
+    AuditError, AuditSink, Authorship, EvidenceStamp, FileAuditSink, ReceiptSigner, RejectionAudit,
+    RejectionReceipt, WrapRejection,
+};
+
+pub use neurorights_core::{NeurorightsEnvelope, NeurorightsProfile};
+pub use neurorights_macros::neurorights_handler;
+
+// Used by `#[neurorights_handler]` expansions.
+#[doc(hidden)]
+pub use inventory;
@@ -1,4 +1,9 @@
-use neurorights_core::{NeurorightsBound, NeurorightsEnvelope, NeurorightsProfile};
+use neurorights_core::{NeurorightsEnvelope, NeurorightsProfile};
+
+use crate::assess::{AssessError, RohAssessor};
+use crate::audit::{RejectionAudit, WrapRejection};
+use crate::bound::{BindCapability, NeurorightsBound};
+use crate::versions::{EnvelopeMismatch, EnvelopeRegistry};
 
 #[derive(Clone, Debug)]
 pub struct PromptEnvelope {
@@ -14,20 +19,163 @@ pub struct PromptEnvelope {
 
 /// Construct a bound envelope from a raw `PromptEnvelope`.
 /// This is the only allowed entry path for router handlers.
-pub fn wrap_prompt(env: PromptEnvelope) -> NeurorightsBound<PromptEnvelope, NeurorightsEnvelope> {
-    let profile = &env.neurorights_profile;
-
-    assert_eq!(
-        profile.id,
-        neurorights_core::NEURORIGHTS_POLICY_ID,
-        "PromptEnvelope neurorights_profile.id mismatch"
-    );
-    assert_eq!(
-        profile.version,
-        neurorights_core::NEURORIGHTS_POLICY_VERSION,
-        "PromptEnvelope neurorights_profile.version mismatch"
-    );
+pub fn wrap_prompt(
+    env: PromptEnvelope,
+) -> Result<NeurorightsBound<PromptEnvelope, NeurorightsEnvelope>, EnvelopeMismatch> {
+    wrap_prompt_with(&EnvelopeRegistry::compiled(), env)
+}
 
+/// As `wrap_prompt`, accepting any version the registry can upgrade to the compiled policy.
+pub fn wrap_prompt_with(
+    registry: &EnvelopeRegistry,
+    env: PromptEnvelope,
+) -> Result<NeurorightsBound<PromptEnvelope, NeurorightsEnvelope>, EnvelopeMismatch> {
+    let env = registry.migrate(env)?;
     let envelope = NeurorightsEnvelope::compiled();
-    NeurorightsBound::new(env, envelope)
+    Ok(NeurorightsBound::new(env, envelope, BindCapability::mint()))
+}
+
+/// As `wrap_prompt_with`, attaching the risk-of-harm `assessor` computes for the migrated
+/// payload; `#[neurorights_handler(max_roh = ..)]` checks this value.
+pub fn wrap_prompt_assessed<A: RohAssessor + ?Sized>(
+    registry: &EnvelopeRegistry,
+    env: PromptEnvelope,
+    assessor: &A,
+) -> Result<NeurorightsBound<PromptEnvelope, NeurorightsEnvelope>, AssessError> {
+    let bound = wrap_prompt_with(registry, env)?;
+    let roh = assessor.assess_roh(bound.payload());
+    if !(0.0..=1.0).contains(&roh) {
+        return Err(AssessError::RohOutOfRange { roh });
+    }
+    Ok(bound.with_roh(roh))
+}
+
+/// As `wrap_prompt_with`, but every rejection is signed and recorded through `audit`
+/// and handed back as a receipt for the citizen. A receipt the sink could not record
+/// comes back as `WrapRejection::Unrecorded`.
+pub fn try_wrap_prompt(
+    env: PromptEnvelope,
+    registry: &EnvelopeRegistry,
+    audit: &RejectionAudit,
+) -> Result<NeurorightsBound<PromptEnvelope, NeurorightsEnvelope>, WrapRejection> {
+    let trace_id = env.trace_id.clone();
+    let presented = env.neurorights_profile.clone();
+    wrap_prompt_with(registry, env).map_err(|reason| audit.reject(&trace_id, reason, presented))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::audit::{AuditError, AuditSink, Authorship, EvidenceStamp, ReceiptSigner, RejectionReceipt};
+
+    struct Recorded(Arc<Mutex<Vec<RejectionReceipt>>>);
+
+    impl AuditSink for Recorded {
+        fn record(&self, receipt: &RejectionReceipt) -> Result<(), AuditError> {
+            self.0.lock().unwrap().push(receipt.clone());
+            Ok(())
+        }
+    }
+
+    struct LengthSigner;
+
+    impl ReceiptSigner for LengthSigner {
+        fn sign(&self, data: &[u8]) -> String {
+            format!("len:{}", data.len())
+        }
+    }
+
+    struct Unwritable;
+
+    impl AuditSink for Unwritable {
+        fn record(&self, _receipt: &RejectionReceipt) -> Result<(), AuditError> {
+            Err(AuditError::Io(std::io::Error::other("disk full")))
+        }
+    }
+
+    fn audit(log: &Arc<Mutex<Vec<RejectionReceipt>>>) -> RejectionAudit {
+        RejectionAudit {
+            authorship: Authorship {
+                user_did: "did:bostrom:firewall".to_string(),
+                aln: "aln:firewall".to_string(),
+                bostrom_address: "bostrom1firewall".to_string(),
+                eibon_label: "Eibon:Experimental".to_string(),
+                neurorights_version: neurorights_core::NEURORIGHTS_POLICY_VERSION.to_string(),
+            },
+            evidence: EvidenceStamp::default_hex(),
+            signer: Box::new(LengthSigner),
+            sink: Box::new(Recorded(Arc::clone(log))),
+        }
+    }
+
+    fn envelope(profile: NeurorightsProfile) -> PromptEnvelope {
+        PromptEnvelope {
+            trace_id: "trace-7".to_string(),
+            intent: "fetch_record".to_string(),
+            args: serde_json::json!({}),
+            security_level: "citizen".to_string(),
+            identity: "did:bostrom:citizen".to_string(),
+            provenance: String::new(),
+            governance: String::new(),
+            neurorights_profile: profile,
+        }
+    }
+
+    #[test]
+    fn test_try_wrap_prompt_binds_current_profile_without_receipt() {
+        let log = Arc::default();
+        let registry = EnvelopeRegistry::compiled();
+        let bound = try_wrap_prompt(envelope(NeurorightsProfile::current()), &registry, &audit(&log));
+        assert_eq!(bound.unwrap().payload().trace_id, "trace-7");
+        assert!(log.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rejection_is_signed_and_recorded() {
+        let log = Arc::default();
+        let mut stale = NeurorightsProfile::current();
+        stale.version = "0.9".to_string();
+
+        let registry = EnvelopeRegistry::compiled();
+        let receipt = match try_wrap_prompt(envelope(stale), &registry, &audit(&log)) {
+            Err(WrapRejection::Rejected(receipt)) => receipt,
+            other => panic!("expected a recorded rejection, got {:?}", other.map(|_| ())),
+        };
+        assert_eq!(receipt.trace_id, "trace-7");
+        assert_eq!(receipt.presented.version, "0.9");
+        assert_eq!(receipt.expected.version, neurorights_core::NEURORIGHTS_POLICY_VERSION);
+        assert!(matches!(receipt.reason, EnvelopeMismatch::UnsupportedVersion { .. }));
+        assert_eq!(receipt.signature, format!("len:{}", receipt.signing_bytes().len()));
+        // Signed bytes are canonical JSON: sorted keys, no whitespace.
+        let signed = String::from_utf8(receipt.signing_bytes()).unwrap();
+        assert!(signed.starts_with(r#"{"authorship":{"aln":"aln:firewall","bostrom_address":"#), "{}", signed);
+        assert!(signed.ends_with(r#""trace_id":"trace-7"}"#), "{}", signed);
+        assert_eq!(receipt.to_json()["hex_stamp"], EvidenceStamp::default_hex().hex_stamp);
+
+        let recorded = log.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].signature, receipt.signature);
+    }
+
+    #[test]
+    fn test_sink_failure_is_its_own_error() {
+        let mut stale = NeurorightsProfile::current();
+        stale.version = "0.9".to_string();
+        let mut audit = audit(&Arc::default());
+        audit.sink = Box::new(Unwritable);
+
+        let registry = EnvelopeRegistry::compiled();
+        let err = try_wrap_prompt(envelope(stale), &registry, &audit).unwrap_err();
+        match &err {
+            WrapRejection::Unrecorded { receipt, error: AuditError::Io(e) } => {
+                assert_eq!(receipt.trace_id, "trace-7");
+                assert_eq!(receipt.signature, format!("len:{}", receipt.signing_bytes().len()));
+                assert_eq!(e.to_string(), "disk full");
+            }
+            other => panic!("expected an unrecorded rejection, got {:?}", other),
+        }
+        assert!(err.to_string().contains("not recorded"));
+    }
 }
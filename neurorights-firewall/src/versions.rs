@@ -0,0 +1,203 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use neurorights_core::NeurorightsProfile;
+
+use crate::router::PromptEnvelope;
+
+/// Rewrites an envelope written against one policy version into the next one.
+pub type EnvelopeUpgrade = fn(PromptEnvelope) -> PromptEnvelope;
+
+/// Why an envelope's neurorights profile cannot be bound to the compiled policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EnvelopeMismatch {
+    PolicyId { expected: String, found: String },
+    UnsupportedVersion { found: String, accepted: Vec<String> },
+    NoUpgradePath { from: String, to: String },
+}
+
+impl fmt::Display for EnvelopeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvelopeMismatch::PolicyId { expected, found } => {
+                write!(f, "neurorights_profile.id {} does not match policy {}", found, expected)
+            }
+            EnvelopeMismatch::UnsupportedVersion { found, accepted } => write!(
+                f,
+                "neurorights_profile.version {} not accepted (accepted: {})",
+                found,
+                accepted.join(", ")
+            ),
+            EnvelopeMismatch::NoUpgradePath { from, to } => {
+                write!(f, "no upgrade path from neurorights policy {} to {}", from, to)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EnvelopeMismatch {}
+
+/// Policy versions the router accepts during a rollout, and the upgrades that carry an
+/// older envelope forward to the compiled version.
+#[derive(Clone, Debug)]
+pub struct EnvelopeRegistry {
+    policy_id: String,
+    current: String,
+    accepted: BTreeSet<String>,
+    upgrades: BTreeMap<String, (String, EnvelopeUpgrade)>,
+}
+
+impl EnvelopeRegistry {
+    /// Accepts only the policy version compiled into `neurorights-core`.
+    pub fn compiled() -> Self {
+        let current = neurorights_core::NEURORIGHTS_POLICY_VERSION.to_string();
+        Self {
+            policy_id: neurorights_core::NEURORIGHTS_POLICY_ID.to_string(),
+            accepted: BTreeSet::from([current.clone()]),
+            current,
+            upgrades: BTreeMap::new(),
+        }
+    }
+
+    /// Accept envelopes written against `version`; it still needs an upgrade path.
+    pub fn accept(mut self, version: &str) -> Self {
+        self.accepted.insert(version.to_string());
+        self
+    }
+
+    /// Register the single upgrade step out of `from`.
+    pub fn with_upgrade(mut self, from: &str, to: &str, upgrade: EnvelopeUpgrade) -> Self {
+        self.upgrades.insert(from.to_string(), (to.to_string(), upgrade));
+        self
+    }
+
+    pub fn current_version(&self) -> &str {
+        &self.current
+    }
+
+    pub fn accepted_versions(&self) -> impl Iterator<Item = &str> {
+        self.accepted.iter().map(String::as_str)
+    }
+
+    /// Check the envelope's profile without changing it.
+    pub fn check(&self, profile: &NeurorightsProfile) -> Result<(), EnvelopeMismatch> {
+        if profile.id != self.policy_id {
+            return Err(EnvelopeMismatch::PolicyId {
+                expected: self.policy_id.clone(),
+                found: profile.id.clone(),
+            });
+        }
+        if !self.accepted.contains(&profile.version) {
+            return Err(EnvelopeMismatch::UnsupportedVersion {
+                found: profile.version.clone(),
+                accepted: self.accepted.iter().cloned().collect(),
+            });
+        }
+        self.path_from(&profile.version).map(|_| ())
+    }
+
+    /// Run the upgrade chain so the envelope carries the compiled profile.
+    pub fn migrate(&self, env: PromptEnvelope) -> Result<PromptEnvelope, EnvelopeMismatch> {
+        self.check(&env.neurorights_profile)?;
+        let mut env = env;
+        for (to, upgrade) in self.path_from(&env.neurorights_profile.version.clone())? {
+            env = upgrade(env);
+            env.neurorights_profile.version = to.to_string();
+        }
+        env.neurorights_profile = NeurorightsProfile::current();
+        Ok(env)
+    }
+
+    // Upgrade steps from `from` to the current version; each version is visited once.
+    fn path_from(&self, from: &str) -> Result<Vec<(&str, EnvelopeUpgrade)>, EnvelopeMismatch> {
+        let mut steps = Vec::new();
+        let mut version = from;
+        while version != self.current {
+            match self.upgrades.get(version) {
+                Some((to, upgrade)) if steps.len() < self.upgrades.len() => {
+                    steps.push((to.as_str(), *upgrade));
+                    version = to;
+                }
+                _ => {
+                    return Err(EnvelopeMismatch::NoUpgradePath {
+                        from: from.to_string(),
+                        to: self.current.clone(),
+                    })
+                }
+            }
+        }
+        Ok(steps)
+    }
+}
+
+impl Default for EnvelopeRegistry {
+    fn default() -> Self {
+        Self::compiled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envelope(version: &str) -> PromptEnvelope {
+        let mut profile = NeurorightsProfile::current();
+        profile.version = version.to_string();
+        PromptEnvelope {
+            trace_id: "trace-1".to_string(),
+            intent: "fetch_record".to_string(),
+            args: serde_json::json!({}),
+            security_level: "citizen".to_string(),
+            identity: "did:bostrom:host".to_string(),
+            provenance: String::new(),
+            governance: String::new(),
+            neurorights_profile: profile,
+        }
+    }
+
+    fn rename_scope(mut env: PromptEnvelope) -> PromptEnvelope {
+        env.governance = "upgraded".to_string();
+        env
+    }
+
+    #[test]
+    fn test_compiled_registry_rejects_other_versions() {
+        let registry = EnvelopeRegistry::compiled();
+        assert!(registry.check(&NeurorightsProfile::current()).is_ok());
+        assert!(matches!(
+            registry.check(&envelope("1.2").neurorights_profile),
+            Err(EnvelopeMismatch::UnsupportedVersion { .. })
+        ));
+
+        let mut foreign = NeurorightsProfile::current();
+        foreign.id = "neurorights.envelope.other.v1".to_string();
+        assert!(matches!(registry.check(&foreign), Err(EnvelopeMismatch::PolicyId { .. })));
+    }
+
+    #[test]
+    fn test_accepted_versions_migrate_through_upgrades() {
+        let current = neurorights_core::NEURORIGHTS_POLICY_VERSION;
+        let registry = EnvelopeRegistry::compiled()
+            .accept("1.1")
+            .accept("1.2")
+            .with_upgrade("1.1", "1.2", |env| env)
+            .with_upgrade("1.2", current, rename_scope);
+
+        let env = registry.migrate(envelope("1.1")).unwrap();
+        assert_eq!(env.neurorights_profile.version, current);
+        assert_eq!(env.neurorights_profile.anchor, neurorights_core::NEURORIGHTS_POLICY_ANCHOR);
+        assert_eq!(env.governance, "upgraded");
+    }
+
+    #[test]
+    fn test_accepted_version_without_path_is_an_error() {
+        let registry = EnvelopeRegistry::compiled()
+            .accept("1.0")
+            .accept("0.9")
+            .with_upgrade("1.0", "0.9", |env| env)
+            .with_upgrade("0.9", "1.0", |env| env);
+        let err = registry.migrate(envelope("1.0")).unwrap_err();
+        assert!(matches!(err, EnvelopeMismatch::NoUpgradePath { .. }));
+        assert!(err.to_string().starts_with("no upgrade path from neurorights policy 1.0"));
+    }
+}
@@ -0,0 +1,7 @@
+//! `NeurorightsBound` can only be minted by the firewall; these crates try and must fail.
+
+#[test]
+fn bound_cannot_be_forged_outside_the_firewall() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,119 @@
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use neurorights_firewall::ci_guards::unenforceable_handlers;
+use neurorights_firewall::handler::registered_handlers;
+use neurorights_firewall::{
+    neurorights_handler, wrap_prompt_assessed, wrap_prompt_with, AssessError, EnvelopeRegistry,
+    HandlerViolation, NeurorightsBound, NeurorightsEnvelope, NeurorightsProfile, PromptEnvelope,
+    RohAssessor,
+};
+
+#[neurorights_handler(max_roh = 0.2, forbid = "inner_state_scoring", forbid = "n1dreamplex")]
+fn lookup(
+    bound: NeurorightsBound<PromptEnvelope, NeurorightsEnvelope>,
+    suffix: &str,
+) -> Result<String, HandlerViolation> {
+    Ok(format!("{}{}", bound.payload().trace_id, suffix))
+}
+
+// Forbids a practice the compiled envelope does not know, so it can never run.
+#[neurorights_handler(forbid = "undeclared_practice")]
+async fn misdeclared<P>(
+    bound: &NeurorightsBound<P, NeurorightsEnvelope>,
+) -> Result<(), HandlerViolation> {
+    let _ = bound.roh();
+    Ok(())
+}
+
+fn envelope(args: serde_json::Value) -> PromptEnvelope {
+    PromptEnvelope {
+        trace_id: "trace-9".to_string(),
+        intent: "lookup".to_string(),
+        args,
+        security_level: "citizen".to_string(),
+        identity: "did:bostrom:citizen".to_string(),
+        provenance: String::new(),
+        governance: String::new(),
+        neurorights_profile: NeurorightsProfile::current(),
+    }
+}
+
+/// Stands in for the router's risk model; scores every prompt the same.
+struct Fixed(f64);
+
+impl RohAssessor for Fixed {
+    fn assess_roh(&self, _env: &PromptEnvelope) -> f64 {
+        self.0
+    }
+}
+
+fn bound(roh: Option<f64>) -> NeurorightsBound<PromptEnvelope, NeurorightsEnvelope> {
+    let registry = EnvelopeRegistry::compiled();
+    let env = envelope(serde_json::json!({}));
+    match roh {
+        Some(roh) => wrap_prompt_assessed(&registry, env, &Fixed(roh)).unwrap(),
+        None => wrap_prompt_with(&registry, env).unwrap(),
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn test_policy_is_checked_before_the_body() {
+    assert_eq!(lookup(bound(Some(0.1)), "!").unwrap(), "trace-9!");
+    assert!(matches!(lookup(bound(Some(0.25)), "!"), Err(HandlerViolation::RohExceeded { .. })));
+    assert!(matches!(lookup(bound(None), "!"), Err(HandlerViolation::RohMissing { .. })));
+
+    let err = block_on(misdeclared(&bound(None))).unwrap_err();
+    assert_eq!(
+        err,
+        HandlerViolation::PracticeAllowed {
+            handler: "handler_attr::misdeclared",
+            practice: "undeclared_practice"
+        }
+    );
+}
+
+#[test]
+fn test_self_declared_roh_is_ignored() {
+    let registry = EnvelopeRegistry::compiled();
+    let declared = || envelope(serde_json::json!({ "roh": 0.0 }));
+
+    let unscored = wrap_prompt_with(&registry, declared()).unwrap();
+    assert_eq!(unscored.roh(), None);
+    assert!(matches!(lookup(unscored, "!"), Err(HandlerViolation::RohMissing { .. })));
+
+    let scored = wrap_prompt_assessed(&registry, declared(), &Fixed(0.9)).unwrap();
+    assert!(matches!(lookup(scored, "!"), Err(HandlerViolation::RohExceeded { roh, .. }) if roh == 0.9));
+}
+
+#[test]
+fn test_out_of_range_roh_is_not_bound() {
+    let registry = EnvelopeRegistry::compiled();
+    for roh in [f64::NAN, f64::INFINITY, -0.1, 1.5] {
+        let err = wrap_prompt_assessed(&registry, envelope(serde_json::json!({})), &Fixed(roh)).unwrap_err();
+        assert!(matches!(err, AssessError::RohOutOfRange { .. }), "{}", roh);
+    }
+    for roh in [0.0, 1.0] {
+        assert_eq!(wrap_prompt_assessed(&registry, envelope(serde_json::json!({})), &Fixed(roh)).unwrap().roh(), Some(roh));
+    }
+}
+
+#[test]
+fn test_handlers_are_registered_for_ci() {
+    let lookup = registered_handlers().find(|p| p.handler == "handler_attr::lookup").unwrap();
+    assert_eq!(lookup.max_roh, Some(0.2));
+    assert_eq!(lookup.forbid, ["inner_state_scoring", "n1dreamplex"]);
+
+    assert_eq!(unenforceable_handlers(), [("handler_attr::misdeclared", "undeclared_practice")]);
+}
@@ -0,0 +1,8 @@
+use neurorights_firewall::{NeurorightsBound, NeurorightsEnvelope};
+
+fn main() {
+    let _bound = NeurorightsBound {
+        payload: "payload",
+        envelope: NeurorightsEnvelope::compiled(),
+    };
+}
@@ -0,0 +1,6 @@
+use neurorights_firewall::{BindCapability, NeurorightsBound, NeurorightsEnvelope};
+
+fn main() {
+    let capability = BindCapability { _sealed: () };
+    let _bound = NeurorightsBound::new("payload", NeurorightsEnvelope::compiled(), capability);
+}
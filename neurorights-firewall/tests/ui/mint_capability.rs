@@ -0,0 +1,5 @@
+use neurorights_firewall::{BindCapability, NeurorightsBound, NeurorightsEnvelope};
+
+fn main() {
+    let _bound = NeurorightsBound::new("payload", NeurorightsEnvelope::compiled(), BindCapability::mint());
+}
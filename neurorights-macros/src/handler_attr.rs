@@ -1,34 +1,111 @@
 use proc_macro::TokenStream;
-use quote::{quote, format_ident};
-use syn::{parse_macro_input, ItemFn};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, FnArg, ItemFn, Lit, LitStr, Pat, ReturnType, Type};
 
-pub fn expand(_attr: TokenStream, item: TokenStream) -> TokenStream {
+#[derive(Default)]
+struct HandlerArgs {
+    max_roh: Option<f64>,
+    forbid: Vec<LitStr>,
+}
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut args = HandlerArgs::default();
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("max_roh") {
+            let lit: Lit = meta.value()?.parse()?;
+            let value = match &lit {
+                Lit::Float(f) => f.base10_parse::<f64>()?,
+                Lit::Int(i) => i.base10_parse::<u8>()? as f64,
+                _ => return Err(syn::Error::new(lit.span(), "max_roh must be a number")),
+            };
+            if !(0.0..=1.0).contains(&value) {
+                return Err(syn::Error::new(lit.span(), "max_roh must be within [0, 1]"));
+            }
+            args.max_roh = Some(value);
+            Ok(())
+        } else if meta.path.is_ident("forbid") {
+            args.forbid.push(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `max_roh = <number>` or `forbid = \"<practice>\"`"))
+        }
+    });
+    parse_macro_input!(attr with parser);
     let input = parse_macro_input!(item as ItemFn);
-    let vis = &input.vis;
-    let sig = &input.sig;
-    let block = &input.block;
+
+    match expand_fn(args, input) {
+        Ok(gen) => gen.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand_fn(args: HandlerArgs, input: ItemFn) -> syn::Result<TokenStream2> {
+    let ItemFn { attrs, vis, sig, block } = input;
     let name = &sig.ident;
 
-    let wrapped_name = format_ident!("{}_inner", name);
-
-    // Original logic moved into `_inner`.
-    let gen = quote! {
-        #vis fn #wrapped_name(
-            bound: ::neurorights_core::NeurorightsBound<
-                crate::PromptEnvelope,
-                ::neurorights_core::NeurorightsEnvelope
-            >
-        ) #block
-
-        #vis fn #name(
-            bound: ::neurorights_core::NeurorightsBound<
-                crate::PromptEnvelope,
-                ::neurorights_core::NeurorightsEnvelope
-            >
-        ) {
-            #wrapped_name(bound)
-        }
+    // The prologue reports violations with `?`, so the error type must absorb them.
+    let returns_result = match &sig.output {
+        ReturnType::Type(_, ty) => last_segment_is(ty, "Result"),
+        ReturnType::Default => false,
+    };
+    if !returns_result {
+        let span = match &sig.output {
+            ReturnType::Type(_, ty) => ty.span(),
+            ReturnType::Default => sig.ident.span(),
+        };
+        return Err(syn::Error::new(
+            span,
+            "#[neurorights_handler] functions must return a Result whose error implements From<HandlerViolation>",
+        ));
+    }
+
+    let bound = sig
+        .inputs
+        .iter()
+        .find_map(|arg| match arg {
+            FnArg::Typed(pat) if last_segment_is(&pat.ty, "NeurorightsBound") => Some(pat),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            syn::Error::new(sig.inputs.span(), "#[neurorights_handler] needs a NeurorightsBound argument")
+        })?;
+    let Pat::Ident(bound_ident) = &*bound.pat else {
+        return Err(syn::Error::new(bound.pat.span(), "bind the NeurorightsBound argument to a name"));
+    };
+    let bound_ident = &bound_ident.ident;
+    let bound_ref = match &*bound.ty {
+        Type::Reference(_) => quote!(#bound_ident),
+        _ => quote!(&#bound_ident),
+    };
+
+    let max_roh = match args.max_roh {
+        Some(v) => quote!(::core::option::Option::Some(#v)),
+        None => quote!(::core::option::Option::None),
     };
+    let forbid = &args.forbid;
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis #sig {
+            const __NEURORIGHTS_POLICY: ::neurorights_firewall::handler::HandlerPolicy =
+                ::neurorights_firewall::handler::HandlerPolicy {
+                    handler: concat!(module_path!(), "::", stringify!(#name)),
+                    max_roh: #max_roh,
+                    forbid: &[#(#forbid),*],
+                };
+            ::neurorights_firewall::inventory::submit! { __NEURORIGHTS_POLICY }
+
+            ::neurorights_firewall::handler::enforce(&__NEURORIGHTS_POLICY, #bound_ref)?;
+            #block
+        }
+    })
+}
 
-    gen.into()
+fn last_segment_is(ty: &Type, ident: &str) -> bool {
+    match ty {
+        Type::Path(path) => path.path.segments.last().is_some_and(|s| s.ident == ident),
+        Type::Reference(r) => last_segment_is(&r.elem, ident),
+        _ => false,
+    }
 }
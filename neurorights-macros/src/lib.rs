@@ -4,6 +4,9 @@ mod handler_attr;
 
 use proc_macro::TokenStream;
 
+/// Check a handler's declared policy against its `NeurorightsBound` argument before the
+/// body runs, and register the policy for CI audits:
+/// `#[neurorights_handler(max_roh = 0.2, forbid = "inner_state_scoring")]`.
 #[proc_macro_attribute]
 pub fn neurorights_handler(attr: TokenStream, item: TokenStream) -> TokenStream {
     handler_attr::expand(attr, item)
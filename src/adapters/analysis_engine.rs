@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use crate::domain::{PromptEnvelope, Metadata, RiskAssessment, Intent, SecurityLevel};
+use crate::logging::{read_events, LogEvent};
+use crate::tools::{ToolAdapter, ToolCapabilities, ToolError};
+
+/// Deterministic aggregate summaries over the router's stored `LogEvent`s.
+pub struct AnalysisEngineAdapter {
+    log_path: PathBuf,
+}
+
+impl AnalysisEngineAdapter {
+    /// `log_path` is the file a `FileLogSink` appends to.
+    pub fn new<P: Into<PathBuf>>(log_path: P) -> Self {
+        Self { log_path: log_path.into() }
+    }
+}
+
+/// Counts and risk statistics only; no params, DIDs or trace ids leave the log.
+pub fn summarize(events: &[LogEvent]) -> Value {
+    let mut by_cmd = BTreeMap::new();
+    let mut by_subject = BTreeMap::new();
+    let mut by_purpose = BTreeMap::new();
+    let mut by_stage = BTreeMap::new();
+    let mut red_flags = 0;
+    let mut max_risk: f32 = 0.0;
+
+    for event in events {
+        *by_cmd.entry(event.cmd.as_str()).or_insert(0) += 1;
+        *by_subject.entry(format!("{:?}", event.metadata.subject)).or_insert(0) += 1;
+        *by_purpose.entry(format!("{:?}", event.metadata.purpose)).or_insert(0) += 1;
+        for c in &event.risk.contributions {
+            *by_stage.entry(c.stage.as_str()).or_insert(0) += 1;
+        }
+        red_flags += usize::from(event.risk.red_flag);
+        max_risk = max_risk.max(event.risk.risk_score);
+    }
+    let mean_risk = if events.is_empty() {
+        0.0
+    } else {
+        events.iter().map(|e| e.risk.risk_score).sum::<f32>() / events.len() as f32
+    };
+
+    json!({
+        "events": events.len(),
+        "by_cmd": by_cmd,
+        "by_subject": by_subject,
+        "by_purpose": by_purpose,
+        "risk": {
+            "mean": mean_risk,
+            "max": max_risk,
+            "red_flags": red_flags,
+            "by_stage": by_stage,
+        },
+    })
+}
+
+#[async_trait]
+impl ToolAdapter for AnalysisEngineAdapter {
+    fn name(&self) -> &'static str {
+        "analysis_engine"
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities {
+            intents: &[Intent::Analyze],
+            max_security: SecurityLevel::Restricted,
+            read_only: true,
+            required_flags: &["rights.mentalprivacy"],
+        }
+    }
+
+    async fn execute(
+        &self,
+        _envelope: &PromptEnvelope,
+        _metadata: &Metadata,
+        _risk: &RiskAssessment,
+    ) -> Result<serde_json::Value, ToolError> {
+        let events = read_events(&self.log_path)
+            .map_err(|e| ToolError::Internal(format!("reading {}: {:?}", self.log_path.display(), e)))?;
+        Ok(json!({ "status": "ok", "summary": summarize(&events) }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::request;
+    use crate::domain::{RiskContribution, SubjectTag};
+    use crate::logging::{FileLogSink, LogSink};
+
+    fn event(cmd: &str, subject: SubjectTag, risk_score: f32, red_flag: bool) -> LogEvent {
+        let (env, mut metadata, mut risk) = request(Intent::Analyze, json!({ "prompt": "" }));
+        metadata.subject = subject;
+        risk.risk_score = risk_score;
+        risk.red_flag = red_flag;
+        risk.contributions.push(RiskContribution {
+            stage: "intent_prior".into(),
+            weight: 1.0,
+            score: risk_score,
+            rationale: String::new(),
+        });
+        LogEvent {
+            trace_id: env.trace_id,
+            user_did: env.identity.user_did.clone(),
+            cmd: cmd.into(),
+            params: env.args,
+            result_ref: None,
+            timestamp: env.created_at,
+            metadata,
+            risk,
+            authorship: env.identity,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_summarizes_stored_events() {
+        let path = std::env::temp_dir().join(format!("analysis-engine-{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let adapter = AnalysisEngineAdapter::new(&path);
+        let (env, meta, risk) = request(Intent::Analyze, json!({ "prompt": "summarize" }));
+
+        let empty = adapter.execute(&env, &meta, &risk).await.unwrap();
+        assert_eq!(empty["summary"]["events"], 0);
+
+        let sink = FileLogSink::new(&path);
+        sink.append(&event("drive_reader", SubjectTag::Prosthetics, 0.1, false)).unwrap();
+        sink.append(&event("drive_reader", SubjectTag::Governance, 0.2, false)).unwrap();
+        sink.append(&event("blocked", SubjectTag::Governance, 0.9, true)).unwrap();
+
+        let summary = adapter.execute(&env, &meta, &risk).await.unwrap()["summary"].clone();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(summary["events"], 3);
+        assert_eq!(summary["by_cmd"], json!({ "blocked": 1, "drive_reader": 2 }));
+        assert_eq!(summary["by_subject"]["Governance"], 2);
+        assert_eq!(summary["risk"]["red_flags"], 1);
+        assert_eq!(summary["risk"]["by_stage"]["intent_prior"], 3);
+        assert!((summary["risk"]["mean"].as_f64().unwrap() - 0.4).abs() < 1e-6);
+        assert!(!summary.to_string().contains("did:example"));
+    }
+}
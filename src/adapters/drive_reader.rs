@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use serde_json::json;
-use crate::domain::{PromptEnvelope, Metadata, RiskAssessment};
-use crate::tools::{ToolAdapter, ToolError};
+use crate::domain::{PromptEnvelope, Metadata, RiskAssessment, Intent, SecurityLevel};
+use crate::tools::{ToolAdapter, ToolCapabilities, ToolError};
 
 pub struct DriveReaderAdapter;
 
@@ -11,6 +11,15 @@ impl ToolAdapter for DriveReaderAdapter {
         "drive_reader"
     }
 
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities {
+            intents: &[Intent::Retrieve],
+            max_security: SecurityLevel::Restricted,
+            read_only: true,
+            required_flags: &["rights.mentalprivacy"],
+        }
+    }
+
     async fn execute(
         &self,
         _envelope: &PromptEnvelope,
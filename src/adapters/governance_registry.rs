@@ -0,0 +1,120 @@
+use std::path::Path;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use crate::domain::{PromptEnvelope, Metadata, RiskAssessment, Intent, SecurityLevel};
+use crate::tools::{ToolAdapter, ToolCapabilities, ToolError};
+
+/// Read-only view of the local ALN shard registry.
+pub struct GovernanceRegistryAdapter {
+    registry: aln::AlnRegistry,
+}
+
+impl GovernanceRegistryAdapter {
+    /// Load every shard under `dir`; any shard whose hexstamp does not match is refused.
+    pub fn load(dir: &Path) -> Result<Self, aln::RegistryError> {
+        let mut registry = aln::AlnRegistry::new();
+        registry.load_dir(dir)?;
+        registry.verify_hexstamps()?;
+        Ok(Self { registry })
+    }
+}
+
+#[async_trait]
+impl ToolAdapter for GovernanceRegistryAdapter {
+    fn name(&self) -> &'static str {
+        "governance_registry"
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities {
+            intents: &[Intent::Retrieve, Intent::Governance],
+            // Shards are published governance documents.
+            max_security: SecurityLevel::Sensitive,
+            read_only: true,
+            required_flags: &["governance.noNeuralInputsForGovernance"],
+        }
+    }
+
+    async fn execute(
+        &self,
+        envelope: &PromptEnvelope,
+        _metadata: &Metadata,
+        _risk: &RiskAssessment,
+    ) -> Result<serde_json::Value, ToolError> {
+        let extra = |key: &str| envelope.args.get("extra")?.get(key)?.as_str();
+
+        // `extra.shard` asks for one shard in full; otherwise list headers, optionally by `extra.kind`.
+        if let Some(id) = extra("shard") {
+            return Ok(match (self.registry.header(id), self.registry.get(id)) {
+                (Some(header), Some(doc)) => json!({
+                    "status": "ok",
+                    "shard": header_json(header),
+                    "content": node_json(&doc.root),
+                }),
+                _ => json!({ "status": "not_found", "shard": id }),
+            });
+        }
+
+        let kind = extra("kind");
+        let shards: Vec<Value> = self
+            .registry
+            .headers()
+            .filter(|h| kind.is_none() || h.kind.as_deref() == kind)
+            .map(header_json)
+            .collect();
+        Ok(json!({ "status": "ok", "count": shards.len(), "shards": shards }))
+    }
+}
+
+fn header_json(header: &aln::ShardHeader) -> Value {
+    json!({
+        "id": header.id,
+        "version": header.version,
+        "kind": header.kind,
+        "hexstamp": header.hexstamp,
+        "dialect": header.dialect.as_str(),
+    })
+}
+
+fn node_json(node: &aln::Node) -> Value {
+    match &node.value {
+        aln::Value::Null => Value::Null,
+        aln::Value::Bool(b) => Value::Bool(*b),
+        aln::Value::Number { value, .. } => json!(value),
+        aln::Value::String(s) => Value::String(s.clone()),
+        aln::Value::List(items) => Value::Array(items.iter().map(node_json).collect()),
+        aln::Value::Map(entries) => {
+            Value::Object(entries.iter().map(|(k, v)| (k.name.clone(), node_json(v))).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::request;
+
+    #[tokio::test]
+    async fn test_lists_and_reads_repo_shards() {
+        let adapter = GovernanceRegistryAdapter::load(Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/aln"))).unwrap();
+
+        let (env, meta, risk) = request(Intent::Governance, json!({ "prompt": "list shards" }));
+        let all = adapter.execute(&env, &meta, &risk).await.unwrap();
+        assert!(all["count"].as_u64().unwrap() >= 10);
+
+        let args = json!({ "base": { "prompt": "" }, "extra": { "kind": "router_routes" } });
+        let (env, meta, risk) = request(Intent::Retrieve, args);
+        let routes = adapter.execute(&env, &meta, &risk).await.unwrap();
+        assert_eq!(routes["shards"][0]["id"], "router.routes.v1");
+
+        let args = json!({ "base": { "prompt": "" }, "extra": { "shard": "neurorights.envelope.citizen.v1" } });
+        let (env, meta, risk) = request(Intent::Retrieve, args);
+        let shard = adapter.execute(&env, &meta, &risk).await.unwrap();
+        assert_eq!(shard["content"]["rights"]["mentalprivacy"], true);
+        assert_eq!(shard["content"]["limits"]["max_inner_state_score"], 0.0);
+
+        let args = json!({ "base": { "prompt": "" }, "extra": { "shard": "no.such.shard" } });
+        let (env, meta, risk) = request(Intent::Retrieve, args);
+        assert_eq!(adapter.execute(&env, &meta, &risk).await.unwrap()["status"], "not_found");
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use serde_json::json;
+use crate::domain::{PromptEnvelope, Metadata, RiskAssessment, Intent, SecurityLevel};
+use crate::logging::{LogSink, LogEvent};
+use crate::pii::redact_args;
+use crate::tools::{ToolAdapter, ToolCapabilities, ToolError};
+
+/// Queues requests no other tool should act on, so a reviewer can route them by hand.
+pub struct LoggingAdapter {
+    review: Arc<dyn LogSink>,
+}
+
+impl LoggingAdapter {
+    /// `review` should be separate from the router's own log.
+    pub fn new(review: Arc<dyn LogSink>) -> Self {
+        Self { review }
+    }
+}
+
+#[async_trait]
+impl ToolAdapter for LoggingAdapter {
+    fn name(&self) -> &'static str {
+        "logging_adapter"
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities {
+            // Also the fallback for Plan and Governance when their primary tool is unavailable.
+            intents: &[Intent::Unknown, Intent::Plan, Intent::Governance],
+            max_security: SecurityLevel::Sensitive,
+            read_only: false,
+            required_flags: &["rights.mentalprivacy"],
+        }
+    }
+
+    async fn execute(
+        &self,
+        envelope: &PromptEnvelope,
+        metadata: &Metadata,
+        risk: &RiskAssessment,
+    ) -> Result<serde_json::Value, ToolError> {
+        // Reviewers see the same redacted args as the router log.
+        let event = LogEvent {
+            trace_id: envelope.trace_id.clone(),
+            user_did: envelope.identity.user_did.clone(),
+            cmd: format!("review:{:?}", envelope.intent),
+            params: redact_args(&envelope.args, envelope.security_level),
+            result_ref: None,
+            timestamp: envelope.created_at,
+            metadata: metadata.clone(),
+            risk: risk.clone(),
+            authorship: envelope.identity.clone(),
+        };
+        self.review
+            .append(&event)
+            .map_err(|e| ToolError::Internal(format!("review queue: {:?}", e)))?;
+
+        Ok(json!({
+            "status": "queued_for_review",
+            "trace_id": envelope.trace_id,
+            "intent": envelope.intent,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use crate::adapters::request;
+    use crate::logging::LogError;
+
+    #[derive(Default)]
+    struct Queue(Mutex<Vec<LogEvent>>);
+
+    impl LogSink for Queue {
+        fn append(&self, event: &LogEvent) -> Result<(), LogError> {
+            self.0.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_requests_are_queued_redacted() {
+        let queue = Arc::new(Queue::default());
+        let adapter = LoggingAdapter::new(queue.clone());
+        let (env, meta, risk) = request(Intent::Unknown, json!({ "prompt": "write to jane@example.org" }));
+
+        let result = adapter.execute(&env, &meta, &risk).await.unwrap();
+        assert_eq!(result, json!({ "status": "queued_for_review", "trace_id": "0x01", "intent": "Unknown" }));
+
+        let queued = queue.0.lock().unwrap();
+        assert_eq!(queued.len(), 1);
+        assert_eq!(queued[0].cmd, "review:Unknown");
+        assert_eq!(queued[0].params["prompt"], "write to [REDACTED:email]");
+    }
+}
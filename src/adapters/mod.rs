@@ -0,0 +1,36 @@
+pub mod drive_reader;
+pub mod governance_registry;
+pub mod analysis_engine;
+pub mod simulation_adapter;
+pub mod logging_adapter;
+
+#[cfg(test)]
+fn request(intent: crate::domain::Intent, args: serde_json::Value) -> (
+    crate::domain::PromptEnvelope,
+    crate::domain::Metadata,
+    crate::domain::RiskAssessment,
+) {
+    use crate::domain::{CodexType, Identity, Metadata, PromptEnvelope, PurposeTag, RiskAssessment, SecurityLevel, SubjectTag};
+
+    let envelope = PromptEnvelope {
+        trace_id: "0x01".into(),
+        intent,
+        args,
+        security_level: SecurityLevel::Restricted,
+        identity: Identity { user_did: "did:example:user".into(), aln: None, bostrom_address: None },
+        created_at: std::time::SystemTime::UNIX_EPOCH,
+    };
+    let metadata = Metadata {
+        codex_type: CodexType::LogEvent,
+        drive_path: String::new(),
+        subject: SubjectTag::Other,
+        purpose: PurposeTag::Other,
+        subject_confidence: 0.0,
+        purpose_confidence: 0.0,
+        has_pii: false,
+        bio_risk_flag: false,
+        policy_relevant: false,
+    };
+    let risk = RiskAssessment { risk_score: 0.0, red_flag: false, rationale: String::new(), contributions: Vec::new() };
+    (envelope, metadata, risk)
+}
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use serde_json::json;
+use organic_cpu_sim::{
+    run_organic_cpu_sim, DdmParams, LifParams, OrganicCpuConfig, WilsonCowanParams,
+};
+use crate::domain::{PromptEnvelope, Metadata, RiskAssessment, Intent, SecurityLevel};
+use crate::tools::{ToolAdapter, ToolCapabilities, ToolError};
+
+/// Longest run a request may ask for.
+pub const MAX_STEPS: usize = 10_000;
+
+/// Runs the organic CPU pipeline (LIF → Wilson–Cowan → DDM) on a fixed configuration.
+pub struct SimulationAdapter {
+    config: OrganicCpuConfig,
+}
+
+impl SimulationAdapter {
+    pub fn new(config: OrganicCpuConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Default for SimulationAdapter {
+    /// Textbook parameters: 20 ms membrane, balanced E/I populations, unit DDM bounds.
+    fn default() -> Self {
+        Self::new(OrganicCpuConfig {
+            lif: LifParams { tau_m: 20.0, v_rest: -65.0, r_m: 10.0, v_thresh: -50.0, v_reset: -65.0 },
+            wc: WilsonCowanParams {
+                tau_e: 10.0,
+                tau_i: 20.0,
+                w_ee: 12.0,
+                w_ei: 4.0,
+                w_ie: 13.0,
+                w_ii: 11.0,
+                p_e: 0.5,
+                p_i: 0.0,
+            },
+            ddm: DdmParams { drift: 0.3, noise: 1.0, upper: 1.0, lower: -1.0 },
+            dt: 0.1,
+            steps: 1_000,
+        })
+    }
+}
+
+#[async_trait]
+impl ToolAdapter for SimulationAdapter {
+    fn name(&self) -> &'static str {
+        "simulation_adapter"
+    }
+
+    fn capabilities(&self) -> ToolCapabilities {
+        ToolCapabilities {
+            intents: &[Intent::Analyze, Intent::Plan, Intent::Simulate],
+            max_security: SecurityLevel::Restricted,
+            read_only: true,
+            required_flags: &["rights.noscorefrominnerstate"],
+        }
+    }
+
+    async fn execute(
+        &self,
+        envelope: &PromptEnvelope,
+        _metadata: &Metadata,
+        _risk: &RiskAssessment,
+    ) -> Result<serde_json::Value, ToolError> {
+        let extra = |key: &str| envelope.args.get("extra")?.get(key);
+
+        let lif_input = extra("lif_input").and_then(|v| v.as_f64()).unwrap_or(2.0) as f32;
+        if !lif_input.is_finite() {
+            return Err(ToolError::Denied("lif_input must be finite".into()));
+        }
+        let mut cfg = self.config.clone();
+        if let Some(steps) = extra("steps").and_then(|v| v.as_u64()) {
+            cfg.steps = (steps as usize).min(MAX_STEPS);
+        }
+
+        // Zero noise keeps every run reproducible from its args alone.
+        let noise = vec![0.0_f32; cfg.steps];
+        let result = run_organic_cpu_sim(&cfg, lif_input, &noise);
+
+        Ok(json!({
+            "status": "ok",
+            "steps": cfg.steps,
+            "lif_input": lif_input,
+            "last_lif_v": result.last_lif_v,
+            "last_wc_e": result.last_wc_e,
+            "last_wc_i": result.last_wc_i,
+            "decision": format!("{:?}", result.decision),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::request;
+
+    #[tokio::test]
+    async fn test_runs_the_pipeline_deterministically() {
+        let adapter = SimulationAdapter::default();
+        let args = json!({ "base": { "prompt": "simulate" }, "extra": { "lif_input": 2.0, "steps": 500 } });
+        let (env, meta, risk) = request(Intent::Simulate, args);
+
+        let first = adapter.execute(&env, &meta, &risk).await.unwrap();
+        assert_eq!(first, adapter.execute(&env, &meta, &risk).await.unwrap());
+        assert_eq!(first["steps"], 500);
+        // drift 0.3 reaches the upper bound of 1.0 after ~3.3 time units.
+        assert_eq!(first["decision"], "Upper");
+        let v = first["last_lif_v"].as_f64().unwrap();
+        assert!((-65.0..-50.0).contains(&v));
+        let e = first["last_wc_e"].as_f64().unwrap();
+        assert!((0.0..=1.0).contains(&e));
+
+        let args = json!({ "base": { "prompt": "simulate" }, "extra": { "steps": 1_000_000 } });
+        let (env, meta, risk) = request(Intent::Simulate, args);
+        assert_eq!(adapter.execute(&env, &meta, &risk).await.unwrap()["steps"], MAX_STEPS);
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt;
+use serde_json::Value;
+use crate::domain::{SubjectTag, PurposeTag};
+use crate::risk::{text_of, words, matches};
+
+/// Lexicon shard compiled into the router; governance reviews changes to it like any shard.
+pub const CLASSIFIER_SHARD: &str = include_str!("../aln/router.classifier.v1.aln");
+
+/// Subject and purpose of a request, with the share of lexicon hits that chose each.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Classification {
+    pub subject: SubjectTag,
+    pub subject_confidence: f32,
+    pub purpose: PurposeTag,
+    pub purpose_confidence: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassifierError {
+    Parse(aln::ParseError),
+    Hexstamp(aln::HexstampError),
+    Missing(&'static str),
+    UnknownTag { section: &'static str, tag: String },
+}
+
+impl fmt::Display for ClassifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClassifierError::Parse(e) => write!(f, "classifier shard:{}", e),
+            ClassifierError::Hexstamp(e) => write!(f, "classifier shard: {}", e),
+            ClassifierError::Missing(key) => write!(f, "classifier shard has no `{}`", key),
+            ClassifierError::UnknownTag { section, tag } => {
+                write!(f, "classifier shard: unknown tag {} in {}", tag, section)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClassifierError {}
+
+/// Table-driven lexicon classifier loaded from a versioned ALN shard.
+#[derive(Debug, Clone)]
+pub struct Classifier {
+    pub id: String,
+    pub version: String,
+    min_confidence: f32,
+    subject_hint: Option<String>,
+    purpose_hint: Option<String>,
+    subjects: Vec<(SubjectTag, Vec<String>)>,
+    purposes: Vec<(PurposeTag, Vec<String>)>,
+}
+
+impl Classifier {
+    /// The shard compiled into this binary.
+    pub fn compiled() -> Self {
+        Self::from_shard(CLASSIFIER_SHARD).expect("compiled classifier shard is valid")
+    }
+
+    /// Parse a classifier shard; its hexstamp must match its content.
+    pub fn from_shard(text: &str) -> Result<Self, ClassifierError> {
+        let doc = aln::parse(text).map_err(ClassifierError::Parse)?;
+        aln::hexstamp::verify(&doc).map_err(ClassifierError::Hexstamp)?;
+        let text_at = |path: &'static str| {
+            doc.path(path).and_then(aln::Node::as_text).ok_or(ClassifierError::Missing(path))
+        };
+
+        Ok(Self {
+            id: text_at("id")?.to_string(),
+            version: text_at("version")?.to_string(),
+            min_confidence: doc
+                .get("min_confidence")
+                .and_then(aln::Node::as_f64)
+                .ok_or(ClassifierError::Missing("min_confidence"))? as f32,
+            subject_hint: text_at("hints.subject").ok().map(String::from),
+            purpose_hint: text_at("hints.purpose").ok().map(String::from),
+            subjects: table(&doc, "subjects", subject_tag)?,
+            purposes: table(&doc, "purposes", purpose_tag)?,
+        })
+    }
+
+    pub fn classify(&self, args: &Value) -> Classification {
+        let text = words(&text_of(args));
+        let hint = |key: &Option<String>| {
+            let key = key.as_deref()?;
+            args.get("extra")?.get(key)?.as_str()
+        };
+
+        let (subject, subject_confidence) = match hint(&self.subject_hint).and_then(subject_tag) {
+            Some(tag) => (tag, 1.0),
+            None => self.best(&self.subjects, &text).unwrap_or((SubjectTag::Other, 0.0)),
+        };
+        let (purpose, purpose_confidence) = match hint(&self.purpose_hint).and_then(purpose_tag) {
+            Some(tag) => (tag, 1.0),
+            None => self.best(&self.purposes, &text).unwrap_or((PurposeTag::Other, 0.0)),
+        };
+        Classification { subject, subject_confidence, purpose, purpose_confidence }
+    }
+
+    // Tag with the most hits, earliest in the table on ties, if its share is high enough.
+    fn best<T: Copy>(&self, table: &[(T, Vec<String>)], text: &str) -> Option<(T, f32)> {
+        let hits: Vec<(T, usize)> = table
+            .iter()
+            .map(|(tag, terms)| {
+                let terms: Vec<&str> = terms.iter().map(String::as_str).collect();
+                (*tag, matches(text, &terms).len())
+            })
+            .collect();
+        let total: usize = hits.iter().map(|(_, n)| n).sum();
+        let (tag, n) = hits.into_iter().rev().max_by_key(|(_, n)| *n)?;
+        let confidence = n as f32 / total.max(1) as f32;
+        (n > 0 && confidence >= self.min_confidence).then_some((tag, confidence))
+    }
+}
+
+impl Default for Classifier {
+    fn default() -> Self {
+        Self::compiled()
+    }
+}
+
+fn table<T>(
+    doc: &aln::Document,
+    section: &'static str,
+    tag: fn(&str) -> Option<T>,
+) -> Result<Vec<(T, Vec<String>)>, ClassifierError> {
+    let entries = doc.get(section).and_then(aln::Node::as_map).ok_or(ClassifierError::Missing(section))?;
+    entries
+        .iter()
+        .map(|(key, terms)| {
+            let tag = tag(&key.name).ok_or_else(|| ClassifierError::UnknownTag { section, tag: key.name.clone() })?;
+            let terms = terms
+                .as_list()
+                .unwrap_or(&[])
+                .iter()
+                .filter_map(aln::Node::as_text)
+                .map(|t| words(t).trim().to_string())
+                .collect();
+            Ok((tag, terms))
+        })
+        .collect()
+}
+
+pub fn subject_tag(name: &str) -> Option<SubjectTag> {
+    [
+        SubjectTag::Prosthetics,
+        SubjectTag::NeuralInterfaces,
+        SubjectTag::Governance,
+        SubjectTag::Ethics,
+        SubjectTag::Simulation,
+        SubjectTag::Other,
+    ]
+    .into_iter()
+    .find(|tag| format!("{:?}", tag).eq_ignore_ascii_case(name))
+}
+
+fn purpose_tag(name: &str) -> Option<PurposeTag> {
+    [
+        PurposeTag::Education,
+        PurposeTag::Policy,
+        PurposeTag::Ethics,
+        PurposeTag::Simulation,
+        PurposeTag::Monitoring,
+        PurposeTag::Other,
+    ]
+    .into_iter()
+    .find(|tag| format!("{:?}", tag).eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lexicons_assign_subject_and_purpose() {
+        let classifier = Classifier::compiled();
+        assert_eq!((classifier.id.as_str(), classifier.version.as_str()), ("router.classifier.v1", "1.0.0"));
+
+        let c = classifier.classify(&serde_json::json!({ "prompt": "Explain myoelectric prosthetic grip control" }));
+        assert_eq!((c.subject, c.purpose), (SubjectTag::Prosthetics, PurposeTag::Education));
+        assert_eq!(c.subject_confidence, 1.0);
+
+        let c = classifier.classify(&serde_json::json!({ "prompt": "What time is it?" }));
+        assert_eq!((c.subject, c.subject_confidence), (SubjectTag::Other, 0.0));
+        assert_eq!(c.purpose, PurposeTag::Other);
+    }
+
+    #[test]
+    fn test_hints_outrank_lexicons_and_ambiguity_falls_back() {
+        let classifier = Classifier::compiled();
+        let args = serde_json::json!({
+            "base": { "prompt": "consent ledger for the BCI implant" },
+            "extra": { "subject": "ethics", "purpose": "monitoring" }
+        });
+        let c = classifier.classify(&args);
+        assert_eq!((c.subject, c.purpose), (SubjectTag::Ethics, PurposeTag::Monitoring));
+        assert_eq!(c.purpose_confidence, 1.0);
+
+        // bci + implant is half of all subject hits; three single hits are not.
+        let c = classifier.classify(&serde_json::json!({ "prompt": "consent ledger for the BCI implant" }));
+        assert_eq!(c.subject, SubjectTag::NeuralInterfaces);
+        let c = classifier.classify(&serde_json::json!({ "prompt": "consent ledger prosthesis" }));
+        assert_eq!(c.subject, SubjectTag::Other);
+    }
+
+    #[test]
+    fn test_shard_edits_need_a_new_hexstamp() {
+        let edited = CLASSIFIER_SHARD.replace("min_confidence: 0.5", "min_confidence: 0.1");
+        assert!(matches!(Classifier::from_shard(&edited), Err(ClassifierError::Hexstamp(_))));
+        let unknown = CLASSIFIER_SHARD.replace("  Monitoring:\n", "  Surveillance:\n");
+        assert!(Classifier::from_shard(&unknown).is_err());
+    }
+}
@@ -2,7 +2,7 @@ use std::time::{SystemTime};
 use serde::{Serialize, Deserialize};
 
 /// High-level intent for a neural syscall.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Intent {
     Retrieve,
     Analyze,
@@ -12,16 +12,31 @@ pub enum Intent {
     Unknown,
 }
 
-/// Security level for the request.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl Intent {
+    pub const ALL: [Intent; 6] = [
+        Intent::Retrieve,
+        Intent::Analyze,
+        Intent::Plan,
+        Intent::Simulate,
+        Intent::Governance,
+        Intent::Unknown,
+    ];
+}
+
+/// Security level for the request, ordered from least to most sensitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum SecurityLevel {
     Public,
     Restricted,
     Sensitive,
 }
 
+impl SecurityLevel {
+    pub const ALL: [SecurityLevel; 3] = [SecurityLevel::Public, SecurityLevel::Restricted, SecurityLevel::Sensitive];
+}
+
 /// High-level codex type for produced assets.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CodexType {
     ResearchSpec,
     PolicyDraft,
@@ -31,7 +46,7 @@ pub enum CodexType {
 }
 
 /// Subject tags for cybernetic research (no wet-lab).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SubjectTag {
     Prosthetics,
     NeuralInterfaces,
@@ -42,7 +57,7 @@ pub enum SubjectTag {
 }
 
 /// Declared purpose of the retrieval.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PurposeTag {
     Education,
     Policy,
@@ -78,6 +93,9 @@ pub struct Metadata {
     pub drive_path: String,
     pub subject: SubjectTag,
     pub purpose: PurposeTag,
+    /// Share of classifier lexicon hits behind `subject` / `purpose` (1.0 for explicit hints).
+    pub subject_confidence: f32,
+    pub purpose_confidence: f32,
     pub has_pii: bool,
     pub bio_risk_flag: bool,
     pub policy_relevant: bool,
@@ -89,4 +107,16 @@ pub struct RiskAssessment {
     pub risk_score: f32,
     pub red_flag: bool,
     pub rationale: String,
+    /// Per-stage contributions, largest first.
+    pub contributions: Vec<RiskContribution>,
+}
+
+/// One risk stage's weighted share of a `RiskAssessment`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskContribution {
+    pub stage: String,
+    pub weight: f32,
+    /// Stage score before weighting (0.0–1.0).
+    pub score: f32,
+    pub rationale: String,
 }
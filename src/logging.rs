@@ -27,6 +27,19 @@ pub enum LogError {
     Serialization(serde_json::Error),
 }
 
+/// Events written by a `FileLogSink`, oldest first; a missing file holds none.
+pub fn read_events(path: &std::path::Path) -> Result<Vec<LogEvent>, LogError> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(LogError::Io(e)),
+    };
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(LogError::Serialization))
+        .collect()
+}
+
 /// Simple file-backed append-only sink (one JSON per line).
 pub struct FileLogSink {
     path: std::path::PathBuf,
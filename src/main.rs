@@ -1,19 +1,16 @@
-mod domain;
-mod logging;
-mod tools;
-mod router;
-mod authorship;
-mod trace;
-mod normalize;
-
+use std::path::Path;
 use std::sync::Arc;
-use crate::logging::FileLogSink;
-use crate::router::CyberRetrievalRouter;
-use crate::authorship::AuthorshipConfig;
-use crate::normalize::{RawPrompt, normalize_prompt};
-use crate::domain::SecurityLevel;
-use crate::tools::ToolAdapter;
-use crate::adapters::drive_reader::DriveReaderAdapter;
+use cyber_retrieval::logging::FileLogSink;
+use cyber_retrieval::router::{CyberRetrievalRouter, DEFAULT_RISK_THRESHOLD};
+use cyber_retrieval::authorship::AuthorshipConfig;
+use cyber_retrieval::normalize::{RawPrompt, normalize_prompt};
+use cyber_retrieval::domain::SecurityLevel;
+use cyber_retrieval::registry::{ToolRegistry, RouteTable};
+use cyber_retrieval::adapters::drive_reader::DriveReaderAdapter;
+use cyber_retrieval::adapters::governance_registry::GovernanceRegistryAdapter;
+use cyber_retrieval::adapters::analysis_engine::AnalysisEngineAdapter;
+use cyber_retrieval::adapters::simulation_adapter::SimulationAdapter;
+use cyber_retrieval::adapters::logging_adapter::LoggingAdapter;
 
 #[tokio::main]
 async fn main() {
@@ -23,11 +20,30 @@ async fn main() {
         Some("bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7".into()),
     );
 
-    // Register tools.
-    let tools: Vec<Arc<dyn ToolAdapter>> = vec![Arc::new(DriveReaderAdapter)];
+    // Register tools; all of them work offline against local files.
+    let governance = GovernanceRegistryAdapter::load(Path::new("aln")).unwrap_or_else(|e| {
+        eprintln!("router startup failed: {}", e);
+        std::process::exit(1);
+    });
+    let tools = ToolRegistry::new()
+        .register(Arc::new(DriveReaderAdapter))
+        .register(Arc::new(governance))
+        .register(Arc::new(AnalysisEngineAdapter::new("cyber_retrieval.log")))
+        .register(Arc::new(SimulationAdapter::default()))
+        .register(Arc::new(LoggingAdapter::new(Arc::new(FileLogSink::new("cyber_retrieval.review.log")))));
     let log_sink = Arc::new(FileLogSink::new("cyber_retrieval.log"));
+    let routes = RouteTable::compiled();
+
+    // Levels no tool admits are denied per request; say so up front.
+    for (intent, level) in tools.unrouted(&routes) {
+        eprintln!("router: no tool admits {:?} {:?} requests; they will be denied", level, intent);
+    }
 
-    let router = CyberRetrievalRouter::new(tools, log_sink, 0.3);
+    // Refuse to start while any intent has no tool to route to.
+    let router = CyberRetrievalRouter::new(tools, routes, log_sink, DEFAULT_RISK_THRESHOLD).unwrap_or_else(|e| {
+        eprintln!("router startup failed: {}", e);
+        std::process::exit(1);
+    });
 
     // Example normalized call.
     let raw = RawPrompt {
@@ -1,6 +1,6 @@
 use std::time::{SystemTime, UNIX_EPOCH};
 use serde_json::Value;
-use crate::domain::{PromptEnvelope, Intent, SecurityLevel};
+use crate::domain::{PromptEnvelope, Identity, Intent, SecurityLevel};
 use crate::authorship::AuthorshipConfig;
 use crate::trace::{make_trace_id, make_args};
 
@@ -39,6 +39,28 @@ pub fn normalize_prompt(
     }
 }
 
+/// The router's view of an envelope the neurorights firewall is binding, so the router can
+/// score it. Unrecognised security levels are read as `Sensitive`.
+pub fn from_firewall(env: &neurorights_firewall::PromptEnvelope) -> PromptEnvelope {
+    let security_level = SecurityLevel::ALL
+        .into_iter()
+        .find(|level| format!("{:?}", level).eq_ignore_ascii_case(&env.security_level))
+        .unwrap_or(SecurityLevel::Sensitive);
+    let intent = Intent::ALL
+        .into_iter()
+        .find(|intent| format!("{:?}", intent).eq_ignore_ascii_case(&env.intent))
+        .unwrap_or_else(|| infer_intent(&env.intent, None));
+
+    PromptEnvelope {
+        trace_id: env.trace_id.clone(),
+        intent,
+        args: env.args.clone(),
+        security_level,
+        identity: Identity { user_did: env.identity.clone(), aln: None, bostrom_address: None },
+        created_at: SystemTime::now(),
+    }
+}
+
 /// Very conservative, deterministic intent inference.
 fn infer_intent(text: &str, hint: Option<Intent>) -> Intent {
     if let Some(h) = hint {
@@ -0,0 +1,413 @@
+use std::collections::BTreeSet;
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use crate::domain::SecurityLevel;
+
+/// Kinds of personal data the scanner recognises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum PiiKind {
+    Email,
+    Phone,
+    Did,
+    Bech32Address,
+    GovernmentId,
+    HealthTerm,
+}
+
+impl PiiKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            PiiKind::Email => "email",
+            PiiKind::Phone => "phone",
+            PiiKind::Did => "did",
+            PiiKind::Bech32Address => "bech32",
+            PiiKind::GovernmentId => "government_id",
+            PiiKind::HealthTerm => "health",
+        }
+    }
+}
+
+/// A match as a byte range into the scanned text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PiiFinding {
+    pub kind: PiiKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+const HEALTH_TERMS: &[&str] = &[
+    "diagnosis", "diagnosed", "epilepsy", "epileptic", "seizure", "seizures", "depression",
+    "anxiety", "schizophrenia", "bipolar", "ptsd", "adhd", "autism", "dementia", "alzheimers",
+    "parkinsons", "hiv", "diabetes", "medication", "prescription", "pregnant",
+    "pregnancy", "psychiatric", "overdose",
+];
+
+/// Health terms common in research text; personal data only right after a personal
+/// pronoun ("my cancer", "I'm in therapy"), not in "gene therapy research".
+const PERSONAL_HEALTH_TERMS: &[&str] = &["cancer", "therapy"];
+
+const PERSONAL_MARKERS: &[&str] = &[
+    "i", "im", "ive", "me", "my", "mine", "we", "our", "you", "your", "he", "his", "she", "her",
+    "they", "their",
+];
+
+/// How many tokens before a `PERSONAL_HEALTH_TERMS` entry a personal marker may appear.
+const PERSONAL_WINDOW: usize = 2;
+
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Whether `level` requires `kind` to be redacted before logging.
+/// DIDs stay visible for audit at every level; wallet addresses are public only for
+/// `Public` requests; everything else is always redacted.
+pub fn redacts(level: SecurityLevel, kind: PiiKind) -> bool {
+    match kind {
+        PiiKind::Did => false,
+        PiiKind::Bech32Address => !matches!(level, SecurityLevel::Public),
+        PiiKind::Email | PiiKind::Phone | PiiKind::GovernmentId | PiiKind::HealthTerm => true,
+    }
+}
+
+/// All findings in `text`, ordered by position and never overlapping.
+pub fn scan_text(text: &str) -> Vec<PiiFinding> {
+    let mut findings = Vec::new();
+    let tokens: Vec<(usize, &str)> = tokens(text).collect();
+    let bare = |token: &str| token.to_ascii_lowercase().replace('\'', "");
+
+    for (i, &(start, token)) in tokens.iter().enumerate() {
+        let lower = bare(token);
+        let personal = || {
+            tokens[i.saturating_sub(PERSONAL_WINDOW)..i]
+                .iter()
+                .any(|(_, t)| PERSONAL_MARKERS.contains(&bare(t).as_str()))
+        };
+        let kind = if is_email(token) {
+            Some(PiiKind::Email)
+        } else if is_did(token) {
+            Some(PiiKind::Did)
+        } else if is_bech32(token) {
+            Some(PiiKind::Bech32Address)
+        } else if is_government_id(token) {
+            Some(PiiKind::GovernmentId)
+        } else if HEALTH_TERMS.contains(&lower.as_str())
+            || (PERSONAL_HEALTH_TERMS.contains(&lower.as_str()) && personal())
+        {
+            Some(PiiKind::HealthTerm)
+        } else {
+            None
+        };
+        if let Some(kind) = kind {
+            findings.push(PiiFinding { kind, start, end: start + token.len() });
+        }
+    }
+
+    // Phone numbers may span tokens ("+1 555 010 9999"), so they are found on raw text.
+    for (start, end) in phone_spans(text) {
+        if !findings.iter().any(|f| f.start < end && start < f.end) {
+            findings.push(PiiFinding { kind: PiiKind::Phone, start, end });
+        }
+    }
+    findings.sort_by_key(|f| f.start);
+    findings
+}
+
+/// Kinds found anywhere in `args`: string values, object keys, and numbers stored
+/// under a key that names a phone number or government ID.
+pub fn scan_args(args: &Value) -> BTreeSet<PiiKind> {
+    let mut kinds = BTreeSet::new();
+    visit(args, None, &mut |value| match value {
+        Scalar::Text(s) => kinds.extend(scan_text(s).into_iter().map(|f| f.kind)),
+        Scalar::Number(n, key) => kinds.extend(scan_number(n, key)),
+    });
+    kinds
+}
+
+/// A bare number carries no phone or ID formatting, so it counts only under a key such
+/// as `phone` or `ssn`; `{"since": 1708357200}` is a timestamp, not a phone number.
+pub fn scan_number(n: &serde_json::Number, key: Option<&str>) -> Option<PiiKind> {
+    let key = key?.to_ascii_lowercase();
+    let mut words = key.split(|c: char| !c.is_ascii_alphanumeric());
+    let kind = words.find_map(|word| match word {
+        "phone" | "tel" | "telephone" | "mobile" | "cell" | "fax" | "callback" | "msisdn" => Some(PiiKind::Phone),
+        "ssn" | "nino" => Some(PiiKind::GovernmentId),
+        _ => None,
+    })?;
+    (n.is_u64() || n.is_i64()).then_some(kind)
+}
+
+/// `text` with every finding `level` redacts replaced by `[REDACTED:<kind>]`.
+pub fn redact_text(text: &str, level: SecurityLevel) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for f in scan_text(text).into_iter().filter(|f| redacts(level, f.kind)) {
+        out.push_str(&text[last..f.start]);
+        out.push_str("[REDACTED:");
+        out.push_str(f.kind.label());
+        out.push(']');
+        last = f.end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+/// Copy of `args` safe to write to the log; the original stays with the tool call.
+/// Keys are redacted like values, numbered `#1`, `#2`, … within their object so that no
+/// entry collapses into another; a redacted number becomes a `[REDACTED:<kind>]` string.
+pub fn redact_args(args: &Value, level: SecurityLevel) -> Value {
+    redact_value(args, None, level)
+}
+
+fn redact_value(value: &Value, key: Option<&str>, level: SecurityLevel) -> Value {
+    match value {
+        Value::String(s) => Value::String(redact_text(s, level)),
+        Value::Number(n) => match scan_number(n, key).filter(|&kind| redacts(level, kind)) {
+            Some(kind) => Value::String(format!("[REDACTED:{}]", kind.label())),
+            None => value.clone(),
+        },
+        Value::Array(items) => Value::Array(items.iter().map(|v| redact_value(v, key, level)).collect()),
+        Value::Object(map) => {
+            let mut out = serde_json::Map::new();
+            let mut redacted = 0;
+            for (k, v) in map {
+                let mut out_key = redact_text(k, level);
+                if out_key != *k {
+                    out_key = loop {
+                        redacted += 1;
+                        let numbered = format!("{}#{}", out_key, redacted);
+                        if !map.contains_key(&numbered) {
+                            break numbered;
+                        }
+                    };
+                }
+                out.insert(out_key, redact_value(v, Some(k), level));
+            }
+            Value::Object(out)
+        }
+        other => other.clone(),
+    }
+}
+
+enum Scalar<'a> {
+    Text(&'a str),
+    /// A number and the key it is stored under, if any.
+    Number(&'a serde_json::Number, Option<&'a str>),
+}
+
+fn visit<'a>(value: &'a Value, key: Option<&'a str>, f: &mut impl FnMut(Scalar<'a>)) {
+    match value {
+        Value::String(s) => f(Scalar::Text(s)),
+        Value::Number(n) => f(Scalar::Number(n, key)),
+        Value::Array(items) => items.iter().for_each(|v| visit(v, key, f)),
+        Value::Object(map) => map.iter().for_each(|(k, v)| {
+            f(Scalar::Text(k));
+            visit(v, Some(k), f)
+        }),
+        _ => {}
+    }
+}
+
+// Whitespace-separated tokens with surrounding punctuation trimmed, and their byte offsets.
+fn tokens(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    const PUNCT: &[char] = &[',', '.', ';', ':', '!', '?', '(', ')', '[', ']', '{', '}', '"', '\'', '<', '>'];
+    text.split_whitespace().filter_map(move |raw| {
+        let offset = raw.as_ptr() as usize - text.as_ptr() as usize;
+        let trimmed_start = raw.trim_start_matches(PUNCT);
+        let token = trimmed_start.trim_end_matches(PUNCT);
+        (!token.is_empty()).then(|| (offset + raw.len() - trimmed_start.len(), token))
+    })
+}
+
+fn is_email(token: &str) -> bool {
+    match token.split_once('@') {
+        Some((user, host)) => {
+            !user.is_empty()
+                && host.split('.').count() >= 2
+                && host.split('.').all(|part| !part.is_empty() && part.chars().all(|c| c.is_alphanumeric() || c == '-'))
+        }
+        None => false,
+    }
+}
+
+// did:<method>:<method-specific-id>
+fn is_did(token: &str) -> bool {
+    let mut parts = token.split(':');
+    parts.next() == Some("did")
+        && parts.next().is_some_and(|m| !m.is_empty() && m.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()))
+        && parts.next().is_some_and(|id| !id.is_empty())
+}
+
+// <hrp>1<data>, with at least a 20-byte payload plus checksum in the bech32 alphabet.
+fn is_bech32(token: &str) -> bool {
+    let Some((hrp, data)) = token.rsplit_once('1') else { return false };
+    !hrp.is_empty()
+        && hrp.len() <= 83
+        && hrp.chars().all(|c| c.is_ascii_lowercase())
+        && data.len() >= 38
+        && data.chars().all(|c| BECH32_CHARSET.contains(c))
+}
+
+// US SSN (123-45-6789) or UK National Insurance number (AB123456C).
+fn is_government_id(token: &str) -> bool {
+    let b = token.as_bytes();
+    let digits = |range: std::ops::Range<usize>| b[range].iter().all(u8::is_ascii_digit);
+    let ssn = b.len() == 11 && b[3] == b'-' && b[6] == b'-' && digits(0..3) && digits(4..6) && digits(7..11);
+    let nino = b.len() == 9
+        && b[..2].iter().all(u8::is_ascii_uppercase)
+        && digits(2..8)
+        && (b'A'..=b'D').contains(&b[8]);
+    ssn || nino
+}
+
+// Phone-shaped runs holding 10–15 digits: a leading `+`, or groups of 2–4 digits split by
+// separators ("(555) 010-9999"). A bare digit run such as an epoch timestamp, a date
+// ("2024-01-15") or a run ending in a time ("10:30") is not a phone number.
+fn phone_spans(text: &str) -> Vec<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !(bytes[i].is_ascii_digit() || (bytes[i] == b'+' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let mut end = i;
+        let mut groups: Vec<usize> = Vec::new();
+        let mut in_group = false;
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || b"+-.() ".contains(&bytes[i])) {
+            if bytes[i].is_ascii_digit() {
+                if !in_group {
+                    groups.push(0);
+                }
+                *groups.last_mut().unwrap() += 1;
+                in_group = true;
+                end = i + 1;
+            } else {
+                in_group = false;
+            }
+            i += 1;
+        }
+        let digits: usize = groups.iter().sum();
+        let word_edge = |at: usize| at == 0 || at >= bytes.len() || !bytes[at].is_ascii_alphanumeric();
+        let international = bytes[start] == b'+';
+        let grouped = groups.len() >= 2 && groups[0] <= 4 && groups[1..].iter().all(|g| (2..=4).contains(g));
+        let date = groups.len() >= 3 && groups[..3] == [4, 2, 2];
+        let time = bytes.get(end) == Some(&b':');
+        if (10..=15).contains(&digits)
+            && (international || grouped)
+            && !date
+            && !time
+            && word_edge(start.wrapping_sub(1))
+            && word_edge(end)
+        {
+            spans.push((start, end));
+        }
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner_finds_each_kind() {
+        let text = "Mail jane.doe@example.org or call +1 (555) 010-9999. \
+                    I am did:ion:EiD7x, wallet bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7, \
+                    SSN 123-45-6789, diagnosed with epilepsy.";
+        let kinds: Vec<PiiKind> = scan_text(text).into_iter().map(|f| f.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                PiiKind::Email,
+                PiiKind::Phone,
+                PiiKind::Did,
+                PiiKind::Bech32Address,
+                PiiKind::GovernmentId,
+                PiiKind::HealthTerm,
+                PiiKind::HealthTerm,
+            ]
+        );
+        assert!(scan_text("Retrieve prosthetic grip research from 2024 (trial 12).").is_empty());
+    }
+
+    #[test]
+    fn test_redaction_depends_on_security_level() {
+        let text = "reach bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7 or a@b.io";
+        assert_eq!(
+            redact_text(text, SecurityLevel::Public),
+            "reach bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7 or [REDACTED:email]"
+        );
+        assert_eq!(
+            redact_text(text, SecurityLevel::Restricted),
+            "reach [REDACTED:bech32] or [REDACTED:email]"
+        );
+        assert_eq!(redact_text("owner did:web:example.org", SecurityLevel::Sensitive), "owner did:web:example.org");
+    }
+
+    #[test]
+    fn test_args_are_scanned_and_redacted_recursively() {
+        let args = serde_json::json!({
+            "base": { "prompt": "my medication list" },
+            "extra": { "contacts": ["+44 20 7946 0958"], "n": 3 }
+        });
+        let kinds = scan_args(&args);
+        assert_eq!(kinds, BTreeSet::from([PiiKind::Phone, PiiKind::HealthTerm]));
+
+        let redacted = redact_args(&args, SecurityLevel::Sensitive);
+        assert_eq!(redacted["base"]["prompt"], "my [REDACTED:health] list");
+        assert_eq!(redacted["extra"]["contacts"][0], "[REDACTED:phone]");
+        assert_eq!(redacted["extra"]["n"], 3);
+        assert_eq!(args["base"]["prompt"], "my medication list");
+    }
+
+    #[test]
+    fn test_keys_and_numbers_are_scanned_and_redacted() {
+        let args = serde_json::json!({
+            "jane@example.org": { "callback": 15550109999_u64, "trial": 12, "dose_mg": 2.5 },
+        });
+        assert_eq!(scan_args(&args), BTreeSet::from([PiiKind::Email, PiiKind::Phone]));
+
+        let redacted = redact_args(&args, SecurityLevel::Sensitive);
+        let inner = &redacted["[REDACTED:email]#1"];
+        assert_eq!(inner["callback"], "[REDACTED:phone]");
+        assert_eq!(inner["trial"], 12);
+        assert_eq!(inner["dose_mg"], 2.5);
+        assert!(!redacted.to_string().contains("jane"));
+    }
+
+    #[test]
+    fn test_redacted_keys_stay_distinct() {
+        let args = serde_json::json!({ "a@b.io": 1, "c@d.io": 2, "[REDACTED:email]#1": 3 });
+        let redacted = redact_args(&args, SecurityLevel::Sensitive);
+        let map = redacted.as_object().unwrap();
+        assert_eq!(map.len(), 3);
+        let mut values: Vec<i64> = map.values().map(|v| v.as_i64().unwrap()).collect();
+        values.sort();
+        assert_eq!(values, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_timestamps_and_dates_are_not_phone_numbers() {
+        let args = serde_json::json!({ "since": 1708357200, "prompt": "logs since epoch 1708357200" });
+        assert!(scan_args(&args).is_empty());
+        assert_eq!(redact_args(&args, SecurityLevel::Sensitive), args);
+
+        let text = "meeting at 2024-01-15 10:30, or call 555-010-9999";
+        assert_eq!(redact_text(text, SecurityLevel::Sensitive), "meeting at 2024-01-15 10:30, or call [REDACTED:phone]");
+        assert!(scan_text("items 1 2 3 4 5 6 7 8 9 10").is_empty());
+        assert_eq!(scan_text("+15550109999")[0].kind, PiiKind::Phone);
+        assert_eq!(scan_number(&1708357200.into(), Some("phone_number")), Some(PiiKind::Phone));
+    }
+
+    #[test]
+    fn test_research_health_terms_need_personal_context() {
+        for text in ["gene therapy research", "Retrieve cancer screening literature", "I am researching cancer therapy"] {
+            assert!(scan_text(text).is_empty(), "{}", text);
+            assert!(scan_args(&serde_json::json!({ "prompt": text })).is_empty(), "{}", text);
+        }
+        for text in ["my cancer came back", "I'm in therapy twice a week", "her therapy notes"] {
+            let kinds: Vec<PiiKind> = scan_text(text).into_iter().map(|f| f.kind).collect();
+            assert_eq!(kinds, [PiiKind::HealthTerm], "{}", text);
+        }
+    }
+}
@@ -0,0 +1,409 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::Arc;
+use crate::classify::subject_tag;
+use crate::domain::{PromptEnvelope, Metadata, Intent, SecurityLevel, SubjectTag};
+use crate::tools::{ToolAdapter, ToolError};
+
+/// Route table compiled into the router; reviewed like any other shard.
+pub const ROUTES_SHARD: &str = include_str!("../aln/router.routes.v1.aln");
+
+/// Neurorights envelope the router runs under; tools may require its flags.
+pub const ENVELOPE_SHARD: &str = include_str!("../aln/neurorights.envelope.citizen.v1.aln");
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RouteError {
+    Parse(aln::ParseError),
+    Hexstamp(aln::HexstampError),
+    Missing(&'static str),
+    UnknownTag { section: &'static str, tag: String },
+    /// Intents whose fallback chain has no registered tool that admits them.
+    Unrouted(Vec<Intent>),
+}
+
+impl fmt::Display for RouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RouteError::Parse(e) => write!(f, "route shard:{}", e),
+            RouteError::Hexstamp(e) => write!(f, "route shard: {}", e),
+            RouteError::Missing(key) => write!(f, "route shard has no `{}`", key),
+            RouteError::UnknownTag { section, tag } => {
+                write!(f, "route shard: unknown tag {} in {}", tag, section)
+            }
+            RouteError::Unrouted(intents) => write!(f, "no registered tool serves intents {:?}", intents),
+        }
+    }
+}
+
+impl std::error::Error for RouteError {}
+
+#[derive(Debug, Clone)]
+struct Route {
+    intent: Intent,
+    /// `None` for the intent-wide `any` chain.
+    subject: Option<SubjectTag>,
+    chain: Vec<String>,
+}
+
+/// Declarative intent/subject → tool fallback chains, loaded from a versioned ALN shard.
+#[derive(Debug, Clone)]
+pub struct RouteTable {
+    pub id: String,
+    pub version: String,
+    read_only_intents: Vec<Intent>,
+    routes: Vec<Route>,
+}
+
+impl RouteTable {
+    /// The shard compiled into this binary.
+    pub fn compiled() -> Self {
+        Self::from_shard(ROUTES_SHARD).expect("compiled route shard is valid")
+    }
+
+    /// Parse a route shard; its hexstamp must match its content.
+    pub fn from_shard(text: &str) -> Result<Self, RouteError> {
+        let doc = verified(text)?;
+        let text_at = |path: &'static str| {
+            doc.path(path).and_then(aln::Node::as_text).ok_or(RouteError::Missing(path))
+        };
+
+        let read_only_intents = doc
+            .get("read_only_intents")
+            .and_then(aln::Node::as_list)
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(aln::Node::as_text)
+            .map(|name| intent_tag(name).ok_or_else(|| unknown("read_only_intents", name)))
+            .collect::<Result<_, _>>()?;
+
+        let mut routes = Vec::new();
+        let by_intent = doc.get("routes").and_then(aln::Node::as_map).ok_or(RouteError::Missing("routes"))?;
+        for (key, subjects) in by_intent {
+            let intent = intent_tag(&key.name).ok_or_else(|| unknown("routes", &key.name))?;
+            for (key, chain) in subjects.as_map().unwrap_or(&[]) {
+                let subject = match key.name.as_str() {
+                    "any" => None,
+                    name => Some(subject_tag(name).ok_or_else(|| unknown("routes", name))?),
+                };
+                let chain = chain
+                    .as_list()
+                    .unwrap_or(&[])
+                    .iter()
+                    .filter_map(aln::Node::as_text)
+                    .map(String::from)
+                    .collect();
+                routes.push(Route { intent, subject, chain });
+            }
+        }
+
+        Ok(Self {
+            id: text_at("id")?.to_string(),
+            version: text_at("version")?.to_string(),
+            read_only_intents,
+            routes,
+        })
+    }
+
+    /// Tool names to try in order: the subject's chain, then the intent's `any` chain.
+    /// `None` gives only the `any` chain, which every subject falls back to.
+    pub fn chain(&self, intent: Intent, subject: Option<SubjectTag>) -> Vec<&str> {
+        let mut chain: Vec<&str> = Vec::new();
+        let specific = self.routes.iter().filter(|r| subject.is_some() && r.subject == subject);
+        let fallback = self.routes.iter().filter(|r| r.subject.is_none());
+        for route in specific.chain(fallback).filter(|r| r.intent == intent) {
+            for name in &route.chain {
+                if !chain.contains(&name.as_str()) {
+                    chain.push(name);
+                }
+            }
+        }
+        chain
+    }
+
+    pub fn requires_read_only(&self, intent: Intent) -> bool {
+        self.read_only_intents.contains(&intent)
+    }
+}
+
+impl Default for RouteTable {
+    fn default() -> Self {
+        Self::compiled()
+    }
+}
+
+/// `section.key` of every flag the envelope shard sets to true, e.g. `rights.mentalprivacy`.
+pub fn envelope_flags(text: &str) -> Result<BTreeSet<String>, RouteError> {
+    let doc = verified(text)?;
+    let mut flags = BTreeSet::new();
+    for (section, node) in doc.root.as_map().unwrap_or(&[]) {
+        for (key, value) in node.as_map().unwrap_or(&[]) {
+            if value.as_bool() == Some(true) {
+                flags.insert(format!("{}.{}", section.name, key.name));
+            }
+        }
+    }
+    Ok(flags)
+}
+
+/// Registered tools and the envelope flags they run under.
+pub struct ToolRegistry {
+    tools: Vec<Arc<dyn ToolAdapter>>,
+    flags: BTreeSet<String>,
+}
+
+impl ToolRegistry {
+    /// Empty registry under the compiled citizen envelope.
+    pub fn new() -> Self {
+        Self::with_flags(envelope_flags(ENVELOPE_SHARD).expect("compiled envelope shard is valid"))
+    }
+
+    pub fn with_flags(flags: BTreeSet<String>) -> Self {
+        Self { tools: Vec::new(), flags }
+    }
+
+    /// Add a tool, replacing any registered under the same name.
+    pub fn register(mut self, tool: Arc<dyn ToolAdapter>) -> Self {
+        self.tools.retain(|t| t.name() != tool.name());
+        self.tools.push(tool);
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn ToolAdapter>> {
+        self.tools.iter().find(|t| t.name() == name)
+    }
+
+    /// Startup check: every intent's `any` chain must reach a tool that serves it at
+    /// `Public`. Intents that no tool admits at a higher level still pass; their requests
+    /// are denied at that level, and `unrouted` lists them.
+    pub fn check(&self, routes: &RouteTable) -> Result<(), RouteError> {
+        let unrouted: Vec<Intent> = self
+            .unrouted(routes)
+            .into_iter()
+            .filter(|(_, level)| *level == SecurityLevel::Public)
+            .map(|(intent, _)| intent)
+            .collect();
+        if unrouted.is_empty() {
+            Ok(())
+        } else {
+            Err(RouteError::Unrouted(unrouted))
+        }
+    }
+
+    /// Intent and security level pairs whose `any` chain has no tool admitting them.
+    pub fn unrouted(&self, routes: &RouteTable) -> Vec<(Intent, SecurityLevel)> {
+        Intent::ALL
+            .into_iter()
+            .flat_map(|intent| SecurityLevel::ALL.map(|level| (intent, level)))
+            .filter(|&(intent, level)| {
+                !routes
+                    .chain(intent, None)
+                    .into_iter()
+                    .filter_map(|name| self.get(name))
+                    .any(|tool| self.admits(routes, tool.as_ref(), intent, level))
+            })
+            .collect()
+    }
+
+    /// First tool on the request's fallback chain whose capabilities admit it.
+    pub fn route(
+        &self,
+        routes: &RouteTable,
+        envelope: &PromptEnvelope,
+        metadata: &Metadata,
+    ) -> Result<Arc<dyn ToolAdapter>, ToolError> {
+        let chain = routes.chain(envelope.intent, Some(metadata.subject));
+        chain
+            .iter()
+            .filter_map(|name| self.get(name))
+            .find(|tool| self.admits(routes, tool.as_ref(), envelope.intent, envelope.security_level))
+            .cloned()
+            .ok_or_else(|| {
+                ToolError::Denied(format!(
+                    "No tool on route [{}] admits {:?} {:?} requests",
+                    chain.join(", "),
+                    envelope.security_level,
+                    envelope.intent
+                ))
+            })
+    }
+
+    fn admits(&self, routes: &RouteTable, tool: &dyn ToolAdapter, intent: Intent, level: SecurityLevel) -> bool {
+        let caps = tool.capabilities();
+        caps.intents.contains(&intent)
+            && level <= caps.max_security
+            && (caps.read_only || !routes.requires_read_only(intent))
+            && caps.required_flags.iter().all(|flag| self.flags.contains(*flag))
+    }
+}
+
+impl Default for ToolRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn verified(text: &str) -> Result<aln::Document, RouteError> {
+    let doc = aln::parse(text).map_err(RouteError::Parse)?;
+    aln::hexstamp::verify(&doc).map_err(RouteError::Hexstamp)?;
+    Ok(doc)
+}
+
+fn unknown(section: &'static str, tag: &str) -> RouteError {
+    RouteError::UnknownTag { section, tag: tag.to_string() }
+}
+
+fn intent_tag(name: &str) -> Option<Intent> {
+    Intent::ALL.into_iter().find(|tag| format!("{:?}", tag).eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+    use async_trait::async_trait;
+    use serde_json::Value;
+    use crate::domain::{CodexType, Identity, PurposeTag, RiskAssessment};
+    use crate::tools::ToolCapabilities;
+
+    struct Fake(&'static str, ToolCapabilities);
+
+    #[async_trait]
+    impl ToolAdapter for Fake {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        fn capabilities(&self) -> ToolCapabilities {
+            self.1
+        }
+
+        async fn execute(&self, _: &PromptEnvelope, _: &Metadata, _: &RiskAssessment) -> Result<Value, ToolError> {
+            Ok(Value::Null)
+        }
+    }
+
+    fn tool(name: &'static str, intents: &'static [Intent], read_only: bool) -> Arc<dyn ToolAdapter> {
+        Arc::new(Fake(
+            name,
+            ToolCapabilities { intents, max_security: SecurityLevel::Restricted, read_only, required_flags: &[] },
+        ))
+    }
+
+    fn full_registry() -> ToolRegistry {
+        ToolRegistry::new()
+            .register(tool("drive_reader", &[Intent::Retrieve], true))
+            .register(tool("governance_registry", &[Intent::Retrieve, Intent::Governance], true))
+            .register(tool("analysis_engine", &[Intent::Analyze], true))
+            .register(tool("simulation_adapter", &[Intent::Analyze, Intent::Plan, Intent::Simulate], true))
+            .register(tool("logging_adapter", &Intent::ALL, false))
+    }
+
+    fn request(intent: Intent, security_level: SecurityLevel, subject: SubjectTag) -> (PromptEnvelope, Metadata) {
+        let envelope = PromptEnvelope {
+            trace_id: "0x01".into(),
+            intent,
+            args: serde_json::json!({ "prompt": "" }),
+            security_level,
+            identity: Identity { user_did: "did:example:user".into(), aln: None, bostrom_address: None },
+            created_at: SystemTime::UNIX_EPOCH,
+        };
+        let metadata = Metadata {
+            codex_type: CodexType::ResearchSpec,
+            drive_path: String::new(),
+            subject,
+            purpose: PurposeTag::Other,
+            subject_confidence: 0.0,
+            purpose_confidence: 0.0,
+            has_pii: false,
+            bio_risk_flag: false,
+            policy_relevant: false,
+        };
+        (envelope, metadata)
+    }
+
+    #[test]
+    fn test_compiled_table_chains_subject_before_fallback() {
+        let routes = RouteTable::compiled();
+        assert_eq!((routes.id.as_str(), routes.version.as_str()), ("router.routes.v1", "1.0.0"));
+        assert_eq!(routes.chain(Intent::Retrieve, Some(SubjectTag::Governance)), ["governance_registry", "drive_reader"]);
+        assert_eq!(routes.chain(Intent::Retrieve, Some(SubjectTag::Ethics)), ["drive_reader"]);
+        assert!(routes.requires_read_only(Intent::Analyze) && !routes.requires_read_only(Intent::Plan));
+
+        let flags = envelope_flags(ENVELOPE_SHARD).unwrap();
+        assert!(flags.contains("rights.mentalprivacy") && flags.contains("governance.noNeuralInputsForGovernance"));
+    }
+
+    #[test]
+    fn test_startup_check_reports_unrouted_intents() {
+        let routes = RouteTable::compiled();
+        assert_eq!(full_registry().check(&routes), Ok(()));
+
+        let partial = ToolRegistry::new().register(tool("drive_reader", &[Intent::Retrieve], true));
+        assert_eq!(
+            partial.check(&routes),
+            Err(RouteError::Unrouted(vec![Intent::Analyze, Intent::Plan, Intent::Simulate, Intent::Governance, Intent::Unknown]))
+        );
+
+        // A writing tool cannot serve a read-only intent, and a missing flag disables a tool.
+        let writer = ToolRegistry::new().register(tool("drive_reader", &[Intent::Retrieve], false));
+        assert!(matches!(writer.check(&routes), Err(RouteError::Unrouted(i)) if i.contains(&Intent::Retrieve)));
+        let strict = Arc::new(Fake(
+            "drive_reader",
+            ToolCapabilities {
+                intents: &[Intent::Retrieve],
+                max_security: SecurityLevel::Sensitive,
+                read_only: true,
+                required_flags: &["rights.telepathy"],
+            },
+        ));
+        let unflagged = ToolRegistry::new().register(strict);
+        assert!(matches!(unflagged.check(&routes), Err(RouteError::Unrouted(i)) if i.contains(&Intent::Retrieve)));
+    }
+
+    #[test]
+    fn test_unrouted_reports_each_security_level() {
+        let routes = RouteTable::compiled();
+        // Every fake tool stops at Restricted, so Sensitive requests reach nothing.
+        let registry = full_registry();
+        assert_eq!(registry.check(&routes), Ok(()));
+        let unrouted = registry.unrouted(&routes);
+        assert_eq!(unrouted, Intent::ALL.map(|intent| (intent, SecurityLevel::Sensitive)));
+
+        // check still passes, but the gap is visible and route denies the request.
+        let (envelope, metadata) = request(Intent::Simulate, SecurityLevel::Sensitive, SubjectTag::Other);
+        assert!(matches!(registry.route(&routes, &envelope, &metadata), Err(ToolError::Denied(_))));
+
+        let public_only = Arc::new(Fake(
+            "simulation_adapter",
+            ToolCapabilities {
+                intents: &[Intent::Simulate],
+                max_security: SecurityLevel::Public,
+                read_only: true,
+                required_flags: &[],
+            },
+        ));
+        let narrowed = full_registry().register(public_only).unrouted(&routes);
+        assert!(narrowed.contains(&(Intent::Simulate, SecurityLevel::Restricted)));
+        assert!(!narrowed.contains(&(Intent::Simulate, SecurityLevel::Public)));
+    }
+
+    #[test]
+    fn test_route_walks_the_fallback_chain() {
+        let routes = RouteTable::compiled();
+        let registry = full_registry();
+        let pick = |intent, level, subject| {
+            let (envelope, metadata) = request(intent, level, subject);
+            registry.route(&routes, &envelope, &metadata).map(|t| t.name())
+        };
+
+        assert_eq!(pick(Intent::Retrieve, SecurityLevel::Public, SubjectTag::Governance).unwrap(), "governance_registry");
+        assert_eq!(pick(Intent::Analyze, SecurityLevel::Public, SubjectTag::Simulation).unwrap(), "simulation_adapter");
+        assert_eq!(pick(Intent::Unknown, SecurityLevel::Restricted, SubjectTag::Other).unwrap(), "logging_adapter");
+        assert!(matches!(pick(Intent::Retrieve, SecurityLevel::Sensitive, SubjectTag::Other), Err(ToolError::Denied(_))));
+
+        // Without the registry, governance requests fall back to the review log.
+        let fallback = ToolRegistry::new().register(tool("logging_adapter", &Intent::ALL, false));
+        let (envelope, metadata) = request(Intent::Governance, SecurityLevel::Public, SubjectTag::Governance);
+        assert_eq!(fallback.route(&routes, &envelope, &metadata).unwrap().name(), "logging_adapter");
+    }
+}
@@ -0,0 +1,379 @@
+use serde_json::Value;
+use crate::domain::{PromptEnvelope, Metadata, RiskAssessment, RiskContribution, Intent};
+use crate::pii::scan_args;
+
+/// What one stage found: a score in 0.0–1.0 and a short reason.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageScore {
+    pub score: f32,
+    pub rationale: String,
+    /// Block regardless of the combined score and the router's threshold.
+    pub red_flag: bool,
+}
+
+impl StageScore {
+    pub fn new(score: f32, rationale: impl Into<String>) -> Self {
+        Self { score: score.clamp(0.0, 1.0), rationale: rationale.into(), red_flag: false }
+    }
+}
+
+/// One stage of the risk pipeline; `None` means the stage found nothing.
+pub trait RiskAssessor: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn assess(&self, envelope: &PromptEnvelope, metadata: &Metadata) -> Option<StageScore>;
+}
+
+/// Weighted stages whose contributions add up to the final score. Whether a score
+/// blocks is the router's `risk_threshold`; the chain only reports stage red flags.
+pub struct RiskChain {
+    stages: Vec<(f32, Box<dyn RiskAssessor>)>,
+}
+
+impl RiskChain {
+    pub fn new() -> Self {
+        Self { stages: Vec::new() }
+    }
+
+    /// Lexicon, PII, bio-procedural and per-intent prior stages. Weighted so that one
+    /// weak signal (a PII match, a bio agent named, a lexicon term weighted 0.3) plus the
+    /// highest intent prior stays at or under 0.25.
+    pub fn standard() -> Self {
+        Self::new()
+            .with_stage(0.5, LexiconAssessor::default())
+            .with_stage(0.15, PiiAssessor)
+            .with_stage(0.6, BioProceduralAssessor)
+            .with_stage(1.0, IntentPrior)
+    }
+
+    pub fn with_stage(mut self, weight: f32, stage: impl RiskAssessor + 'static) -> Self {
+        self.stages.push((weight, Box::new(stage)));
+        self
+    }
+
+    pub fn assess(&self, envelope: &PromptEnvelope, metadata: &Metadata) -> RiskAssessment {
+        let mut contributions = Vec::new();
+        let mut red_flag = false;
+
+        for (weight, stage) in &self.stages {
+            if let Some(found) = stage.assess(envelope, metadata) {
+                red_flag |= found.red_flag;
+                contributions.push(RiskContribution {
+                    stage: stage.name().to_string(),
+                    weight: *weight,
+                    score: found.score,
+                    rationale: found.rationale,
+                });
+            }
+        }
+        contributions.sort_by(|a, b| (b.weight * b.score).total_cmp(&(a.weight * a.score)));
+
+        let risk_score = contributions.iter().map(|c| c.weight * c.score).sum::<f32>().clamp(0.0, 1.0);
+        let rationale = if contributions.is_empty() {
+            "No risk stage raised the score.".to_string()
+        } else {
+            contributions
+                .iter()
+                .map(|c| format!("{} +{:.2} ({})", c.stage, c.weight * c.score, c.rationale))
+                .collect::<Vec<_>>()
+                .join("; ")
+        };
+
+        RiskAssessment {
+            risk_score,
+            red_flag,
+            rationale,
+            contributions,
+        }
+    }
+}
+
+impl Default for RiskChain {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// All string values in `args`, space-separated, in document order.
+pub fn text_of(args: &Value) -> String {
+    fn collect(value: &Value, out: &mut String) {
+        match value {
+            Value::String(s) => {
+                out.push_str(s);
+                out.push(' ');
+            }
+            Value::Array(items) => items.iter().for_each(|v| collect(v, out)),
+            Value::Object(map) => map.values().for_each(|v| collect(v, out)),
+            _ => {}
+        }
+    }
+    let mut out = String::new();
+    collect(args, &mut out);
+    out
+}
+
+/// Lowercase words separated by single spaces, padded so ` term ` matches whole words.
+pub fn words(text: &str) -> String {
+    let mut out = String::from(" ");
+    for word in text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()) {
+        out.push_str(&word.to_lowercase());
+        out.push(' ');
+    }
+    out
+}
+
+/// Terms from `terms` found in text normalized by `words`.
+pub fn matches<'a>(words: &str, terms: &[&'a str]) -> Vec<&'a str> {
+    terms.iter().copied().filter(|t| words.contains(&format!(" {} ", t))).collect()
+}
+
+/// Weighted keyword lexicon; hits combine as 1 - Π(1 - w).
+/// Bio agents are left to `BioProceduralAssessor` so a mention is not counted twice.
+pub struct LexiconAssessor {
+    pub terms: Vec<(&'static str, f32)>,
+}
+
+impl Default for LexiconAssessor {
+    fn default() -> Self {
+        Self {
+            terms: vec![
+                ("weaponize", 0.9),
+                ("weaponise", 0.9),
+                ("bioweapon", 0.9),
+                ("nerve agent", 0.9),
+                ("bypass safety", 0.6),
+                ("disable safety", 0.6),
+                ("override consent", 0.7),
+                ("coerce", 0.5),
+                ("covert", 0.3),
+                ("exploit", 0.3),
+                ("untraceable", 0.3),
+            ],
+        }
+    }
+}
+
+impl RiskAssessor for LexiconAssessor {
+    fn name(&self) -> &'static str {
+        "lexicon"
+    }
+
+    fn assess(&self, envelope: &PromptEnvelope, _metadata: &Metadata) -> Option<StageScore> {
+        let text = words(&text_of(&envelope.args));
+        let hits: Vec<(&str, f32)> =
+            self.terms.iter().copied().filter(|(t, _)| text.contains(&format!(" {} ", t))).collect();
+        if hits.is_empty() {
+            return None;
+        }
+        let score = 1.0 - hits.iter().map(|(_, w)| 1.0 - w).product::<f32>();
+        let names: Vec<&str> = hits.iter().map(|(t, _)| *t).collect();
+        Some(StageScore::new(score, format!("terms: {}", names.join(", "))))
+    }
+}
+
+/// Personal data in the request raises risk.
+pub struct PiiAssessor;
+
+impl RiskAssessor for PiiAssessor {
+    fn name(&self) -> &'static str {
+        "pii"
+    }
+
+    fn assess(&self, envelope: &PromptEnvelope, metadata: &Metadata) -> Option<StageScore> {
+        let kinds = scan_args(&envelope.args);
+        if kinds.is_empty() && !metadata.has_pii {
+            return None;
+        }
+        let labels: Vec<&str> = kinds.iter().map(|k| k.label()).collect();
+        Some(StageScore::new(1.0, format!("personal data in args: {}", labels.join(", "))))
+    }
+}
+
+const BIO_AGENTS: &[&str] = &[
+    "pathogen", "virus", "viral", "bacteria", "bacterial", "toxin", "prion", "spore", "spores",
+    "anthrax", "botulinum", "crispr", "plasmid", "gain of function",
+];
+
+const BIO_PROCEDURES: &[&str] = &[
+    "synthesize", "synthesise", "culture", "cultivate", "amplify", "purify", "inoculate",
+    "aerosolize", "aerosolise", "transfect", "step by step", "dosage", "yield",
+    "increase transmissibility", "enhance transmissibility",
+];
+
+/// Words that make "virus" and "viral" read as computing rather than biology.
+const NON_BIO_CONTEXT: &[&str] = &[
+    "computer", "malware", "antivirus", "ransomware", "trojan", "botnet", "network", "software",
+    "firmware",
+];
+
+/// Agents that are also everyday computing words.
+const AMBIGUOUS_AGENTS: &[&str] = &["virus", "viral"];
+
+/// Bio agents named together with wet-lab procedure language.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BioSignal {
+    pub agents: Vec<&'static str>,
+    pub procedures: Vec<&'static str>,
+}
+
+impl BioSignal {
+    pub fn scan(text: &str) -> Self {
+        let text = words(text);
+        let mut agents = matches(&text, BIO_AGENTS);
+        if !matches(&text, NON_BIO_CONTEXT).is_empty() {
+            agents.retain(|agent| !AMBIGUOUS_AGENTS.contains(agent));
+        }
+        Self { agents, procedures: matches(&text, BIO_PROCEDURES) }
+    }
+
+    /// Operational request: an agent and a procedure in the same prompt.
+    pub fn is_procedural(&self) -> bool {
+        !self.agents.is_empty() && !self.procedures.is_empty()
+    }
+}
+
+/// Flags operational wet-lab requests; naming an agent alone is only mildly risky.
+pub struct BioProceduralAssessor;
+
+impl RiskAssessor for BioProceduralAssessor {
+    fn name(&self) -> &'static str {
+        "bio_procedural"
+    }
+
+    fn assess(&self, envelope: &PromptEnvelope, _metadata: &Metadata) -> Option<StageScore> {
+        let signal = BioSignal::scan(&text_of(&envelope.args));
+        if signal.is_procedural() {
+            let terms = [signal.agents, signal.procedures].concat().join(", ");
+            let mut found = StageScore::new(1.0, format!("operational bio request: {}", terms));
+            found.red_flag = true;
+            Some(found)
+        } else if !signal.agents.is_empty() {
+            Some(StageScore::new(0.2, format!("bio agent named: {}", signal.agents.join(", "))))
+        } else {
+            None
+        }
+    }
+}
+
+/// Baseline risk per intent, before anything in the prompt is considered.
+pub struct IntentPrior;
+
+impl RiskAssessor for IntentPrior {
+    fn name(&self) -> &'static str {
+        "intent_prior"
+    }
+
+    fn assess(&self, envelope: &PromptEnvelope, _metadata: &Metadata) -> Option<StageScore> {
+        let score = match envelope.intent {
+            Intent::Retrieve => 0.02,
+            Intent::Analyze | Intent::Governance => 0.04,
+            Intent::Simulate => 0.06,
+            Intent::Plan => 0.08,
+            Intent::Unknown => 0.1,
+        };
+        Some(StageScore::new(score, format!("{:?}", envelope.intent)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+    use crate::domain::{CodexType, Identity, PurposeTag, SecurityLevel, SubjectTag};
+    use crate::router::DEFAULT_RISK_THRESHOLD;
+
+    fn envelope(intent: Intent, prompt: &str) -> PromptEnvelope {
+        PromptEnvelope {
+            trace_id: "0x01".into(),
+            intent,
+            args: serde_json::json!({ "prompt": prompt }),
+            security_level: SecurityLevel::Public,
+            identity: Identity { user_did: "did:example:user".into(), aln: None, bostrom_address: None },
+            created_at: SystemTime::UNIX_EPOCH,
+        }
+    }
+
+    fn metadata() -> Metadata {
+        Metadata {
+            codex_type: CodexType::ResearchSpec,
+            drive_path: String::new(),
+            subject: SubjectTag::Other,
+            purpose: PurposeTag::Other,
+            subject_confidence: 0.0,
+            purpose_confidence: 0.0,
+            has_pii: false,
+            bio_risk_flag: false,
+            policy_relevant: false,
+        }
+    }
+
+    #[test]
+    fn test_benign_request_stays_under_threshold() {
+        let risk = RiskChain::standard().assess(&envelope(Intent::Retrieve, "Retrieve prosthetic grip research"), &metadata());
+        assert!(!risk.red_flag);
+        assert!((risk.risk_score - 0.02).abs() < 1e-6);
+        assert_eq!(risk.contributions.len(), 1);
+        assert_eq!(risk.rationale, "intent_prior +0.02 (Retrieve)");
+    }
+
+    #[test]
+    fn test_operational_bio_request_is_red_flagged_and_explained() {
+        let env = envelope(Intent::Plan, "Give a step by step protocol to culture the pathogen");
+        let risk = RiskChain::standard().assess(&env, &metadata());
+        assert!(risk.red_flag);
+        assert!(risk.risk_score >= DEFAULT_RISK_THRESHOLD);
+        assert_eq!(risk.contributions[0].stage, "bio_procedural");
+        assert!(risk.rationale.starts_with("bio_procedural +0.60 (operational bio request: pathogen, culture"));
+        assert!(!risk.rationale.contains("lexicon"));
+    }
+
+    #[test]
+    fn test_naming_a_bio_agent_stays_under_threshold() {
+        for prompt in ["Explain how the immune system recognizes a pathogen", "What is a toxin?"] {
+            let risk = RiskChain::standard().assess(&envelope(Intent::Unknown, prompt), &metadata());
+            assert!(!risk.red_flag, "{}: {}", prompt, risk.rationale);
+            assert!(risk.risk_score < DEFAULT_RISK_THRESHOLD, "{}: {}", prompt, risk.risk_score);
+            assert_eq!(risk.contributions.iter().filter(|c| c.stage == "lexicon").count(), 0);
+        }
+    }
+
+    #[test]
+    fn test_single_weak_signal_never_blocks() {
+        // The requests the review queue exists for: one weak signal on an unknown intent.
+        for prompt in ["reach me at jane@example.org", "is this an exploit?", "covert", "untraceable", "What is a prion?"] {
+            let risk = RiskChain::standard().assess(&envelope(Intent::Unknown, prompt), &metadata());
+            assert!(!risk.red_flag, "{}: {}", prompt, risk.rationale);
+            assert!(risk.risk_score <= 0.25 + 1e-6, "{}: {}", prompt, risk.rationale);
+        }
+
+        // A high score alone is the router's call, not a red flag.
+        let risk = RiskChain::standard().assess(&envelope(Intent::Unknown, "weaponize it, untraceable"), &metadata());
+        assert!(risk.risk_score >= DEFAULT_RISK_THRESHOLD);
+        assert!(!risk.red_flag);
+    }
+
+    #[test]
+    fn test_computing_text_is_not_a_bio_request() {
+        for prompt in [
+            "explain the network protocol this computer virus uses",
+            "step by step guide to remove a computer virus",
+            "how does ransomware go viral across a network",
+        ] {
+            let signal = BioSignal::scan(prompt);
+            assert!(signal.agents.is_empty(), "{}: {:?}", prompt, signal);
+            let risk = RiskChain::standard().assess(&envelope(Intent::Analyze, prompt), &metadata());
+            assert!(!risk.red_flag, "{}: {}", prompt, risk.rationale);
+            assert!(risk.contributions.iter().all(|c| c.stage != "bio_procedural"), "{}", risk.rationale);
+        }
+        assert!(BioSignal::scan("culture the virus step by step").is_procedural());
+    }
+
+    #[test]
+    fn test_custom_chain_and_pii_stage() {
+        let chain = RiskChain::new().with_stage(0.4, PiiAssessor);
+        let risk = chain.assess(&envelope(Intent::Analyze, "Contact me at jane@example.org"), &metadata());
+        assert!((risk.risk_score - 0.4).abs() < 1e-6);
+        assert!(!risk.red_flag);
+        assert!(chain.assess(&envelope(Intent::Analyze, "no personal data"), &metadata()).contributions.is_empty());
+    }
+}
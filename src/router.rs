@@ -2,25 +2,61 @@ use std::sync::Arc;
 use serde_json::json;
 use crate::domain::{
     PromptEnvelope, Metadata, RiskAssessment,
-    Intent, SubjectTag, PurposeTag, CodexType, SecurityLevel,
+    Intent, CodexType,
 };
 use crate::logging::{LogSink, LogEvent};
-use crate::tools::{ToolAdapter, ToolError};
+use crate::risk::{RiskChain, BioSignal, text_of};
+use crate::pii::{scan_args, redact_args};
+use crate::classify::Classifier;
+use crate::registry::{ToolRegistry, RouteTable, RouteError};
+use crate::tools::ToolError;
+use crate::normalize::from_firewall;
+use neurorights_firewall::{
+    wrap_prompt_assessed, AssessError, EnvelopeRegistry, NeurorightsBound, NeurorightsEnvelope, RohAssessor,
+};
+
+/// Risk score at or above which a request is blocked unless configured otherwise.
+pub const DEFAULT_RISK_THRESHOLD: f32 = 0.3;
 
 /// Central router state.
 pub struct CyberRetrievalRouter {
-    tools: Vec<Arc<dyn ToolAdapter>>,
+    tools: ToolRegistry,
+    routes: RouteTable,
     log_sink: Arc<dyn LogSink>,
-    risk_threshold: f32, // e.g. 0.3
+    risk_threshold: f32, // e.g. DEFAULT_RISK_THRESHOLD
+    risk_chain: RiskChain,
+    classifier: Classifier,
 }
 
 impl CyberRetrievalRouter {
+    /// Fails at startup if any intent has no tool to route to.
     pub fn new(
-        tools: Vec<Arc<dyn ToolAdapter>>,
+        tools: ToolRegistry,
+        routes: RouteTable,
         log_sink: Arc<dyn LogSink>,
         risk_threshold: f32,
-    ) -> Self {
-        Self { tools, log_sink, risk_threshold }
+    ) -> Result<Self, RouteError> {
+        tools.check(&routes)?;
+        Ok(Self {
+            tools,
+            routes,
+            log_sink,
+            risk_threshold,
+            risk_chain: RiskChain::standard(),
+            classifier: Classifier::compiled(),
+        })
+    }
+
+    /// Replace the compiled subject/purpose classifier, e.g. with a newly reviewed shard.
+    pub fn with_classifier(mut self, classifier: Classifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// Replace the standard risk pipeline.
+    pub fn with_risk_chain(mut self, risk_chain: RiskChain) -> Self {
+        self.risk_chain = risk_chain;
+        self
     }
 
     /// Entry point: handle a normalized envelope.
@@ -28,7 +64,7 @@ impl CyberRetrievalRouter {
         let metadata = self.derive_metadata(&envelope);
         let risk = self.assess_risk(&envelope, &metadata);
 
-        // Red-flag path: block if above threshold.
+        // Red-flag path: block at this router's threshold, or on any stage's red flag.
         if risk.risk_score >= self.risk_threshold || risk.red_flag {
             let result = json!({
                 "status": "blocked",
@@ -42,7 +78,7 @@ impl CyberRetrievalRouter {
         }
 
         // Deterministic tool selection based on intent + subject.
-        let tool = self.select_tool(&envelope, &metadata)?;
+        let tool = self.tools.route(&self.routes, &envelope, &metadata)?;
 
         let result = tool.execute(&envelope, &metadata, &risk).await?;
 
@@ -52,10 +88,19 @@ impl CyberRetrievalRouter {
         Ok(result)
     }
 
+    /// Bind a firewall envelope for neurorights handlers, with this router's risk score
+    /// as its RoH; any `roh` the caller put in the args is not read.
+    pub fn bind(
+        &self,
+        registry: &EnvelopeRegistry,
+        env: neurorights_firewall::PromptEnvelope,
+    ) -> Result<NeurorightsBound<neurorights_firewall::PromptEnvelope, NeurorightsEnvelope>, AssessError> {
+        wrap_prompt_assessed(registry, env, self)
+    }
+
     fn derive_metadata(&self, envelope: &PromptEnvelope) -> Metadata {
         // Deterministic mapping from intent/args to metadata.
-        let subject = SubjectTag::Other;
-        let purpose = PurposeTag::Other;
+        let class = self.classifier.classify(&envelope.args);
         let codex_type = match envelope.intent {
             Intent::Governance => CodexType::PolicyDraft,
             Intent::Analyze | Intent::Retrieve => CodexType::ResearchSpec,
@@ -67,54 +112,25 @@ impl CyberRetrievalRouter {
         Metadata {
             codex_type,
             drive_path: format!(
-                "Drive:/Cyber-Retrieval/Logs/{:?}/{}",
+                "Drive:/Cyber-Retrieval/Logs/{:?}/{:?}/{:?}/{}",
+                class.subject,
+                class.purpose,
                 envelope.created_at,
                 envelope.trace_id
             ),
-            subject,
-            purpose,
-            has_pii: false,
-            bio_risk_flag: false,
+            subject: class.subject,
+            purpose: class.purpose,
+            subject_confidence: class.subject_confidence,
+            purpose_confidence: class.purpose_confidence,
+            has_pii: !scan_args(&envelope.args).is_empty(),
+            bio_risk_flag: !BioSignal::scan(&text_of(&envelope.args)).agents.is_empty(),
             policy_relevant: matches!(envelope.intent, Intent::Governance),
         }
     }
 
-    fn assess_risk(&self, _envelope: &PromptEnvelope, metadata: &Metadata) -> RiskAssessment {
-        // High-level, non-procedural risk estimate.
-        let mut score: f32 = 0.05;
-
-        if metadata.bio_risk_flag {
-            score = 0.25;
-        }
-
-        let red_flag = score >= 0.3;
-
-        RiskAssessment {
-            risk_score: score,
-            red_flag,
-            rationale: "High-level conceptual guidance only; no operational bio steps.".into(),
-        }
-    }
-
-    fn select_tool(
-        &self,
-        envelope: &PromptEnvelope,
-        metadata: &Metadata,
-    ) -> Result<Arc<dyn ToolAdapter>, ToolError> {
-        // Simple deterministic routing example: extend as needed.
-        let target_name = match (envelope.intent, metadata.codex_type) {
-            (Intent::Governance, _) => "governance_registry",
-            (Intent::Retrieve, _) => "drive_reader",
-            (Intent::Analyze, _) => "analysis_engine",
-            (Intent::Plan, _) | (Intent::Simulate, _) => "simulation_adapter",
-            (Intent::Unknown, _) => "logging_adapter",
-        };
-
-        self.tools
-            .iter()
-            .find(|t| t.name() == target_name)
-            .cloned()
-            .ok_or_else(|| ToolError::Internal(format!("No tool registered for {}", target_name)))
+    fn assess_risk(&self, envelope: &PromptEnvelope, metadata: &Metadata) -> RiskAssessment {
+        // High-level, non-procedural risk estimate; see `RiskChain` for the stages.
+        self.risk_chain.assess(envelope, metadata)
     }
 
     fn build_log_event(
@@ -125,8 +141,9 @@ impl CyberRetrievalRouter {
         result: Option<&serde_json::Value>,
         cmd: &str,
     ) -> LogEvent {
-        let params = envelope.args.clone();
-        let result_ref = result.map(|r| format!("inline:{}", envelope.trace_id));
+        // Only the redacted copy is logged; the tool call keeps the original args.
+        let params = redact_args(&envelope.args, envelope.security_level);
+        let result_ref = result.map(|_| format!("inline:{}", envelope.trace_id));
 
         LogEvent {
             trace_id: envelope.trace_id.clone(),
@@ -141,3 +158,88 @@ impl CyberRetrievalRouter {
         }
     }
 }
+
+impl RohAssessor for CyberRetrievalRouter {
+    fn assess_roh(&self, env: &neurorights_firewall::PromptEnvelope) -> f64 {
+        let envelope = from_firewall(env);
+        let metadata = self.derive_metadata(&envelope);
+        f64::from(self.assess_risk(&envelope, &metadata).risk_score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::time::SystemTime;
+    use crate::adapters::{analysis_engine::AnalysisEngineAdapter, drive_reader::DriveReaderAdapter};
+    use crate::adapters::{logging_adapter::LoggingAdapter, simulation_adapter::SimulationAdapter};
+    use crate::domain::{Identity, SecurityLevel};
+    use crate::logging::LogError;
+
+    #[derive(Default)]
+    struct Memory(Mutex<Vec<LogEvent>>);
+
+    impl LogSink for Memory {
+        fn append(&self, event: &LogEvent) -> Result<(), LogError> {
+            self.0.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    fn router(risk_threshold: f32) -> CyberRetrievalRouter {
+        let tools = ToolRegistry::new()
+            .register(Arc::new(DriveReaderAdapter))
+            .register(Arc::new(AnalysisEngineAdapter::new("unused.log")))
+            .register(Arc::new(SimulationAdapter::default()))
+            .register(Arc::new(LoggingAdapter::new(Arc::new(Memory::default()))));
+        CyberRetrievalRouter::new(tools, RouteTable::compiled(), Arc::new(Memory::default()), risk_threshold).unwrap()
+    }
+
+    fn envelope(prompt: &str) -> PromptEnvelope {
+        PromptEnvelope {
+            trace_id: "0x01".into(),
+            intent: Intent::Unknown,
+            args: json!({ "prompt": prompt }),
+            security_level: SecurityLevel::Public,
+            identity: Identity { user_did: "did:example:user".into(), aln: None, bostrom_address: None },
+            created_at: SystemTime::UNIX_EPOCH,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_configured_threshold_decides_blocking() {
+        // Two weak signals (PII and a 0.3 lexicon term) on an unknown intent score 0.40.
+        let prompt = "is this an exploit? reach me at jane@example.org";
+        assert!(matches!(router(DEFAULT_RISK_THRESHOLD).handle(envelope(prompt)).await, Err(ToolError::Denied(_))));
+        let queued = router(0.5).handle(envelope(prompt)).await.unwrap();
+        assert_eq!(queued["status"], "queued_for_review");
+
+        // A stage red flag blocks whatever the threshold.
+        let bio = envelope("step by step, culture the pathogen");
+        assert!(matches!(router(1.0).handle(bio).await, Err(ToolError::Denied(_))));
+    }
+
+    #[test]
+    fn test_bind_attaches_the_router_risk_score() {
+        let env = |prompt: &str| neurorights_firewall::PromptEnvelope {
+            trace_id: "0x01".into(),
+            intent: "fetch_record".into(),
+            // A caller-declared roh is never read.
+            args: json!({ "prompt": prompt, "roh": 0.0 }),
+            security_level: "citizen".into(),
+            identity: "did:example:user".into(),
+            provenance: String::new(),
+            governance: String::new(),
+            neurorights_profile: neurorights_firewall::NeurorightsProfile::current(),
+        };
+        let router = router(DEFAULT_RISK_THRESHOLD);
+        let registry = EnvelopeRegistry::compiled();
+
+        let benign = router.bind(&registry, env("fetch prosthetic grip research")).unwrap();
+        assert!((benign.roh().unwrap() - 0.02).abs() < 1e-6);
+
+        let risky = router.bind(&registry, env("weaponize the pathogen, step by step")).unwrap();
+        assert!(risky.roh().unwrap() > 0.5);
+    }
+}
@@ -1,12 +1,25 @@
 use async_trait::async_trait;
 use serde_json::Value;
-use crate::domain::{PromptEnvelope, Metadata, RiskAssessment};
+use crate::domain::{PromptEnvelope, Metadata, RiskAssessment, Intent, SecurityLevel};
+
+/// What a tool declares about itself; the registry routes only requests it admits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToolCapabilities {
+    pub intents: &'static [Intent],
+    /// Most sensitive request the tool may see.
+    pub max_security: SecurityLevel,
+    pub read_only: bool,
+    /// Neurorights envelope flags (`section.key`) that must be true, e.g. `rights.mentalprivacy`.
+    pub required_flags: &'static [&'static str],
+}
 
 /// Trait for any tool adapter (drive, registry, chain, etc.).
 #[async_trait]
 pub trait ToolAdapter: Send + Sync {
     fn name(&self) -> &'static str;
 
+    fn capabilities(&self) -> ToolCapabilities;
+
     async fn execute(
         &self,
         envelope: &PromptEnvelope,